@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use strum::Display;
+use ticks::tasks::TaskPriority;
 
 /// Actions that can be performed in the TUI
 #[derive(Debug, Clone, Serialize, Display, Deserialize)]
@@ -14,6 +15,8 @@ pub enum Action {
     Quit,
     /// Refresh task list from server
     RefreshTasks,
+    /// Send changes made while offline to the server
+    SyncPending,
     /// Display error message
     Error(String),
     /// Toggle help screen
@@ -40,22 +43,72 @@ pub enum Action {
     PreviousTab,
     /// Switch to next tab
     NextTab,
+    /// Jump to the tab at a position in the tab bar, counting from 0
+    SelectTab(usize),
+    /// Show the tasks of the selected project in the Projects tab
+    OpenProject,
+    /// Go back to the project list in the Projects tab
+    CloseProject,
+    /// Show the tasks with the selected tag in the Tags tab
+    OpenTag,
+    /// Go back to the tag list in the Tags tab
+    CloseTag,
+
+    // Search
+    /// Open the `/` search prompt
+    StartSearch,
+    /// Filter the list by the text typed at the search prompt
+    SetSearch(String),
+    /// Switch between searching the current tab and every project
+    ToggleSearchScope,
+    /// Close the search prompt, keeping the filter
+    ConfirmSearch,
+    /// Close the search prompt and show every task again
+    ClearSearch,
+
+    // Saved queries
+    /// Open the modal to save a query as a tab, or edit the current saved tab
+    StartSaveQuery,
+    /// Remove the current saved query tab
+    DeleteSavedQuery,
 
     // Task actions
+    /// Undo the last task change
+    Undo,
+    /// Redo the last undone task change
+    Redo,
     /// Mark task as complete
     CompleteTask,
     /// Start complete task confirmation
     StartCompleteTask,
+    /// Mark the selected completed tasks as not done
+    ReopenTasks,
+    /// Recreate the selected deleted tasks from the trash
+    RestoreTasks,
     /// Start delete task confirmation
     StartDeleteTask,
     /// Delete selected task
     DeleteTask,
     /// Start creating a new task
     StartCreateTask,
+    /// Start creating a task from a single quick-add line
+    StartQuickAdd,
     /// Start editing selected task
     StartEditTask,
     /// Postpone a task
     StartPostponeTask,
+    /// Move the selected tasks to another project
+    StartMoveTasks,
+    /// Set the priority of the selected tasks
+    SetPriority(TaskPriority),
+    /// Set the tags of the selected tasks
+    StartTagTasks,
+    /// Only show tasks with the given tags
+    StartTagFilter,
+    /// Hide or show tasks whose start date hasn't come yet
+    ToggleHideUnstarted,
+    /// Draw with the next theme from the config
+    NextTheme,
     /// Cancel current input operation
     CancelInput,
     /// Confirm current input operation
@@ -78,10 +131,34 @@ pub enum Action {
     EnterTaskEditor,
     /// Exit task editor pane (focus back on task list)
     ExitTaskEditor,
+    /// Move left in the current field, or back to the task list at the start of a line
+    EditorLeft,
+    /// Move down a line of the notes, or to the next field
+    EditorDown,
+    /// Move up a line of the notes, or to the previous field
+    EditorUp,
+    /// Jump to the first field, or the first line of the notes
+    EditorTop,
+    /// Jump to the last field, or the last line of the notes
+    EditorBottom,
+    /// Focus the next field of the task editor pane
+    NextField,
+    /// Focus the previous field of the task editor pane
+    PreviousField,
 
     // Task updates
     /// Task operation completed successfully
-    TaskOperationComplete,
+    TaskOperationComplete(u64),
+    /// Task operation was rejected and its local change should be rolled back
+    TaskOperationFailed(u64),
     /// Tasks fetched from API - triggers UI update
     TasksFetched,
+    /// A background refresh finished, whether or not it succeeded
+    RefreshFinished,
+    /// Tasks of the open project fetched from API
+    ProjectTasksFetched,
+    /// Tasks with the open tag fetched from API
+    TagTasksFetched,
+    /// Every task fetched from API for searching all projects or a saved query
+    AllTasksFetched,
 }
@@ -1,21 +1,37 @@
 use anyhow::Result;
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use crossterm::event::KeyEvent;
 use ratatui::layout::Rect;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use ticks::{
     projects::ProjectID,
-    tasks::{Task, TaskID},
-    TickTick,
+    tasks::{Task, TaskID, TaskPriority},
 };
 use tokio::sync::mpsc::{self, UnboundedSender};
 
 use crate::{
     action::Action,
-    tasks::{self, fetch_all_tasks},
+    backend::{is_inbox_project, local_task, same_task_id, NewTask, ProjectInfo, TaskBackend},
+    cache::{TaskCache, TaskSnapshot},
+    checklist::checklist_items,
+    completed::CompletedLog,
+    config::{Config, TabFilter},
+    history::{History, HistoryEntry},
+    keymap::{KeyChord, KeyMode},
+    query::{Query, SavedQuery},
+    quick_add::{
+        find_project, format_tags, parse_priority_field, parse_quick_add, parse_tags, priority_name,
+    },
+    recurrence::{parse_recurrence, repeat_rule, repeat_text},
+    reminders::{describe_reminders, parse_reminders, reminders},
+    search::{self, Search, SearchScope},
+    tasks::{self, fetch_all_tasks, format_start, has_started, parse_start, TaskChanges},
+    trash::Trash,
     ui::{
-        self, AppUI, ConfirmationModal, ConfirmationType, Event, PostponeModal, TaskModal, Tui,
-        ViewTab,
+        self, tui::Frame, AppUI, ConfirmationModal, ConfirmationType, Event, FieldValues,
+        MoveModal, PostponeModal, QueryModal, QuickAddModal, TagModal, TagModalKind, TaskModal,
+        Tui, ViewTab,
     },
     utils::{self, parse_date_us_format, parse_time_us_format},
 };
@@ -28,29 +44,127 @@ pub enum Mode {
     Visual,
     Processing,
     Help,
+    Search,
+}
+
+/// Local state needed to undo an optimistic change if the server rejects it
+struct Rollback {
+    /// Affected tasks as they were before the change, and whether they were in the inbox
+    before: Vec<(Task, bool)>,
+    /// Task shown only until the server confirms it, like one still being created
+    placeholder: Option<(TaskID, bool)>,
 }
 
+/// Text shown in a priority field, blank for tasks without a priority
+fn priority_field_text(priority: &TaskPriority) -> &'static str {
+    match priority {
+        TaskPriority::None => "",
+        priority => priority_name(priority),
+    }
+}
+
+/// Tags that every one of the tasks has
+fn common_tags(tasks: &[Task]) -> Vec<String> {
+    let Some((first, rest)) = tasks.split_first() else {
+        return Vec::new();
+    };
+    first
+        .tags
+        .iter()
+        .filter(|tag| rest.iter().all(|task| tasks::has_tag(task, tag)))
+        .cloned()
+        .collect()
+}
+
+/// Seconds between attempts to send offline changes
+const SYNC_INTERVAL_SECONDS: f64 = 10.0;
+
+/// Seconds a status message stays in the header
+const STATUS_SECONDS: f64 = 3.0;
+
 pub struct App {
     pub should_quit: bool,
     pub mode: Mode,
     pub ui: AppUI,
-    pub client: Arc<TickTick>,
+    pub client: Arc<dyn TaskBackend>,
     pub error_message: Option<String>,
-    pub error_ticks: u8,
+    pub error_ticks: u32,
     pub today_cache: Vec<Task>,
     pub week_cache: Vec<Task>,
     pub inbox_cache: Vec<Task>,
     pub tasks_loaded: bool,
     pub pending_tasks: Arc<Mutex<Option<(Vec<Task>, Vec<Task>, Vec<Task>)>>>,
+    pub projects: Vec<ProjectInfo>,
+    pub pending_projects: Arc<Mutex<Option<Vec<ProjectInfo>>>>,
+    /// Project shown in the Projects tab, or `None` while browsing the project list
+    pub open_project: Option<ProjectInfo>,
+    pub project_cache: Vec<Task>,
+    pub pending_project_tasks: Arc<Mutex<Option<(ProjectID, Vec<Task>)>>>,
+    /// Tag shown in the Tags tab, or `None` while browsing the tag list
+    pub open_tag: Option<String>,
+    pub tag_cache: Vec<Task>,
+    pub pending_tag_tasks: Arc<Mutex<Option<(String, Vec<Task>)>>>,
+    /// Only tasks with all of these tags are shown
+    pub tag_filter: Vec<String>,
+    /// Whether tasks that haven't reached their start date are hidden
+    pub hide_unstarted: bool,
+    /// Text typed at the `/` prompt; tasks that don't match it are hidden
+    pub search: Option<Search>,
+    /// Every task, while searching all projects or showing a saved query
+    pub all_cache: Vec<Task>,
+    pub pending_all_tasks: Arc<Mutex<Option<Vec<Task>>>>,
+    /// Queries shown as extra tabs after the inbox
+    pub saved_queries: Vec<SavedQuery>,
+    /// Recently completed tasks, listed in the Completed tab
+    pub completed: CompletedLog,
+    /// Snapshots of deleted tasks, listed in the Trash tab
+    pub trash: Trash,
     pub current_tab: ViewTab,
     pub editing_task: Option<(ProjectID, TaskID)>,
     pub task_editor_focused: bool,
+    pub cache: Option<TaskCache>,
+    pub sync_ticks: u32,
+    /// Refreshes still running in the background
+    refreshes: u32,
+    /// Whether a refresh may have missed local changes, so its results are dropped and fetched again
+    stale_refreshes: bool,
+    /// Keys of a sequence like `g g` typed so far
+    pending_keys: Vec<KeyChord>,
+    /// Settings from the config file
+    pub config: Config,
+    rollbacks: HashMap<u64, Rollback>,
+    next_operation_id: u64,
+    pub created_tasks: Arc<Mutex<HashMap<u64, Task>>>,
+    history: History,
+    pub status_message: Option<String>,
+    pub status_ticks: u32,
 }
 
 impl App {
-    pub fn new(client: Arc<TickTick>) -> Result<Self> {
-        let ui = AppUI::new();
-        Ok(Self {
+    pub fn new(
+        client: Arc<dyn TaskBackend>,
+        cache: Option<TaskCache>,
+        config: Config,
+    ) -> Result<Self> {
+        let mut ui = AppUI::new();
+        ui.dates = config.dates.clone();
+        ui.help = config.keys.help();
+        ui.palette = config.palette();
+        ui.task_list.layout = config.tabs.clone();
+        let snapshot = cache.as_ref().and_then(|cache| cache.load_snapshot());
+        let completed = cache
+            .as_ref()
+            .map(|cache| cache.load_completed())
+            .unwrap_or_default();
+        let trash = cache
+            .as_ref()
+            .map(|cache| cache.load_trash())
+            .unwrap_or_default();
+        let saved_queries = cache
+            .as_ref()
+            .map(|cache| cache.load_queries())
+            .unwrap_or_default();
+        let mut app = Self {
             should_quit: false,
             mode: Mode::Normal,
             ui,
@@ -62,16 +176,61 @@ impl App {
             inbox_cache: Vec::new(),
             tasks_loaded: false,
             pending_tasks: Arc::new(Mutex::new(None)),
+            projects: Vec::new(),
+            pending_projects: Arc::new(Mutex::new(None)),
+            open_project: None,
+            project_cache: Vec::new(),
+            pending_project_tasks: Arc::new(Mutex::new(None)),
+            open_tag: None,
+            tag_cache: Vec::new(),
+            pending_tag_tasks: Arc::new(Mutex::new(None)),
+            tag_filter: Vec::new(),
+            hide_unstarted: false,
+            search: None,
+            all_cache: Vec::new(),
+            pending_all_tasks: Arc::new(Mutex::new(None)),
+            saved_queries,
+            completed,
+            trash,
             current_tab: ViewTab::Today,
             editing_task: None,
             task_editor_focused: false,
-        })
+            cache,
+            sync_ticks: 0,
+            refreshes: 0,
+            stale_refreshes: false,
+            pending_keys: Vec::new(),
+            config,
+            rollbacks: HashMap::new(),
+            next_operation_id: 0,
+            created_tasks: Arc::new(Mutex::new(HashMap::new())),
+            history: History::default(),
+            status_message: None,
+            status_ticks: 0,
+        };
+
+        app.ui.task_list.saved_tabs = app
+            .saved_queries
+            .iter()
+            .map(|saved| saved.name.clone())
+            .collect();
+        app.ui.task_list.select_tab(0);
+        app.current_tab = app.ui.task_list.get_current_tab();
+
+        // Show the last known tasks right away while the first refresh runs
+        if let Some(snapshot) = snapshot {
+            app.update_cache(snapshot.today, snapshot.week, snapshot.inbox);
+        }
+
+        Ok(app)
     }
 
     pub async fn run(&mut self) -> Result<()> {
         let (action_tx, mut action_rx) = mpsc::unbounded_channel();
 
-        let mut tui = ui::Tui::new()?;
+        let mut tui = ui::Tui::new()?
+            .tick_rate(self.config.tick_rate)
+            .frame_rate(self.config.frame_rate);
         tui.enter()?;
 
         // Initial load of tasks
@@ -93,321 +252,1612 @@ impl App {
 
             while let Ok(action) = action_rx.try_recv() {
                 match action {
-                    Action::Tick => self.next_tick(),
                     Action::Render => self.render(&mut tui)?,
                     Action::Resize(w, h) => tui.terminal.resize(Rect::new(0, 0, w, h))?,
-                    Action::Quit => self.should_quit = true,
-                    Action::RefreshTasks => self.refresh_tasks(action_tx.clone()),
-                    Action::Error(msg) => self.error(msg),
-                    Action::ToggleHelp => self.toggle_help(),
-
-                    Action::SelectPrevious => {
-                        self.save_task_before_changing_selection(action_tx.clone());
-                        let current_tab = self.ui.task_list.current_tab;
-                        let tasks = self.get_view_tasks(current_tab);
-                        self.ui.task_list.select_previous(tasks.len());
-                        self.sync_task_editor_with_selection();
+                    action => self.update(action, &action_tx),
+                }
+            }
+
+            if self.should_quit {
+                tui.stop()?;
+                break;
+            }
+        }
+
+        tui.exit()?;
+        Ok(())
+    }
+
+    /// Apply a single action to the app state
+    fn update(&mut self, action: Action, tx: &UnboundedSender<Action>) {
+        match action {
+            Action::Tick => self.next_tick(tx),
+            // Rendering and resizing need the terminal and are handled by the event loop
+            Action::Render | Action::Resize(_, _) => {}
+            Action::Quit => self.should_quit = true,
+            Action::RefreshTasks => self.refresh_tasks(tx.clone()),
+            Action::SyncPending => self.sync_pending(tx.clone()),
+            Action::Error(msg) => self.error(msg),
+            Action::ToggleHelp => self.toggle_help(),
+
+            Action::SelectPrevious => {
+                self.save_task_before_changing_selection(tx.clone());
+                let len = self.view_len();
+                self.ui.task_list.select_previous(len);
+                self.sync_task_editor_with_selection();
+            }
+            Action::SelectNext => {
+                self.save_task_before_changing_selection(tx.clone());
+                let len = self.view_len();
+                self.ui.task_list.select_next(len);
+                self.sync_task_editor_with_selection();
+            }
+            Action::SelectPreviousCycling => {
+                self.save_task_before_changing_selection(tx.clone());
+                let len = self.view_len();
+                self.ui.task_list.select_previous_cycling(len);
+                self.sync_task_editor_with_selection();
+            }
+            Action::SelectNextCycling => {
+                self.save_task_before_changing_selection(tx.clone());
+                let len = self.view_len();
+                self.ui.task_list.select_next_cycling(len);
+                self.sync_task_editor_with_selection();
+            }
+            Action::SelectFirst => {
+                self.save_task_before_changing_selection(tx.clone());
+                let len = self.view_len();
+                self.ui.task_list.select_first(len);
+                self.sync_task_editor_with_selection();
+            }
+            Action::SelectLast => {
+                self.save_task_before_changing_selection(tx.clone());
+                let len = self.view_len();
+                self.ui.task_list.select_last(len);
+                self.sync_task_editor_with_selection();
+            }
+            Action::SelectNone => {
+                self.save_task_before_changing_selection(tx.clone());
+                self.ui.task_list.select_none();
+                self.sync_task_editor_with_selection();
+            }
+
+            Action::PreviousTab => {
+                self.save_task_before_changing_selection(tx.clone());
+                self.previous_tab();
+                self.load_query_tab(tx.clone());
+            }
+            Action::NextTab => {
+                self.save_task_before_changing_selection(tx.clone());
+                self.next_tab();
+                self.load_query_tab(tx.clone());
+            }
+            Action::SelectTab(position) => {
+                self.save_task_before_changing_selection(tx.clone());
+                self.select_tab(position);
+                self.load_query_tab(tx.clone());
+            }
+            Action::OpenProject => self.open_project(tx.clone()),
+            Action::CloseProject => {
+                self.save_task_before_changing_selection(tx.clone());
+                self.close_project();
+            }
+            Action::OpenTag => self.open_tag(tx.clone()),
+            Action::CloseTag => {
+                self.save_task_before_changing_selection(tx.clone());
+                self.close_tag();
+            }
+
+            Action::StartSearch => self.start_search(),
+            Action::SetSearch(query) => self.set_search(query),
+            Action::ToggleSearchScope => self.toggle_search_scope(tx.clone()),
+            Action::ConfirmSearch => self.confirm_search(),
+            Action::ClearSearch => self.clear_search(),
+
+            Action::StartSaveQuery => self.start_save_query(),
+            Action::DeleteSavedQuery => self.delete_saved_query(),
+
+            Action::Undo => self.undo(tx.clone()),
+            Action::Redo => self.redo(tx.clone()),
+
+            Action::CompleteTask => self.complete_task(tx.clone()),
+            Action::StartCompleteTask => self.start_complete_task(),
+            Action::ReopenTasks => self.reopen_tasks(tx.clone()),
+            Action::RestoreTasks => self.restore_tasks(tx.clone()),
+            Action::StartDeleteTask => self.start_delete_task(),
+            Action::DeleteTask => self.delete_task(tx.clone()),
+            Action::StartPostponeTask => self.start_postpone_task(),
+            Action::StartMoveTasks => self.start_move_tasks(),
+            Action::SetPriority(priority) => self.set_priority(priority, tx.clone()),
+            Action::StartTagTasks => self.start_tag_tasks(),
+            Action::StartTagFilter => self.start_tag_filter(),
+            Action::ToggleHideUnstarted => self.toggle_hide_unstarted(),
+            Action::NextTheme => self.next_theme(),
+            Action::StartCreateTask => self.start_create_task(),
+            Action::StartQuickAdd => self.start_quick_add(),
+            Action::StartEditTask => self.start_edit_task(),
+            Action::CancelInput => self.cancel_input(),
+            Action::ConfirmInput => self.confirm_input(tx.clone()),
+
+            Action::EnterNormal => {
+                self.mode = Mode::Normal;
+                self.ui.task_list.exit_visual_mode();
+            }
+            Action::EnterInsert => {
+                self.mode = Mode::Insert;
+                self.ui.task_list.exit_visual_mode();
+            }
+            Action::EnterVisual => {
+                self.mode = Mode::Visual;
+                self.ui.task_list.enter_visual_mode();
+            }
+            Action::EnterProcessing => self.mode = Mode::Processing,
+            Action::ExitProcessing => {
+                self.mode = Mode::Normal;
+                self.ui.task_list.exit_visual_mode();
+            }
+
+            Action::EnterTaskEditor => self.enter_task_editor(),
+            Action::ExitTaskEditor => self.exit_task_editor(tx.clone()),
+            Action::EditorLeft
+            | Action::EditorDown
+            | Action::EditorUp
+            | Action::EditorTop
+            | Action::EditorBottom
+            | Action::NextField
+            | Action::PreviousField => self.move_in_editor(action, tx.clone()),
+
+            Action::TaskOperationComplete(id) => {
+                self.operation_complete(id);
+                self.refetch_if_stale(tx.clone());
+            }
+            Action::TaskOperationFailed(id) => {
+                self.operation_failed(id);
+                self.refetch_if_stale(tx.clone());
+            }
+            Action::TasksFetched => self.tasks_fetched(),
+            Action::RefreshFinished => {
+                self.refreshes = self.refreshes.saturating_sub(1);
+                self.refetch_if_stale(tx.clone());
+            }
+            Action::ProjectTasksFetched => self.project_tasks_fetched(),
+            Action::TagTasksFetched => self.tag_tasks_fetched(),
+            Action::AllTasksFetched => self.all_tasks_fetched(),
+        }
+    }
+
+    /// Get the tasks for a specific view from cache, narrowed by the tag filter and search
+    fn get_view_tasks(&self, tab: ViewTab) -> Vec<Task> {
+        let listed: Vec<Task>;
+        let tasks = match tab {
+            _ if self.searching_all() => &self.all_cache,
+            ViewTab::Today => &self.today_cache,
+            ViewTab::Week => &self.week_cache,
+            ViewTab::Inbox => &self.inbox_cache,
+            ViewTab::Saved(index) => {
+                listed = self
+                    .saved_queries
+                    .get(index)
+                    .map(|saved| self.query_tasks(&saved.query))
+                    .unwrap_or_default();
+                &listed
+            }
+            ViewTab::Custom(index) => {
+                listed = self
+                    .config
+                    .custom_tabs
+                    .get(index)
+                    .map(|filter| self.query_tasks(&filter.query()))
+                    .unwrap_or_default();
+                &listed
+            }
+            ViewTab::Projects => &self.project_cache,
+            ViewTab::Tags => &self.tag_cache,
+            ViewTab::Completed => {
+                listed = self
+                    .completed
+                    .entries()
+                    .iter()
+                    .map(|entry| entry.task.clone())
+                    .collect();
+                &listed
+            }
+            ViewTab::Trash => {
+                listed = self
+                    .trash
+                    .entries()
+                    .iter()
+                    .map(|entry| entry.task.clone())
+                    .collect();
+                &listed
+            }
+        };
+        tasks
+            .iter()
+            .filter(|task| self.tag_filter.iter().all(|tag| tasks::has_tag(task, tag)))
+            .filter(|task| !self.hide_unstarted || has_started(task))
+            .filter(|task| {
+                self.search
+                    .as_ref()
+                    .is_none_or(|search| search::matches(task, &search.query))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Every task matching a query, or none if it doesn't parse
+    fn query_tasks(&self, query: &str) -> Vec<Task> {
+        let Ok(query) = Query::parse(query) else {
+            return Vec::new();
+        };
+        let today = Local::now().date_naive();
+        self.all_cache
+            .iter()
+            .filter(|task| {
+                let project = self.project_name(&task.project_id);
+                query.matches(task, project.as_deref(), today)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Number of rows in the current list (projects or tags while browsing them)
+    fn view_len(&self) -> usize {
+        match self.ui.task_list.current_tab {
+            tab if self.searching_all() => self.get_view_tasks(tab).len(),
+            ViewTab::Projects if self.open_project.is_none() => self.projects.len(),
+            ViewTab::Tags if self.open_tag.is_none() => self.known_tags().len(),
+            tab => self.get_view_tasks(tab).len(),
+        }
+    }
+
+    /// Whether the current tab is showing a list of projects or tags rather than tasks
+    fn is_browsing(&self) -> bool {
+        match self.ui.task_list.current_tab {
+            _ if self.searching_all() => false,
+            ViewTab::Projects => self.open_project.is_none(),
+            ViewTab::Tags => self.open_tag.is_none(),
+            _ => false,
+        }
+    }
+
+    /// Whether the list shows the tasks of `tab` rather than search results from every project
+    fn showing_tab(&self, tab: ViewTab) -> bool {
+        self.ui.task_list.current_tab == tab && !self.searching_all()
+    }
+
+    /// Whether the current tab lists completed or deleted tasks, which can only be brought back
+    fn showing_removed(&self) -> bool {
+        self.showing_tab(ViewTab::Completed) || self.showing_tab(ViewTab::Trash)
+    }
+
+    fn searching_all(&self) -> bool {
+        self.search
+            .as_ref()
+            .is_some_and(|search| search.scope == SearchScope::All)
+    }
+
+    /// Whether the list is drawn from every task rather than one view's cache
+    fn needs_all_tasks(&self) -> bool {
+        self.searching_all() || self.showing_query_tab()
+    }
+
+    /// Whether the current tab lists a saved query or a project or query from the config
+    fn showing_query_tab(&self) -> bool {
+        matches!(
+            self.ui.task_list.current_tab,
+            ViewTab::Saved(_) | ViewTab::Custom(_)
+        )
+    }
+
+    /// Every tag on a cached task, sorted by name
+    fn known_tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = Vec::new();
+        for task in self
+            .today_cache
+            .iter()
+            .chain(&self.week_cache)
+            .chain(&self.inbox_cache)
+            .chain(&self.project_cache)
+            .chain(&self.tag_cache)
+        {
+            for tag in &task.tags {
+                if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                    tags.push(tag.clone());
+                }
+            }
+        }
+        tags.sort_by_key(|tag| tag.to_lowercase());
+        tags
+    }
+
+    /// Tags given to new tasks so they show up in the current view
+    fn default_tags(&self) -> Vec<String> {
+        let mut tags = self.tag_filter.clone();
+        if let (ViewTab::Tags, Some(tag)) = (self.current_tab, &self.open_tag) {
+            if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                tags.push(tag.clone());
+            }
+        }
+        tags
+    }
+
+    fn default_project(&self) -> Option<ProjectID> {
+        match (self.current_tab, &self.open_project) {
+            (ViewTab::Projects, Some(project)) => Some(project.id.clone()),
+            (ViewTab::Custom(index), _) => match self.config.custom_tabs.get(index)? {
+                TabFilter::Project(name) => self
+                    .projects
+                    .iter()
+                    .find(|project| project.name.eq_ignore_ascii_case(name))
+                    .map(|project| project.id.clone()),
+                TabFilter::Query(_) => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Projects a task can be moved to, including the inbox
+    fn project_choices(&self) -> Vec<ProjectInfo> {
+        // Inbox tasks carry the real inbox id, which the API expects when moving
+        let inbox_id = self
+            .inbox_cache
+            .iter()
+            .map(|task| task.project_id.clone())
+            .find(is_inbox_project)
+            .unwrap_or_else(|| ProjectID("inbox".to_string()));
+        std::iter::once(ProjectInfo {
+            id: inbox_id,
+            name: "Inbox".to_string(),
+        })
+        .chain(self.projects.iter().cloned())
+        .collect()
+    }
+
+    /// Display name of a project, if it's known
+    fn project_name(&self, project_id: &ProjectID) -> Option<String> {
+        if is_inbox_project(project_id) {
+            return Some("Inbox".to_string());
+        }
+        self.projects
+            .iter()
+            .find(|project| project.id.0 == project_id.0)
+            .map(|project| project.name.clone())
+    }
+
+    /// Update the cache with new tasks and refresh the UI
+    fn update_cache(
+        &mut self,
+        today_tasks: Vec<Task>,
+        week_tasks: Vec<Task>,
+        inbox_tasks: Vec<Task>,
+    ) {
+        // Update the caches
+        self.today_cache = today_tasks;
+        self.week_cache = week_tasks;
+        self.inbox_cache = inbox_tasks;
+        self.tasks_loaded = true;
+
+        // Update UI with current view's tasks
+        let current_tasks = self.get_view_tasks(self.ui.task_list.get_current_tab());
+        self.ui.task_list.set_tasks(&current_tasks);
+        self.sync_task_editor_with_selection();
+    }
+
+    /// Refresh the task list after the caches were changed locally
+    fn caches_changed(&mut self) {
+        let current_tasks = self.get_view_tasks(self.ui.task_list.get_current_tab());
+        self.ui.task_list.set_tasks(&current_tasks);
+        // Don't overwrite fields the user is editing
+        if !self.task_editor_focused {
+            self.sync_task_editor_with_selection();
+        }
+    }
+
+    /// Clones of the selected tasks in the current view
+    fn selected_tasks(&self) -> Vec<Task> {
+        let tasks = self.get_view_tasks(self.ui.task_list.get_current_tab());
+        self.ui
+            .task_list
+            .get_selected_indices()
+            .iter()
+            .filter_map(|&index| tasks.get(index))
+            .cloned()
+            .collect()
+    }
+
+    /// Find a cached task and whether it's in the inbox view
+    fn find_cached_task(&self, task_id: &TaskID) -> Option<(Task, bool)> {
+        let in_inbox = self
+            .inbox_cache
+            .iter()
+            .any(|t| same_task_id(t.get_id(), task_id));
+        self.today_cache
+            .iter()
+            .chain(&self.week_cache)
+            .chain(&self.inbox_cache)
+            .chain(&self.project_cache)
+            .chain(&self.tag_cache)
+            .chain(&self.all_cache)
+            .find(|t| same_task_id(t.get_id(), task_id))
+            .map(|t| (t.clone(), in_inbox))
+    }
+
+    fn remove_cached_task(&mut self, task_id: &TaskID) {
+        for cache in [
+            &mut self.today_cache,
+            &mut self.week_cache,
+            &mut self.inbox_cache,
+            &mut self.project_cache,
+            &mut self.tag_cache,
+            &mut self.all_cache,
+        ] {
+            cache.retain(|t| !same_task_id(t.get_id(), task_id));
+        }
+    }
+
+    /// Put a task into every view it belongs in, replacing any older copy
+    fn place_cached_task(&mut self, task: Task, in_inbox: bool) {
+        self.remove_cached_task(task.get_id());
+        if tasks::is_today_task(&task) {
+            self.today_cache.push(task.clone());
+            tasks::sort_tasks(&mut self.today_cache);
+        }
+        if tasks::is_week_task(&task, self.config.week_days) {
+            self.week_cache.push(task.clone());
+            tasks::sort_tasks(&mut self.week_cache);
+        }
+        if self
+            .open_project
+            .as_ref()
+            .is_some_and(|project| project.id.0 == task.project_id.0)
+        {
+            self.project_cache.push(task.clone());
+            tasks::sort_tasks(&mut self.project_cache);
+        }
+        if self
+            .open_tag
+            .as_ref()
+            .is_some_and(|tag| tasks::has_tag(&task, tag))
+        {
+            self.tag_cache.push(task.clone());
+            tasks::sort_tasks(&mut self.tag_cache);
+        }
+        if self.needs_all_tasks() {
+            self.all_cache.push(task.clone());
+            tasks::sort_tasks(&mut self.all_cache);
+        }
+        if in_inbox {
+            self.inbox_cache.push(task);
+            tasks::sort_tasks(&mut self.inbox_cache);
+        }
+    }
+
+    /// Remember how to undo a local change until the server confirms it
+    fn start_operation(
+        &mut self,
+        before: Vec<(Task, bool)>,
+        placeholder: Option<(TaskID, bool)>,
+    ) -> u64 {
+        let id = self.next_operation_id;
+        self.next_operation_id += 1;
+        // A refresh already on its way may have read the server before this change
+        if self.refreshes > 0 {
+            self.stale_refreshes = true;
+        }
+        self.rollbacks.insert(
+            id,
+            Rollback {
+                before,
+                placeholder,
+            },
+        );
+        id
+    }
+
+    fn operation_complete(&mut self, id: u64) {
+        let rollback = self.rollbacks.remove(&id);
+        let created = self
+            .created_tasks
+            .lock()
+            .ok()
+            .and_then(|mut created| created.remove(&id));
+
+        // Swap the placeholder for the task the server created
+        if let (
+            Some(Rollback {
+                placeholder: Some((placeholder_id, in_inbox)),
+                ..
+            }),
+            Some(task),
+        ) = (rollback, created)
+        {
+            self.remove_cached_task(&placeholder_id);
+            self.history.replace_task(&placeholder_id, &task);
+            self.place_cached_task(task, in_inbox);
+            self.caches_changed();
+        }
+    }
+
+    fn operation_failed(&mut self, id: u64) {
+        if let Some(rollback) = self.rollbacks.remove(&id) {
+            if let Some((placeholder_id, _)) = &rollback.placeholder {
+                self.remove_cached_task(placeholder_id);
+                self.history.forget_task(placeholder_id);
+            }
+            let mut log_changed = false;
+            for (task, in_inbox) in rollback.before {
+                self.history.forget_task(task.get_id());
+                // A task that failed to complete or delete is still around
+                log_changed |= self.completed.remove(task.get_id());
+                log_changed |= self.trash.remove(task.get_id());
+                self.place_cached_task(task, in_inbox);
+            }
+            if log_changed {
+                self.save_completed();
+                self.save_trash();
+            }
+            self.caches_changed();
+        }
+    }
+
+    /// Remove tasks from the local views, returning an operation per task
+    fn remove_tasks_optimistically(&mut self, tasks: Vec<Task>) -> Vec<(u64, ProjectID, TaskID)> {
+        let mut operations = Vec::new();
+        for task in tasks {
+            // Undo and redo can target tasks that aren't in any view
+            let before = self
+                .find_cached_task(task.get_id())
+                .unwrap_or_else(|| (task.clone(), is_inbox_project(&task.project_id)));
+            self.remove_cached_task(task.get_id());
+            let id = self.start_operation(vec![before], None);
+            operations.push((id, task.project_id.clone(), task.get_id().clone()));
+        }
+        self.caches_changed();
+        operations
+    }
+
+    /// Apply edits to the local views and send them in the background
+    ///
+    /// Returns the edited tasks as they were before and after the change.
+    fn edit_tasks(
+        &mut self,
+        edits: Vec<(Task, TaskChanges)>,
+        verb: &'static str,
+        tx: UnboundedSender<Action>,
+    ) -> (Vec<Task>, Vec<Task>) {
+        let mut operations = Vec::new();
+        let mut before_tasks = Vec::new();
+        let mut after_tasks = Vec::new();
+        for (task, changes) in edits {
+            // Undo and redo can target tasks that aren't in any view
+            let (before, in_inbox) = self
+                .find_cached_task(task.get_id())
+                .unwrap_or_else(|| (task.clone(), is_inbox_project(&task.project_id)));
+            let mut edited = before.clone();
+            if let Err(e) = tasks::apply_task_changes(&mut edited, &changes) {
+                self.error(e);
+                continue;
+            }
+            // A moved task only stays in the inbox view if it was moved to the inbox
+            let in_inbox = if edited.project_id.0 != before.project_id.0 {
+                is_inbox_project(&edited.project_id)
+            } else {
+                in_inbox
+            };
+            before_tasks.push(before.clone());
+            after_tasks.push(edited.clone());
+            self.place_cached_task(edited, in_inbox);
+            let id = self.start_operation(vec![(before, in_inbox)], None);
+            operations.push((id, task.project_id.clone(), task.get_id().clone(), changes));
+        }
+        self.caches_changed();
+        if !operations.is_empty() {
+            self.send_edits(operations, verb, tx);
+        }
+        (before_tasks, after_tasks)
+    }
+
+    /// Send edits to the server, rolling back any that fail
+    fn send_edits(
+        &self,
+        operations: Vec<(u64, ProjectID, TaskID, TaskChanges)>,
+        verb: &'static str,
+        tx: UnboundedSender<Action>,
+    ) {
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            let mut errors = Vec::new();
+
+            for (operation_id, project_id, task_id, changes) in operations {
+                // Get fresh task data and apply the same changes to it
+                let result = match client.get_task(&project_id, &task_id).await {
+                    Ok(mut task) => tasks::edit_task(&*client, &mut task, &changes).await,
+                    Err(e) => Err(e),
+                };
+
+                match result {
+                    Ok(()) => {
+                        let _ = tx.send(Action::TaskOperationComplete(operation_id));
                     }
-                    Action::SelectNext => {
-                        self.save_task_before_changing_selection(action_tx.clone());
-                        let current_tab = self.ui.task_list.current_tab;
-                        let tasks = self.get_view_tasks(current_tab);
-                        self.ui.task_list.select_next(tasks.len());
-                        self.sync_task_editor_with_selection();
+                    Err(e) => {
+                        errors.push(e);
+                        let _ = tx.send(Action::TaskOperationFailed(operation_id));
                     }
-                    Action::SelectPreviousCycling => {
-                        self.save_task_before_changing_selection(action_tx.clone());
-                        let current_tab = self.ui.task_list.current_tab;
-                        let tasks = self.get_view_tasks(current_tab);
-                        self.ui.task_list.select_previous_cycling(tasks.len());
-                        self.sync_task_editor_with_selection();
+                }
+            }
+
+            // Send error if any tasks failed
+            if !errors.is_empty() {
+                let combined_error = format!(
+                    "Failed to {} {} task(s): {}",
+                    verb,
+                    errors.len(),
+                    errors.join(", ")
+                );
+                let _ = tx.send(Action::Error(combined_error));
+            }
+        });
+    }
+
+    /// Show a new task right away and create it on the server in the background
+    ///
+    /// Returns the placeholder shown until the server responds.
+    fn create_task_optimistically(
+        &mut self,
+        new_task: NewTask,
+        tx: UnboundedSender<Action>,
+    ) -> Task {
+        // The placeholder is shown until the server returns the real task
+        let placeholder = local_task(&format!("pending-{}", self.next_operation_id), &new_task);
+        let in_inbox = is_inbox_project(&new_task.project_id);
+        let operation_id =
+            self.start_operation(Vec::new(), Some((placeholder.get_id().clone(), in_inbox)));
+        self.place_cached_task(placeholder.clone(), in_inbox);
+        self.caches_changed();
+
+        let client = Arc::clone(&self.client);
+        let created = Arc::clone(&self.created_tasks);
+        tokio::spawn(async move {
+            match client.create_task(new_task).await {
+                Ok(task) => {
+                    if let Ok(mut guard) = created.lock() {
+                        guard.insert(operation_id, task);
                     }
-                    Action::SelectNextCycling => {
-                        self.save_task_before_changing_selection(action_tx.clone());
-                        let current_tab = self.ui.task_list.current_tab;
-                        let tasks = self.get_view_tasks(current_tab);
-                        self.ui.task_list.select_next_cycling(tasks.len());
-                        self.sync_task_editor_with_selection();
+                    let _ = tx.send(Action::TaskOperationComplete(operation_id));
+                }
+                Err(e) => {
+                    let _ = tx.send(Action::TaskOperationFailed(operation_id));
+                    let _ = tx.send(Action::Error(e));
+                }
+            }
+        });
+
+        placeholder
+    }
+
+    /// Recreate tasks from their fields, returning the placeholders shown meanwhile
+    fn recreate_tasks(&mut self, tasks: Vec<Task>, tx: UnboundedSender<Action>) -> Vec<Task> {
+        tasks
+            .iter()
+            .map(|task| self.create_task_optimistically(NewTask::from_task(task), tx.clone()))
+            .collect()
+    }
+
+    /// Bring completed tasks back to their views and reopen them on the server
+    fn reopen_completed(&mut self, tasks: Vec<Task>, tx: UnboundedSender<Action>) {
+        let mut operations = Vec::new();
+        for task in tasks {
+            self.completed.remove(task.get_id());
+            // A repeating task can still be listed at its next date until this goes through
+            let before = self.find_cached_task(task.get_id()).into_iter().collect();
+            let in_inbox = is_inbox_project(&task.project_id);
+            self.place_cached_task(task.clone(), in_inbox);
+            let id = self.start_operation(before, Some((task.get_id().clone(), in_inbox)));
+            operations.push((id, task));
+        }
+        self.save_completed();
+        self.caches_changed();
+        if operations.is_empty() {
+            return;
+        }
+
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            let mut errors = Vec::new();
+
+            for (operation_id, task) in operations {
+                match client.reopen_task(&task).await {
+                    Ok(()) => {
+                        let _ = tx.send(Action::TaskOperationComplete(operation_id));
                     }
-                    Action::SelectFirst => {
-                        self.save_task_before_changing_selection(action_tx.clone());
-                        let current_tab = self.ui.task_list.current_tab;
-                        let tasks = self.get_view_tasks(current_tab);
-                        self.ui.task_list.select_first(tasks.len());
-                        self.sync_task_editor_with_selection();
+                    Err(e) => {
+                        errors.push(e);
+                        let _ = tx.send(Action::TaskOperationFailed(operation_id));
                     }
-                    Action::SelectLast => {
-                        self.save_task_before_changing_selection(action_tx.clone());
-                        let current_tab = self.ui.task_list.current_tab;
-                        let tasks = self.get_view_tasks(current_tab);
-                        self.ui.task_list.select_last(tasks.len());
-                        self.sync_task_editor_with_selection();
+                }
+            }
+
+            // Send error if any tasks failed
+            if !errors.is_empty() {
+                let combined_error = format!(
+                    "Failed to reopen {} task(s): {}",
+                    errors.len(),
+                    errors.join(", ")
+                );
+                let _ = tx.send(Action::Error(combined_error));
+            }
+        });
+    }
+
+    fn undo(&mut self, tx: UnboundedSender<Action>) {
+        let Some(entry) = self.history.pop_undo() else {
+            self.set_status("Nothing to undo".to_string());
+            return;
+        };
+        let description = entry.describe();
+
+        let redo_entry = match entry {
+            HistoryEntry::Complete(tasks) => {
+                self.reopen_completed(tasks.clone(), tx);
+                HistoryEntry::Complete(tasks)
+            }
+            HistoryEntry::Delete(tasks) => {
+                for task in &tasks {
+                    self.trash.remove(task.get_id());
+                }
+                self.save_trash();
+                HistoryEntry::Delete(self.recreate_tasks(tasks, tx))
+            }
+            HistoryEntry::Create(tasks) => {
+                self.delete_tasks(tasks.clone(), tx);
+                HistoryEntry::Create(tasks)
+            }
+            HistoryEntry::Reopen(tasks) => {
+                self.complete_tasks(tasks.clone(), tx);
+                HistoryEntry::Reopen(tasks)
+            }
+            HistoryEntry::Edit {
+                verb,
+                before,
+                after,
+            } => {
+                let edits = after
+                    .iter()
+                    .zip(&before)
+                    .map(|(current, target)| (current.clone(), TaskChanges::restoring(target)))
+                    .collect();
+                self.edit_tasks(edits, "undo", tx);
+                HistoryEntry::Edit {
+                    verb,
+                    before,
+                    after,
+                }
+            }
+        };
+
+        self.history.push_redo(redo_entry);
+        self.set_status(format!("↶ Undid {}", description));
+    }
+
+    fn redo(&mut self, tx: UnboundedSender<Action>) {
+        let Some(entry) = self.history.pop_redo() else {
+            self.set_status("Nothing to redo".to_string());
+            return;
+        };
+        let description = entry.describe();
+
+        let undo_entry = match entry {
+            HistoryEntry::Complete(tasks) => {
+                self.complete_tasks(tasks.clone(), tx);
+                HistoryEntry::Complete(tasks)
+            }
+            HistoryEntry::Delete(tasks) => {
+                self.delete_tasks(tasks.clone(), tx);
+                HistoryEntry::Delete(tasks)
+            }
+            HistoryEntry::Create(tasks) => HistoryEntry::Create(self.recreate_tasks(tasks, tx)),
+            HistoryEntry::Reopen(tasks) => {
+                self.reopen_completed(tasks.clone(), tx);
+                HistoryEntry::Reopen(tasks)
+            }
+            HistoryEntry::Edit {
+                verb,
+                before,
+                after,
+            } => {
+                let edits = before
+                    .iter()
+                    .zip(&after)
+                    .map(|(current, target)| (current.clone(), TaskChanges::restoring(target)))
+                    .collect();
+                self.edit_tasks(edits, "redo", tx);
+                HistoryEntry::Edit {
+                    verb,
+                    before,
+                    after,
+                }
+            }
+        };
+
+        self.history.push_undo(undo_entry);
+        self.set_status(format!("↷ Redid {}", description));
+    }
+
+    /// Show a short-lived message in the header
+    fn set_status(&mut self, msg: String) {
+        self.status_message = Some(msg);
+        self.status_ticks = 0;
+    }
+
+    fn error(&mut self, msg: String) {
+        self.error_message = Some(msg);
+        self.error_ticks = 0;
+        // Background failures shouldn't interrupt whatever the user is doing now
+        if self.mode == Mode::Processing {
+            self.mode = Mode::Normal;
+        }
+    }
+
+    /// Ticks that make up `seconds` at the configured tick rate
+    fn ticks(&self, seconds: f64) -> u32 {
+        (seconds * self.config.tick_rate).ceil() as u32
+    }
+
+    fn next_tick(&mut self, tx: &UnboundedSender<Action>) {
+        if self.error_message.is_some() {
+            self.error_ticks += 1;
+            if self.error_ticks > self.ticks(self.config.error_seconds) {
+                self.error_message = None;
+                self.error_ticks = 0;
+            }
+        }
+        if self.status_message.is_some() {
+            self.status_ticks += 1;
+            if self.status_ticks > self.ticks(STATUS_SECONDS) {
+                self.status_message = None;
+                self.status_ticks = 0;
+            }
+        }
+
+        // Periodically retry sending changes made while offline
+        if self.client.pending_task_ids().is_empty() {
+            self.sync_ticks = 0;
+        } else {
+            self.sync_ticks += 1;
+            if self.sync_ticks >= self.ticks(SYNC_INTERVAL_SECONDS) {
+                self.sync_ticks = 0;
+                let _ = tx.send(Action::SyncPending);
+            }
+        }
+    }
+
+    fn sync_pending(&mut self, tx: UnboundedSender<Action>) {
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            match client.sync().await {
+                Ok(0) => {}
+                Ok(_) => {
+                    let _ = tx.send(Action::RefreshTasks);
+                }
+                Err(e) => {
+                    let _ = tx.send(Action::Error(e));
+                    let _ = tx.send(Action::RefreshTasks);
+                }
+            }
+        });
+    }
+
+    fn render(&mut self, tui: &mut Tui) -> Result<()> {
+        tui.draw(|f| self.draw(f))
+    }
+
+    /// Draw the full UI into a frame
+    fn draw(&mut self, f: &mut Frame) {
+        self.current_tab = self.ui.task_list.get_current_tab();
+        let tasks = self.get_view_tasks(self.current_tab);
+        let pending_ids = self.client.pending_task_ids();
+        let known_tags = self.known_tags();
+        // Completion and deletion times line up with the tasks in their tabs
+        let removed_at: Vec<DateTime<Utc>> = tasks
+            .iter()
+            .filter_map(|task| {
+                if self.showing_tab(ViewTab::Completed) {
+                    self.completed.logged_at(task.get_id())
+                } else if self.showing_tab(ViewTab::Trash) {
+                    self.trash.logged_at(task.get_id())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let _ = self.ui.draw(
+            f,
+            f.area(),
+            self.mode,
+            &tasks,
+            &self.error_message,
+            &self.status_message,
+            self.tasks_loaded,
+            self.task_editor_focused,
+            self.client.is_offline(),
+            &pending_ids,
+            &self.projects,
+            self.open_project.as_ref(),
+            &known_tags,
+            self.open_tag.as_deref(),
+            &self.tag_filter,
+            self.hide_unstarted,
+            &removed_at,
+            self.search.as_ref(),
+        );
+    }
+
+    /// Fetch every view in the background, leaving the current mode alone
+    fn refresh_tasks(&mut self, tx: UnboundedSender<Action>) {
+        self.refreshes += 1;
+        let client = Arc::clone(&self.client);
+        let pending = Arc::clone(&self.pending_tasks);
+        let pending_projects = Arc::clone(&self.pending_projects);
+        let pending_project_tasks = Arc::clone(&self.pending_project_tasks);
+        let open_project_id = self.open_project.as_ref().map(|project| project.id.clone());
+        let pending_tag_tasks = Arc::clone(&self.pending_tag_tasks);
+        let open_tag = self.open_tag.clone();
+        let pending_all_tasks = Arc::clone(&self.pending_all_tasks);
+        let needs_all_tasks = self.needs_all_tasks();
+        let week_days = self.config.week_days;
+        tokio::spawn(async move {
+            // Keep the open project's tasks up to date along with the views
+            if let Some(project_id) = open_project_id {
+                if let Ok(tasks) = tasks::fetch_project_tasks(&*client, &project_id).await {
+                    if let Ok(mut guard) = pending_project_tasks.lock() {
+                        *guard = Some((project_id, tasks));
+                    }
+                    let _ = tx.send(Action::ProjectTasksFetched);
+                }
+            }
+            if let Some(tag) = open_tag {
+                if let Ok(tasks) = tasks::fetch_tagged_tasks(&*client, &tag).await {
+                    if let Ok(mut guard) = pending_tag_tasks.lock() {
+                        *guard = Some((tag, tasks));
+                    }
+                    let _ = tx.send(Action::TagTasksFetched);
+                }
+            }
+            if needs_all_tasks {
+                if let Ok(tasks) = tasks::fetch_every_task(&*client).await {
+                    if let Ok(mut guard) = pending_all_tasks.lock() {
+                        *guard = Some(tasks);
+                    }
+                    let _ = tx.send(Action::AllTasksFetched);
+                }
+            }
+            match fetch_all_tasks(&*client, week_days).await {
+                Ok((today, week, inbox)) => {
+                    // Store the tasks in pending storage
+                    if let Ok(mut guard) = pending.lock() {
+                        *guard = Some((today, week, inbox));
+                    }
+                    // Projects are only needed to resolve names, so keep the old list on failure
+                    if let Ok(projects) = client.get_projects().await {
+                        if let Ok(mut guard) = pending_projects.lock() {
+                            *guard = Some(projects);
+                        }
+                    }
+                    let _ = tx.send(Action::TasksFetched);
+                }
+                Err(e) => {
+                    let _ = tx.send(Action::Error(e));
+                }
+            }
+            let _ = tx.send(Action::RefreshFinished);
+        });
+    }
+
+    /// Whether fetched tasks would overwrite local changes the server hasn't confirmed yet
+    fn fetch_is_stale(&mut self) -> bool {
+        if !self.rollbacks.is_empty() {
+            self.stale_refreshes = true;
+        }
+        self.stale_refreshes
+    }
+
+    /// Fetch again once every refresh and pending change has settled
+    fn refetch_if_stale(&mut self, tx: UnboundedSender<Action>) {
+        if self.stale_refreshes && self.refreshes == 0 && self.rollbacks.is_empty() {
+            self.stale_refreshes = false;
+            self.refresh_tasks(tx);
+        }
+    }
+
+    fn tasks_fetched(&mut self) {
+        let tasks_opt = if let Ok(mut guard) = self.pending_tasks.lock() {
+            guard.take()
+        } else {
+            None
+        };
+
+        if let Some((today, week, inbox)) = tasks_opt {
+            if !self.fetch_is_stale() {
+                self.update_cache(today, week, inbox);
+                self.save_snapshot();
+            }
+        }
+
+        if let Some(projects) = self
+            .pending_projects
+            .lock()
+            .ok()
+            .and_then(|mut guard| guard.take())
+        {
+            self.projects = projects;
+        }
+    }
+
+    /// Show the tasks of the project selected in the project list
+    fn open_project(&mut self, tx: UnboundedSender<Action>) {
+        if self.ui.task_list.current_tab != ViewTab::Projects || self.open_project.is_some() {
+            return;
+        }
+        let Some(project) = self
+            .ui
+            .task_list
+            .selected_index()
+            .and_then(|index| self.projects.get(index))
+            .cloned()
+        else {
+            return;
+        };
+
+        self.open_project = Some(project.clone());
+        self.project_cache.clear();
+        self.ui.task_list.select_none();
+        self.caches_changed();
+
+        self.refreshes += 1;
+        let client = Arc::clone(&self.client);
+        let pending = Arc::clone(&self.pending_project_tasks);
+        tokio::spawn(async move {
+            match tasks::fetch_project_tasks(&*client, &project.id).await {
+                Ok(tasks) => {
+                    if let Ok(mut guard) = pending.lock() {
+                        *guard = Some((project.id, tasks));
                     }
-                    Action::SelectNone => {
-                        self.save_task_before_changing_selection(action_tx.clone());
-                        self.ui.task_list.select_none();
-                        self.sync_task_editor_with_selection();
+                    let _ = tx.send(Action::ProjectTasksFetched);
+                }
+                Err(e) => {
+                    let _ = tx.send(Action::Error(e));
+                }
+            }
+            let _ = tx.send(Action::RefreshFinished);
+        });
+    }
+
+    fn project_tasks_fetched(&mut self) {
+        let fetched = self
+            .pending_project_tasks
+            .lock()
+            .ok()
+            .and_then(|mut guard| guard.take());
+        if self.fetch_is_stale() {
+            return;
+        }
+
+        // Ignore results for a project that was closed while loading
+        if let (Some((project_id, mut tasks)), Some(project)) = (fetched, &self.open_project) {
+            if project.id.0 == project_id.0 {
+                tasks::sort_tasks(&mut tasks);
+                self.project_cache = tasks;
+                self.caches_changed();
+            }
+        }
+    }
+
+    /// Go back to the project list, selecting the project that was open
+    fn close_project(&mut self) {
+        if self.ui.task_list.current_tab != ViewTab::Projects {
+            return;
+        }
+        if let Some(project) = self.open_project.take() {
+            self.project_cache.clear();
+            self.task_editor_focused = false;
+            self.ui.task_list.exit_visual_mode();
+            self.ui.task_list.select_none();
+            let index = self.projects.iter().position(|p| p.id.0 == project.id.0);
+            self.ui.task_list.get_list_state_mut().select(index);
+            self.sync_task_editor_with_selection();
+        }
+    }
+
+    /// Show every task with the tag selected in the tag list
+    fn open_tag(&mut self, tx: UnboundedSender<Action>) {
+        if self.ui.task_list.current_tab != ViewTab::Tags || self.open_tag.is_some() {
+            return;
+        }
+        let Some(tag) = self
+            .ui
+            .task_list
+            .selected_index()
+            .and_then(|index| self.known_tags().get(index).cloned())
+        else {
+            return;
+        };
+
+        // Start with the tagged tasks already at hand while the rest load
+        let mut tagged = Vec::new();
+        for task in self
+            .today_cache
+            .iter()
+            .chain(&self.week_cache)
+            .chain(&self.inbox_cache)
+            .chain(&self.project_cache)
+        {
+            if tasks::has_tag(task, &tag)
+                && !tagged
+                    .iter()
+                    .any(|t: &Task| same_task_id(t.get_id(), task.get_id()))
+            {
+                tagged.push(task.clone());
+            }
+        }
+        tasks::sort_tasks(&mut tagged);
+        self.open_tag = Some(tag.clone());
+        self.tag_cache = tagged;
+        self.ui.task_list.select_none();
+        self.caches_changed();
+
+        self.refreshes += 1;
+        let client = Arc::clone(&self.client);
+        let pending = Arc::clone(&self.pending_tag_tasks);
+        tokio::spawn(async move {
+            match tasks::fetch_tagged_tasks(&*client, &tag).await {
+                Ok(tasks) => {
+                    if let Ok(mut guard) = pending.lock() {
+                        *guard = Some((tag, tasks));
                     }
+                    let _ = tx.send(Action::TagTasksFetched);
+                }
+                Err(e) => {
+                    let _ = tx.send(Action::Error(e));
+                }
+            }
+            let _ = tx.send(Action::RefreshFinished);
+        });
+    }
+
+    fn tag_tasks_fetched(&mut self) {
+        let fetched = self
+            .pending_tag_tasks
+            .lock()
+            .ok()
+            .and_then(|mut guard| guard.take());
+        if self.fetch_is_stale() {
+            return;
+        }
+
+        // Ignore results for a tag that was closed while loading
+        if let (Some((tag, tasks)), Some(open_tag)) = (fetched, &self.open_tag) {
+            if tag.eq_ignore_ascii_case(open_tag) {
+                self.tag_cache = tasks;
+                self.caches_changed();
+            }
+        }
+    }
+
+    /// Go back to the tag list, selecting the tag that was open
+    fn close_tag(&mut self) {
+        if self.ui.task_list.current_tab != ViewTab::Tags {
+            return;
+        }
+        if let Some(tag) = self.open_tag.take() {
+            self.tag_cache.clear();
+            self.task_editor_focused = false;
+            self.ui.task_list.exit_visual_mode();
+            self.ui.task_list.select_none();
+            let index = self
+                .known_tags()
+                .iter()
+                .position(|t| t.eq_ignore_ascii_case(&tag));
+            self.ui.task_list.get_list_state_mut().select(index);
+            self.sync_task_editor_with_selection();
+        }
+    }
+
+    /// Open the search prompt, keeping any search that is already active
+    fn start_search(&mut self) {
+        self.mode = Mode::Search;
+        if self.search.is_none() {
+            self.search = Some(Search::default());
+        }
+    }
+
+    /// Narrow the list to tasks matching `query` and select the first match
+    fn set_search(&mut self, query: String) {
+        if let Some(search) = &mut self.search {
+            search.query = query;
+        }
+        self.ui.task_list.select_none();
+        self.caches_changed();
+        let len = self.view_len();
+        self.ui.task_list.select_first(len);
+        self.sync_task_editor_with_selection();
+    }
+
+    fn toggle_search_scope(&mut self, tx: UnboundedSender<Action>) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+        search.scope = match search.scope {
+            SearchScope::View => SearchScope::All,
+            SearchScope::All => SearchScope::View,
+        };
+        let (scope, query) = (search.scope, search.query.clone());
+        if scope == SearchScope::All {
+            self.load_all_tasks(tx);
+        } else if !self.needs_all_tasks() {
+            self.all_cache.clear();
+        }
+        self.set_search(query);
+    }
 
-                    Action::PreviousTab => {
-                        self.save_task_before_changing_selection(action_tx.clone());
-                        self.previous_tab();
-                    }
-                    Action::NextTab => {
-                        self.save_task_before_changing_selection(action_tx.clone());
-                        self.next_tab();
-                    }
+    /// Load every task to search or query, starting with the ones already at hand
+    fn load_all_tasks(&mut self, tx: UnboundedSender<Action>) {
+        let mut known: Vec<Task> = Vec::new();
+        for task in self
+            .today_cache
+            .iter()
+            .chain(&self.week_cache)
+            .chain(&self.inbox_cache)
+            .chain(&self.project_cache)
+            .chain(&self.tag_cache)
+        {
+            if !known
+                .iter()
+                .any(|t| same_task_id(t.get_id(), task.get_id()))
+            {
+                known.push(task.clone());
+            }
+        }
+        tasks::sort_tasks(&mut known);
+        self.all_cache = known;
 
-                    Action::CompleteTask => self.complete_task(action_tx.clone()),
-                    Action::StartCompleteTask => self.start_complete_task(),
-                    Action::StartDeleteTask => self.start_delete_task(),
-                    Action::DeleteTask => self.delete_task(action_tx.clone()),
-                    Action::StartPostponeTask => self.start_postpone_task(),
-                    Action::StartCreateTask => self.start_create_task(),
-                    Action::StartEditTask => self.start_edit_task(),
-                    Action::CancelInput => self.cancel_input(),
-                    Action::ConfirmInput => self.confirm_input(action_tx.clone()),
-
-                    Action::EnterNormal => {
-                        self.mode = Mode::Normal;
-                        self.ui.task_list.exit_visual_mode();
-                    }
-                    Action::EnterInsert => {
-                        self.mode = Mode::Insert;
-                        self.ui.task_list.exit_visual_mode();
-                    }
-                    Action::EnterVisual => {
-                        self.mode = Mode::Visual;
-                        self.ui.task_list.enter_visual_mode();
-                    }
-                    Action::EnterProcessing => self.mode = Mode::Processing,
-                    Action::ExitProcessing => {
-                        self.mode = Mode::Normal;
-                        self.ui.task_list.exit_visual_mode();
+        let client = Arc::clone(&self.client);
+        let pending = Arc::clone(&self.pending_all_tasks);
+        tokio::spawn(async move {
+            match tasks::fetch_every_task(&*client).await {
+                Ok(tasks) => {
+                    if let Ok(mut guard) = pending.lock() {
+                        *guard = Some(tasks);
                     }
-
-                    Action::EnterTaskEditor => self.enter_task_editor(),
-                    Action::ExitTaskEditor => self.exit_task_editor(action_tx.clone()),
-
-                    Action::TaskOperationComplete => todo!(),
-                    Action::TasksFetched => self.tasks_fetched(),
+                    let _ = tx.send(Action::AllTasksFetched);
+                }
+                Err(e) => {
+                    let _ = tx.send(Action::Error(e));
                 }
             }
+        });
+    }
 
-            if self.should_quit {
-                tui.stop()?;
-                break;
-            }
+    fn all_tasks_fetched(&mut self) {
+        let fetched = self
+            .pending_all_tasks
+            .lock()
+            .ok()
+            .and_then(|mut guard| guard.take());
+        if self.fetch_is_stale() {
+            return;
         }
 
-        tui.exit()?;
-        Ok(())
+        // Ignore results for a search or saved tab that was left while loading
+        if let (Some(tasks), true) = (fetched, self.needs_all_tasks()) {
+            self.all_cache = tasks;
+            self.caches_changed();
+        }
     }
 
-    /// Get the tasks for a specific view from cache
-    fn get_view_tasks(&self, tab: ViewTab) -> &Vec<Task> {
-        match tab {
-            ViewTab::Today => &self.today_cache,
-            ViewTab::Week => &self.week_cache,
-            ViewTab::Inbox => &self.inbox_cache,
+    /// Close the prompt, dropping a search that matches everything
+    fn confirm_search(&mut self) {
+        if self
+            .search
+            .as_ref()
+            .is_some_and(|search| search.query.trim().is_empty())
+        {
+            self.clear_search();
+        } else {
+            self.mode = Mode::Normal;
         }
     }
 
-    /// Update the cache with new tasks and refresh the UI
-    fn update_cache(
-        &mut self,
-        today_tasks: Vec<Task>,
-        week_tasks: Vec<Task>,
-        inbox_tasks: Vec<Task>,
-    ) {
-        // Update the caches
-        self.today_cache = today_tasks;
-        self.week_cache = week_tasks;
-        self.inbox_cache = inbox_tasks;
-        self.tasks_loaded = true;
+    fn clear_search(&mut self) {
+        self.mode = Mode::Normal;
+        self.search = None;
+        if !self.needs_all_tasks() {
+            self.all_cache.clear();
+        }
+        self.ui.task_list.select_none();
+        self.caches_changed();
+    }
 
-        // Update UI with current view's tasks
-        let current_tasks = match self.ui.task_list.get_current_tab() {
-            ViewTab::Today => &self.today_cache,
-            ViewTab::Week => &self.week_cache,
-            ViewTab::Inbox => &self.inbox_cache,
-        };
-        self.ui.task_list.set_tasks(current_tasks);
-        self.sync_task_editor_with_selection();
+    /// Load every task when switching to a tab that lists a query
+    fn load_query_tab(&mut self, tx: UnboundedSender<Action>) {
+        if self.showing_query_tab() && !self.searching_all() {
+            self.load_all_tasks(tx);
+            self.caches_changed();
+        }
     }
 
-    fn error(&mut self, msg: String) {
-        self.error_message = Some(msg);
-        self.error_ticks = 0;
-        self.mode = Mode::Normal;
+    /// Open the modal to save a query as a tab, starting from the active search
+    ///
+    /// On a saved tab the modal edits that tab instead.
+    fn start_save_query(&mut self) {
+        let (title, name, query) = match self.current_tab {
+            ViewTab::Saved(index) => match self.saved_queries.get(index) {
+                Some(saved) => ("Edit Saved Query", saved.name.clone(), saved.query.clone()),
+                None => return,
+            },
+            _ => {
+                let query = self
+                    .search
+                    .as_ref()
+                    .map(|search| search.query.trim().to_string())
+                    .unwrap_or_default();
+                ("Save Query as Tab", String::new(), query)
+            }
+        };
+        self.mode = Mode::Insert;
+        self.ui
+            .task_list
+            .start_modal(QueryModal::new(title, &name, &query));
     }
 
-    fn next_tick(&mut self) {
-        if self.error_message.is_some() {
-            self.error_ticks += 1;
-            if self.error_ticks > 12 {
-                // Clear after ~3 seconds (at 4 ticks/second)
-                self.error_message = None;
-                self.error_ticks = 0;
+    /// Save the query from the modal and switch to its tab
+    fn save_query(&mut self, values: Vec<String>, tx: UnboundedSender<Action>) {
+        let [name, query] = match <[String; 2]>::try_from(values) {
+            Ok(values) => values,
+            Err(_) => return,
+        };
+        let saved = SavedQuery { name, query };
+        let index = match self.current_tab {
+            ViewTab::Saved(index) if index < self.saved_queries.len() => {
+                self.saved_queries[index] = saved;
+                index
             }
-        }
+            _ => {
+                self.saved_queries.push(saved);
+                self.saved_queries.len() - 1
+            }
+        };
+        self.saved_queries_changed();
+        self.set_status(format!("Saved \"{}\"", self.saved_queries[index].name));
+
+        // The saved tab replaces the search it was made from
+        self.search = None;
+        self.ui.task_list.current_tab = ViewTab::Saved(index);
+        self.current_tab = ViewTab::Saved(index);
+        self.ui.task_list.select_none();
+        self.load_query_tab(tx);
     }
 
-    fn render(&mut self, tui: &mut Tui) -> Result<()> {
-        self.current_tab = self.ui.task_list.get_current_tab();
-        let tasks: &[Task] = match self.current_tab {
-            ViewTab::Today => &self.today_cache,
-            ViewTab::Week => &self.week_cache,
-            ViewTab::Inbox => &self.inbox_cache,
+    /// Remove the current saved tab, moving to the tab before it
+    fn delete_saved_query(&mut self) {
+        let ViewTab::Saved(index) = self.current_tab else {
+            return;
         };
-        let mode = self.mode;
-        let error_message = &self.error_message;
-        let tasks_loaded = self.tasks_loaded;
-        let ui = &mut self.ui;
-        tui.draw(|f| {
-            let _ = ui.draw(
-                f,
-                f.area(),
-                mode,
-                tasks,
-                error_message,
-                tasks_loaded,
-                self.task_editor_focused,
-            );
-        })
+        if index >= self.saved_queries.len() {
+            return;
+        }
+        let position = self
+            .ui
+            .task_list
+            .tabs()
+            .iter()
+            .position(|&tab| tab == self.current_tab)
+            .unwrap_or(0);
+        let removed = self.saved_queries.remove(index);
+        self.saved_queries_changed();
+        self.ui.task_list.select_tab(position.saturating_sub(1));
+        self.current_tab = self.ui.task_list.current_tab;
+        self.ui.task_list.select_none();
+        if !self.needs_all_tasks() {
+            self.all_cache.clear();
+        }
+        self.caches_changed();
+        self.set_status(format!("Removed \"{}\"", removed.name));
     }
 
-    fn refresh_tasks(&mut self, tx: UnboundedSender<Action>) {
-        self.mode = Mode::Processing;
+    /// Show the saved queries as tabs and remember them for next time
+    fn saved_queries_changed(&mut self) {
+        self.ui.task_list.saved_tabs = self
+            .saved_queries
+            .iter()
+            .map(|saved| saved.name.clone())
+            .collect();
+        if let Some(cache) = &self.cache {
+            let _ = cache.save_queries(&self.saved_queries);
+        }
+    }
+
+    /// Persist the current views so they can be shown offline or at next startup
+    fn save_snapshot(&self) {
+        if let Some(cache) = &self.cache {
+            let snapshot = TaskSnapshot {
+                today: self.today_cache.clone(),
+                week: self.week_cache.clone(),
+                inbox: self.inbox_cache.clone(),
+            };
+            let _ = cache.save_snapshot(&snapshot);
+        }
+    }
+
+    fn complete_task(&mut self, tx: UnboundedSender<Action>) {
+        let selected_tasks = self.selected_tasks();
+        self.history
+            .record(HistoryEntry::Complete(selected_tasks.clone()));
+        let repeating: Vec<String> = selected_tasks.iter().filter_map(repeat_text).collect();
+        self.complete_tasks(selected_tasks, tx);
+        match repeating.as_slice() {
+            [] => {}
+            [repeat] => self.set_status(format!("↻ Next one scheduled ({})", repeat)),
+            _ => self.set_status(format!("↻ Next {} scheduled", repeating.len())),
+        }
+    }
+
+    fn complete_tasks(&mut self, tasks_to_complete: Vec<Task>, tx: UnboundedSender<Action>) {
+        // The server schedules the next occurrence of repeating tasks, so fetch it afterwards
+        let any_repeating = tasks_to_complete
+            .iter()
+            .any(|task| repeat_rule(task).is_some());
+        self.completed.record(&tasks_to_complete, Utc::now());
+        self.save_completed();
+        let operations = self.remove_tasks_optimistically(tasks_to_complete);
+        self.mode = Mode::Normal;
+        self.ui.task_list.exit_visual_mode();
+        if operations.is_empty() {
+            return;
+        }
+
         let client = Arc::clone(&self.client);
-        let pending = Arc::clone(&self.pending_tasks);
         tokio::spawn(async move {
-            match fetch_all_tasks(&client).await {
-                Ok((today, week, inbox)) => {
-                    // Store the tasks in pending storage
-                    if let Ok(mut guard) = pending.lock() {
-                        *guard = Some((today, week, inbox));
+            let mut errors = Vec::new();
+
+            // Complete all selected tasks
+            for (operation_id, project_id, task_id) in operations {
+                let result =
+                    tasks::complete_task_with_client(&*client, &project_id, &task_id).await;
+                match result {
+                    Ok(()) => {
+                        let _ = tx.send(Action::TaskOperationComplete(operation_id));
+                    }
+                    Err(e) => {
+                        errors.push(e);
+                        let _ = tx.send(Action::TaskOperationFailed(operation_id));
                     }
-                    let _ = tx.send(Action::TasksFetched);
-                }
-                Err(e) => {
-                    let _ = tx.send(Action::Error(e));
                 }
             }
-            let _ = tx.send(Action::ExitProcessing);
+
+            // Send error if any tasks failed
+            if !errors.is_empty() {
+                let combined_error = format!(
+                    "Failed to complete {} task(s): {}",
+                    errors.len(),
+                    errors.join(", ")
+                );
+                let _ = tx.send(Action::Error(combined_error));
+            }
+            if any_repeating {
+                let _ = tx.send(Action::RefreshTasks);
+            }
         });
     }
 
-    fn tasks_fetched(&mut self) {
-        let tasks_opt = if let Ok(mut guard) = self.pending_tasks.lock() {
-            guard.take()
-        } else {
-            None
+    /// Mark the selected completed tasks as not done, bringing them back to their views
+    fn reopen_tasks(&mut self, tx: UnboundedSender<Action>) {
+        let selected = self.selected_tasks();
+        if selected.is_empty() {
+            return;
+        }
+        self.mode = Mode::Normal;
+        self.ui.task_list.exit_visual_mode();
+        let status = match selected.as_slice() {
+            [task] => format!("Reopened \"{}\"", task.title),
+            tasks => format!("Reopened {} tasks", tasks.len()),
         };
+        self.history.record(HistoryEntry::Reopen(selected.clone()));
+        self.reopen_completed(selected, tx);
+        self.set_status(status);
+    }
 
-        if let Some((today, week, inbox)) = tasks_opt {
-            self.update_cache(today, week, inbox);
+    /// Recreate the selected deleted tasks from their snapshots
+    fn restore_tasks(&mut self, tx: UnboundedSender<Action>) {
+        let selected = self.selected_tasks();
+        if selected.is_empty() {
+            return;
+        }
+        for task in &selected {
+            self.trash.remove(task.get_id());
         }
+        self.save_trash();
+        self.mode = Mode::Normal;
+        self.ui.task_list.exit_visual_mode();
+        let status = match selected.as_slice() {
+            [task] => format!("Restored \"{}\"", task.title),
+            tasks => format!("Restored {} tasks", tasks.len()),
+        };
+        let placeholders = self.recreate_tasks(selected, tx);
+        self.history.record(HistoryEntry::Create(placeholders));
+        self.set_status(status);
     }
 
-    fn complete_task(&mut self, tx: UnboundedSender<Action>) {
-        let selected_indices = self.ui.task_list.get_selected_indices();
-        if !selected_indices.is_empty() {
-            let current_tab = self.ui.task_list.get_current_tab();
-            let tasks_to_complete: Vec<_> = selected_indices
-                .iter()
-                .filter_map(|&index| self.get_view_tasks(current_tab).get(index))
-                .map(|task| (task.get_id().clone(), task.project_id.clone()))
-                .collect();
-
-            if !tasks_to_complete.is_empty() {
-                let client = Arc::clone(&self.client);
-                self.mode = Mode::Processing;
-
-                tokio::spawn(async move {
-                    let mut errors = Vec::new();
-
-                    // Complete all selected tasks
-                    for (task_id, project_id) in tasks_to_complete {
-                        let result =
-                            tasks::complete_task_with_client(&client, &project_id, &task_id).await;
-                        if let Err(e) = result {
-                            errors.push(e);
-                        }
-                    }
+    fn save_completed(&self) {
+        if let Some(cache) = &self.cache {
+            let _ = cache.save_completed(&self.completed);
+        }
+    }
 
-                    // Send error if any tasks failed
-                    if !errors.is_empty() {
-                        let combined_error = format!(
-                            "Failed to complete {} task(s): {}",
-                            errors.len(),
-                            errors.join(", ")
-                        );
-                        let _ = tx.send(Action::Error(combined_error));
-                    } else {
-                        let _ = tx.send(Action::RefreshTasks);
-                    }
-                    let _ = tx.send(Action::ExitProcessing);
-                });
-            }
+    fn save_trash(&self) {
+        if let Some(cache) = &self.cache {
+            let _ = cache.save_trash(&self.trash);
         }
     }
 
     fn delete_task(&mut self, tx: UnboundedSender<Action>) {
-        let selected_indices = self.ui.task_list.get_selected_indices();
-        if !selected_indices.is_empty() {
-            let current_tab = self.ui.task_list.get_current_tab();
-            let tasks_to_delete: Vec<_> = selected_indices
-                .iter()
-                .filter_map(|&index| self.get_view_tasks(current_tab).get(index))
-                .map(|task| (task.get_id().clone(), task.project_id.clone()))
-                .collect();
-
-            if !tasks_to_delete.is_empty() {
-                let client = Arc::clone(&self.client);
-                self.mode = Mode::Processing;
-
-                tokio::spawn(async move {
-                    let mut errors = Vec::new();
-
-                    // Delete all selected tasks
-                    for (task_id, project_id) in tasks_to_delete {
-                        match ticks::tasks::Task::get(&client, &project_id, &task_id).await {
-                            Ok(task) => {
-                                let result = utils::delete_task(task).await;
-                                if let Err(e) = result {
-                                    errors.push(e);
-                                }
-                            }
-                            Err(e) => {
-                                errors.push(format!("Failed to fetch task: {:?}", e));
-                            }
-                        }
-                    }
+        let selected_tasks = self.selected_tasks();
+        self.history
+            .record(HistoryEntry::Delete(selected_tasks.clone()));
+        self.delete_tasks(selected_tasks, tx);
+    }
 
-                    // Send error if any tasks failed
-                    if !errors.is_empty() {
-                        let combined_error = format!(
-                            "Failed to delete {} task(s): {}",
-                            errors.len(),
-                            errors.join(", ")
-                        );
-                        let _ = tx.send(Action::Error(combined_error));
-                    } else {
-                        let _ = tx.send(Action::RefreshTasks);
+    fn delete_tasks(&mut self, tasks_to_delete: Vec<Task>, tx: UnboundedSender<Action>) {
+        self.trash.record(&tasks_to_delete, Utc::now());
+        self.save_trash();
+        let operations = self.remove_tasks_optimistically(tasks_to_delete);
+        self.mode = Mode::Normal;
+        self.ui.task_list.exit_visual_mode();
+        if operations.is_empty() {
+            return;
+        }
+
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            let mut errors = Vec::new();
+
+            // Delete all selected tasks
+            for (operation_id, project_id, task_id) in operations {
+                let result = utils::delete_task(&*client, &project_id, &task_id).await;
+                match result {
+                    Ok(()) => {
+                        let _ = tx.send(Action::TaskOperationComplete(operation_id));
                     }
-                    let _ = tx.send(Action::ExitProcessing);
-                });
+                    Err(e) => {
+                        errors.push(e);
+                        let _ = tx.send(Action::TaskOperationFailed(operation_id));
+                    }
+                }
             }
-        }
+
+            // Send error if any tasks failed
+            if !errors.is_empty() {
+                let combined_error = format!(
+                    "Failed to delete {} task(s): {}",
+                    errors.len(),
+                    errors.join(", ")
+                );
+                let _ = tx.send(Action::Error(combined_error));
+            }
+        });
     }
 
     fn start_postpone_task(&mut self) {
         if let Some(task_index) = self.ui.task_list.selected_index() {
             let current_tab = self.ui.task_list.get_current_tab();
-            if let Some(_task) = self.get_view_tasks(current_tab).get(task_index) {
+            if let Some(task) = self.get_view_tasks(current_tab).get(task_index) {
+                // Postponing moves this occurrence only, so show the repeat it keeps
+                let title = match repeat_text(task) {
+                    Some(repeat) => format!("Postpone Task (↻ {})", repeat),
+                    None => "Postpone Task".to_string(),
+                };
                 // Default to 1 day from now
-                let mut modal = PostponeModal::new("Postpone Task");
+                let mut modal = PostponeModal::new(&title);
                 modal.set_editor_to_insert_mode();
 
                 self.mode = Mode::Insert;
@@ -416,6 +1866,172 @@ impl App {
         }
     }
 
+    fn start_move_tasks(&mut self) {
+        let count = self.selected_tasks().len();
+        if count == 0 {
+            return;
+        }
+        let title = if count == 1 {
+            "Move Task".to_string()
+        } else {
+            format!("Move {} Tasks", count)
+        };
+        self.mode = Mode::Insert;
+        let modal = MoveModal::new(&title, self.project_choices());
+        self.ui.task_list.start_modal(modal);
+    }
+
+    /// Move the selected tasks to a project
+    fn move_tasks(&mut self, project_id: ProjectID, tx: UnboundedSender<Action>) {
+        let edits: Vec<_> = self
+            .selected_tasks()
+            .into_iter()
+            .filter(|task| task.project_id.0 != project_id.0)
+            .map(|task| {
+                let changes = TaskChanges {
+                    project: Some(project_id.clone()),
+                    ..Default::default()
+                };
+                (task, changes)
+            })
+            .collect();
+        if edits.is_empty() {
+            return;
+        }
+        let (before, after) = self.edit_tasks(edits, "move", tx);
+        self.history.record(HistoryEntry::Edit {
+            verb: "move".to_string(),
+            before,
+            after,
+        });
+    }
+
+    /// Set the priority of the selected tasks
+    fn set_priority(&mut self, priority: TaskPriority, tx: UnboundedSender<Action>) {
+        let edits: Vec<_> = self
+            .selected_tasks()
+            .into_iter()
+            .filter(|task| priority_name(&task.priority) != priority_name(&priority))
+            .map(|task| {
+                let changes = TaskChanges {
+                    priority: Some(priority.clone()),
+                    ..Default::default()
+                };
+                (task, changes)
+            })
+            .collect();
+        self.ui.task_list.exit_visual_mode();
+        self.mode = Mode::Normal;
+        if edits.is_empty() {
+            return;
+        }
+        let (before, after) = self.edit_tasks(edits, "reprioritize", tx);
+        self.history.record(HistoryEntry::Edit {
+            verb: "reprioritize".to_string(),
+            before,
+            after,
+        });
+    }
+
+    /// Open a modal to set the tags of the selected tasks
+    fn start_tag_tasks(&mut self) {
+        let selected = self.selected_tasks();
+        if selected.is_empty() {
+            return;
+        }
+        let title = if selected.len() == 1 {
+            "Tag Task".to_string()
+        } else {
+            format!("Tag {} Tasks", selected.len())
+        };
+        self.mode = Mode::Insert;
+        let modal = TagModal::new(
+            &title,
+            TagModalKind::SetTags,
+            self.known_tags(),
+            &common_tags(&selected),
+        );
+        self.ui.task_list.start_modal(modal);
+    }
+
+    /// Replace the tags shared by the selected tasks, keeping tags only some of them have
+    fn set_tags(&mut self, tags: Vec<String>, tx: UnboundedSender<Action>) {
+        let selected = self.selected_tasks();
+        let removed: Vec<String> = common_tags(&selected)
+            .into_iter()
+            .filter(|tag| !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+            .collect();
+        let edits: Vec<_> = selected
+            .into_iter()
+            .filter_map(|task| {
+                let mut new_tags: Vec<String> = task
+                    .tags
+                    .iter()
+                    .filter(|tag| !removed.iter().any(|r| r.eq_ignore_ascii_case(tag)))
+                    .cloned()
+                    .collect();
+                for tag in &tags {
+                    if !new_tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                        new_tags.push(tag.clone());
+                    }
+                }
+                if new_tags == task.tags {
+                    return None;
+                }
+                let changes = TaskChanges {
+                    tags: Some(new_tags),
+                    ..Default::default()
+                };
+                Some((task, changes))
+            })
+            .collect();
+        if edits.is_empty() {
+            return;
+        }
+        let (before, after) = self.edit_tasks(edits, "tag", tx);
+        self.history.record(HistoryEntry::Edit {
+            verb: "tag".to_string(),
+            before,
+            after,
+        });
+    }
+
+    fn start_tag_filter(&mut self) {
+        self.mode = Mode::Insert;
+        let modal = TagModal::new(
+            "Filter by Tag",
+            TagModalKind::Filter,
+            self.known_tags(),
+            &self.tag_filter,
+        );
+        self.ui.task_list.start_modal(modal);
+    }
+
+    /// Only show tasks with all of `tags` in every view (no tags shows everything)
+    fn set_tag_filter(&mut self, tags: Vec<String>) {
+        self.tag_filter = tags;
+        self.ui.task_list.select_none();
+        self.caches_changed();
+    }
+
+    fn toggle_hide_unstarted(&mut self) {
+        self.hide_unstarted = !self.hide_unstarted;
+        self.ui.task_list.select_none();
+        self.caches_changed();
+        self.set_status(if self.hide_unstarted {
+            "Hiding tasks that haven't started".to_string()
+        } else {
+            "Showing all tasks".to_string()
+        });
+    }
+
+    fn next_theme(&mut self) {
+        self.config.theme = (self.config.theme + 1) % self.config.themes.len();
+        self.ui.palette = self.config.palette();
+        let name = self.config.themes[self.config.theme].name.clone();
+        self.set_status(format!("Theme: {}", name));
+    }
+
     fn start_create_task(&mut self) {
         self.mode = Mode::Insert;
         // Set default date to today if in Today view
@@ -424,13 +2040,59 @@ impl App {
         } else {
             None
         };
-        let modal = TaskModal::new_with_defaults("New Task", None, None, default_date, None, false);
+        let title = match (&self.default_project(), &self.open_project) {
+            (Some(_), Some(project)) => format!("New Task in {}", project.name),
+            _ => "New Task".to_string(),
+        };
+        let project_name = self
+            .default_project()
+            .and_then(|project_id| self.project_name(&project_id));
+        let modal = TaskModal::new_with_defaults(&title, None, None, default_date, None, false)
+            .with_projects(self.project_choices(), project_name.as_deref())
+            .with_tags(self.known_tags(), &self.default_tags());
+        self.ui.task_list.start_modal(modal);
+    }
+
+    fn start_quick_add(&mut self) {
+        self.mode = Mode::Insert;
+        let modal = QuickAddModal::new("Quick Add", self.projects.clone(), self.default_date());
         self.ui.task_list.start_modal(modal);
     }
 
+    /// Date given to new tasks without one, so they stay in the current view
+    fn default_date(&self) -> Option<NaiveDate> {
+        if self.current_tab == ViewTab::Today {
+            Some(Local::now().date_naive())
+        } else {
+            None
+        }
+    }
+
+    /// Create a task from a quick-add line like `Call vendor tomorrow 3pm !high #billing ^Work`
+    fn quick_add(&mut self, input: &str, tx: UnboundedSender<Action>) {
+        let parsed = parse_quick_add(input);
+        let project_id = parsed
+            .project
+            .as_deref()
+            .and_then(|name| find_project(&self.projects, name))
+            .map(|project| project.id.clone())
+            .or_else(|| self.default_project());
+        let mut new_task = match parsed.new_task(project_id, self.default_date()) {
+            Ok(new_task) => new_task,
+            Err(e) => return self.error(e),
+        };
+        for tag in self.default_tags() {
+            if !new_task.tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+                new_task.tags.push(tag);
+            }
+        }
+        let placeholder = self.create_task_optimistically(new_task, tx);
+        self.history.record(HistoryEntry::Create(vec![placeholder]));
+    }
+
     fn start_delete_task(&mut self) {
         let selected_indices = self.ui.task_list.get_selected_indices();
-        if selected_indices.is_empty() {
+        if selected_indices.is_empty() || self.is_browsing() {
             return;
         }
 
@@ -447,12 +2109,12 @@ impl App {
 
     fn start_complete_task(&mut self) {
         let selected_indices = self.ui.task_list.get_selected_indices();
-        if selected_indices.is_empty() {
+        if selected_indices.is_empty() || self.is_browsing() {
             return;
         }
 
         let count = selected_indices.len();
-        let message = if count == 1 {
+        let mut message = if count == 1 {
             "Are you sure you want to mark this task as complete?".to_string()
         } else {
             format!(
@@ -460,6 +2122,22 @@ impl App {
                 count
             )
         };
+        let repeating: Vec<String> = self
+            .selected_tasks()
+            .iter()
+            .filter_map(repeat_text)
+            .collect();
+        match repeating.as_slice() {
+            [] => {}
+            [repeat] if count == 1 => message.push_str(&format!(
+                "\nIt repeats {}, so the next one will be scheduled.",
+                repeat
+            )),
+            _ => message.push_str(&format!(
+                "\n{} of them repeat and will be scheduled again.",
+                repeating.len()
+            )),
+        }
 
         let modal =
             ConfirmationModal::new_with_type("Complete Task", &message, ConfirmationType::Complete);
@@ -511,15 +2189,32 @@ impl App {
                         task_description,
                         task_date,
                         task_time,
+                        priority_field_text(&task.priority),
+                        task.tags.clone(),
+                        repeat_text(task).unwrap_or_default(),
+                        describe_reminders(&reminders(task), task.is_all_day),
+                        format_start(task),
                         project_id,
                         task_id,
                     )
                 });
 
-            if let Some((task_title, task_description, task_date, task_time, project_id, task_id)) =
-                task_data
+            if let Some((
+                task_title,
+                task_description,
+                task_date,
+                task_time,
+                priority,
+                tags,
+                repeat,
+                task_reminders,
+                start,
+                project_id,
+                task_id,
+            )) = task_data
             {
                 self.mode = Mode::Insert;
+                let project_name = self.project_name(&project_id);
                 self.editing_task = Some((project_id, task_id));
                 let modal = TaskModal::new_with_defaults(
                     "Edit Task",
@@ -528,7 +2223,13 @@ impl App {
                     task_date,
                     task_time,
                     true,
-                );
+                )
+                .with_projects(self.project_choices(), project_name.as_deref())
+                .with_priority(priority)
+                .with_tags(self.known_tags(), &tags)
+                .with_repeat(&repeat)
+                .with_reminders(&task_reminders)
+                .with_start(&start);
                 self.ui.task_list.start_modal(modal);
             }
         }
@@ -573,147 +2274,82 @@ impl App {
                 return;
             }
 
-            let values = if self.ui.task_list.has_modal() {
-                self.ui.task_list.get_modal_values()
-            } else {
-                // No overlay system anymore, this shouldn't happen
-                vec![]
-            };
-
+            let values = if self.ui.task_list.has_modal() {
+                self.ui.task_list.get_modal_values()
+            } else {
+                // No overlay system anymore, this shouldn't happen
+                vec![]
+            };
+
+            if self.ui.task_list.is_quick_add_modal() {
+                if let Some(input) = values.first() {
+                    self.quick_add(input, tx);
+                }
+                self.ui.task_list.close_modal();
+                self.mode = Mode::Normal;
+                return;
+            }
+
+            if self.ui.task_list.is_move_modal() {
+                if let Some(project_id) = values.first().filter(|id| !id.is_empty()) {
+                    self.move_tasks(ProjectID(project_id.clone()), tx);
+                }
+                self.ui.task_list.close_modal();
+                self.ui.task_list.exit_visual_mode();
+                self.mode = Mode::Normal;
+                return;
+            }
+
+            if self.ui.task_list.is_query_modal() {
+                self.save_query(values, tx);
+                self.ui.task_list.close_modal();
+                self.mode = Mode::Normal;
+                return;
+            }
+
+            if let Some(kind) = self.ui.task_list.tag_modal_kind() {
+                match kind {
+                    TagModalKind::SetTags => self.set_tags(values, tx),
+                    TagModalKind::Filter => self.set_tag_filter(values),
+                }
+                self.ui.task_list.close_modal();
+                self.ui.task_list.exit_visual_mode();
+                self.mode = Mode::Normal;
+                return;
+            }
+
             // Check if this is a postpone modal (has 1 value: duration string)
             if self.ui.task_list.has_modal() && values.len() == 1 && !values[0].contains('\n') {
                 // This might be a postpone operation - try to parse as duration
                 if let Ok(postpone_target) = utils::parse_duration(&values[0]) {
                     // This is a postpone operation - handle multiple selected tasks
-                    let selected_indices = self.ui.task_list.get_selected_indices();
-                    if !selected_indices.is_empty() {
-                        let current_tab = self.ui.task_list.get_current_tab();
-                        let tasks_to_postpone: Vec<_> = selected_indices
-                            .iter()
-                            .filter_map(|&index| self.get_view_tasks(current_tab).get(index))
-                            .map(|task| (task.get_id().clone(), task.project_id.clone()))
-                            .collect();
-
-                        if !tasks_to_postpone.is_empty() {
-                            let client = Arc::clone(&self.client);
-                            self.mode = Mode::Processing;
-
-                            tokio::spawn(async move {
-                                let mut errors = Vec::new();
-
-                                // First, fetch all tasks to calculate relative offsets for absolute time targets
-                                let mut tasks_with_data = Vec::new();
-                                for (task_id, project_id) in tasks_to_postpone {
-                                    match ticks::tasks::Task::get(&client, &project_id, &task_id)
-                                        .await
-                                    {
-                                        Ok(task) => {
-                                            tasks_with_data.push(task);
-                                        }
-                                        Err(e) => {
-                                            errors.push(format!("Failed to fetch task: {:?}", e));
-                                        }
-                                    }
-                                }
-
-                                // Calculate the base time for absolute targets
-                                let base_datetime_utc = match &postpone_target {
-                                    utils::PostponeTarget::RelativeToDueDate(_) => {
-                                        // For relative targets, we don't need a base time
-                                        None
-                                    }
-                                    utils::PostponeTarget::AbsoluteTime(datetime) => {
-                                        // For absolute targets, find the earliest task's due date
-                                        if let Some(earliest_task) =
-                                            tasks_with_data.iter().min_by_key(|task| task.due_date)
-                                        {
-                                            Some((
-                                                datetime.with_timezone(&chrono::Utc),
-                                                earliest_task.due_date,
-                                            ))
-                                        } else {
-                                            None
-                                        }
-                                    }
-                                };
-
-                                // Postpone all selected tasks
-                                for mut task in tasks_with_data {
-                                    // Calculate the new due datetime based on the postpone target
-                                    let new_datetime_utc =
-                                        match (&postpone_target, &base_datetime_utc) {
-                                            (
-                                                utils::PostponeTarget::RelativeToDueDate(duration),
-                                                _,
-                                            ) => {
-                                                // Add duration to the task's original due_date
-                                                task.due_date + *duration
-                                            }
-                                            (
-                                                utils::PostponeTarget::AbsoluteTime(_),
-                                                Some((target_time, earliest_due_date)),
-                                            ) => {
-                                                // Calculate the offset from the earliest task and apply it to the target time
-                                                let offset_from_earliest =
-                                                    task.due_date - *earliest_due_date;
-                                                *target_time + offset_from_earliest
-                                            }
-                                            (
-                                                utils::PostponeTarget::AbsoluteTime(datetime),
-                                                None,
-                                            ) => {
-                                                // Fallback: use the absolute datetime (shouldn't happen with proper logic)
-                                                datetime.with_timezone(&chrono::Utc)
-                                            }
-                                        };
-
-                                    // Convert to local timezone before extracting naive date/time
-                                    // so the API interprets them correctly as local time
-                                    let new_datetime_local =
-                                        new_datetime_utc.with_timezone(&chrono::Local);
-                                    let due_date = new_datetime_local.date_naive();
-                                    let due_time = new_datetime_local.time();
-
-                                    let result = tasks::edit_task(
-                                        &mut task,
-                                        None,
-                                        None,
-                                        None,
-                                        None,
-                                        None,
-                                        Some(due_date),
-                                        Some(due_time),
-                                    )
-                                    .await;
-
-                                    if let Err(e) = result {
-                                        errors.push(e);
-                                    }
-                                }
-
-                                // Send error if any tasks failed
-                                if !errors.is_empty() {
-                                    let combined_error = format!(
-                                        "Failed to postpone {} task(s): {}",
-                                        errors.len(),
-                                        errors.join(", ")
-                                    );
-                                    let _ = tx.send(Action::Error(combined_error));
-                                } else {
-                                    let _ = tx.send(Action::RefreshTasks);
-                                }
-                                let _ = tx.send(Action::ExitProcessing);
-                            });
-                        }
-                    }
+                    let selected_tasks = self.selected_tasks();
+                    let due_dates: Vec<_> =
+                        selected_tasks.iter().map(|task| task.due_date).collect();
+                    let edits = selected_tasks
+                        .into_iter()
+                        .zip(postpone_target.new_due_dates(&due_dates))
+                        .map(|(task, new_due)| {
+                            let changes = TaskChanges::postponing(&task, new_due);
+                            (task, changes)
+                        })
+                        .collect();
+                    let (before, after) = self.edit_tasks(edits, "postpone", tx);
+                    self.history.record(HistoryEntry::Edit {
+                        verb: "postpone".to_string(),
+                        before,
+                        after,
+                    });
 
                     self.ui.task_list.close_modal();
+                    self.ui.task_list.exit_visual_mode();
                     self.mode = Mode::Normal;
                     return;
                 }
             }
 
-            // Handle create/edit task modal (has 4 values: title, description, date, time)
+            // Handle create/edit task modal (title, description, date, time, project id, priority, tags,
+            // repeat, reminders, start)
             if !values.is_empty() && !values[0].is_empty() {
                 let title = values[0].clone();
                 let description = if values.len() > 1 {
@@ -731,58 +2367,93 @@ impl App {
                 } else {
                     String::new()
                 };
+                let project = values
+                    .get(4)
+                    .filter(|id| !id.is_empty())
+                    .map(|id| ProjectID(id.clone()));
+                let priority = values
+                    .get(5)
+                    .and_then(|text| parse_priority_field(text).ok())
+                    .unwrap_or(TaskPriority::None);
+                let tags = values
+                    .get(6)
+                    .map(|text| parse_tags(text))
+                    .unwrap_or_default();
+                let repeat = values
+                    .get(7)
+                    .and_then(|text| parse_recurrence(text).ok())
+                    .flatten();
+                let task_reminders = values
+                    .get(8)
+                    .and_then(|text| parse_reminders(text).ok())
+                    .unwrap_or_default();
+                let start_text = values.get(9).cloned().unwrap_or_default();
+                let start = parse_start(&start_text).ok();
+
+                let due_date = parse_date_us_format(&date).ok();
+                let due_time = parse_time_us_format(&time).ok();
+                let content = if !description.is_empty() {
+                    Some(description)
+                } else {
+                    None
+                };
 
-                let client = Arc::clone(&self.client);
-                self.mode = Mode::Processing;
-                let editing_task = self.editing_task.take();
-
-                tokio::spawn(async move {
-                    let due_date = parse_date_us_format(&date).ok();
-                    let due_time = parse_time_us_format(&time).ok();
-
-                    let result = if let Some((project_id, task_id)) = editing_task {
-                        // Editing existing task
-                        match ticks::tasks::Task::get(&client, &project_id, &task_id).await {
-                            Ok(mut task) => {
-                                let content = if !description.is_empty() {
-                                    Some(description)
-                                } else {
-                                    None
-                                };
-                                tasks::edit_task(
-                                    &mut task,
-                                    Some(title),
-                                    None,
-                                    content,
-                                    None,
-                                    None,
-                                    due_date,
-                                    due_time,
-                                )
-                                .await
-                            }
-                            Err(e) => Err(format!("Failed to fetch task: {:?}", e)),
-                        }
-                    } else {
-                        // Creating new task
-                        let content = if !description.is_empty() {
-                            Some(description)
-                        } else {
-                            None
+                if let Some((_, task_id)) = self.editing_task.take() {
+                    // Editing existing task
+                    if let Some((task, _)) = self.find_cached_task(&task_id) {
+                        let changes = TaskChanges {
+                            title: Some(title),
+                            project: project.filter(|p| p.0 != task.project_id.0),
+                            priority: Some(priority)
+                                .filter(|p| priority_name(p) != priority_name(&task.priority)),
+                            tags: Some(tags).filter(|tags| *tags != task.tags),
+                            repeat: Some(repeat).filter(|repeat| *repeat != repeat_rule(&task)),
+                            reminders: Some(task_reminders)
+                                .filter(|triggers| *triggers != reminders(&task)),
+                            start: start.filter(|_| start_text.trim() != format_start(&task)),
+                            content,
+                            date: due_date,
+                            time: due_time,
+                            ..Default::default()
                         };
-                        tasks::create_task(
-                            &client, title, None, content, None, None, due_date, due_time,
-                        )
-                        .await
-                    };
-
-                    if let Err(e) = result {
-                        let _ = tx.send(Action::Error(e));
-                    } else {
-                        let _ = tx.send(Action::RefreshTasks);
+                        let (before, after) = self.edit_tasks(vec![(task, changes)], "edit", tx);
+                        self.history.record(HistoryEntry::Edit {
+                            verb: "edit".to_string(),
+                            before,
+                            after,
+                        });
                     }
-                    let _ = tx.send(Action::ExitProcessing);
-                });
+                } else {
+                    // Creating new task
+                    let created = tasks::new_task(
+                        title,
+                        project.or_else(|| self.default_project()),
+                        content,
+                        None,
+                        Some(priority).filter(|p| !matches!(p, TaskPriority::None)),
+                        due_date,
+                        due_time,
+                    )
+                    .and_then(|mut new_task| {
+                        new_task.tags = tags;
+                        new_task.repeat = repeat;
+                        new_task.reminders = task_reminders;
+                        let due = new_task.due_date.unwrap_or_default();
+                        new_task.start_date = start
+                            .map(|start| start.start_date(due))
+                            .transpose()?
+                            .flatten()
+                            .filter(|start_date| start_date.timestamp() > 0 && *start_date != due);
+                        Ok(new_task)
+                    });
+                    match created {
+                        Ok(new_task) => {
+                            let placeholder = self.create_task_optimistically(new_task, tx);
+                            self.history.record(HistoryEntry::Create(vec![placeholder]));
+                        }
+                        Err(e) => self.error(e),
+                    }
+                }
             }
 
             if self.ui.task_list.has_modal() {
@@ -816,22 +2487,27 @@ impl App {
     fn next_tab(&mut self) {
         self.ui.task_list.next_tab();
         self.current_tab = self.ui.task_list.get_current_tab();
-        match self.current_tab {
-            ViewTab::Today => self.ui.task_list.set_tasks(&self.today_cache),
-            ViewTab::Week => self.ui.task_list.set_tasks(&self.week_cache),
-            ViewTab::Inbox => self.ui.task_list.set_tasks(&self.inbox_cache),
+        let tasks = self.get_view_tasks(self.current_tab);
+        self.ui.task_list.set_tasks(&tasks);
+        self.sync_task_editor_with_selection();
+    }
+
+    /// Jump to the tab at `position` in the tab bar, if there is one
+    fn select_tab(&mut self, position: usize) {
+        if !self.ui.task_list.select_tab(position) {
+            return;
         }
+        self.current_tab = self.ui.task_list.get_current_tab();
+        let tasks = self.get_view_tasks(self.current_tab);
+        self.ui.task_list.set_tasks(&tasks);
         self.sync_task_editor_with_selection();
     }
 
     fn previous_tab(&mut self) {
         self.ui.task_list.previous_tab();
         self.current_tab = self.ui.task_list.get_current_tab();
-        match self.current_tab {
-            ViewTab::Today => self.ui.task_list.set_tasks(&self.today_cache),
-            ViewTab::Week => self.ui.task_list.set_tasks(&self.week_cache),
-            ViewTab::Inbox => self.ui.task_list.set_tasks(&self.inbox_cache),
-        }
+        let tasks = self.get_view_tasks(self.current_tab);
+        self.ui.task_list.set_tasks(&tasks);
         self.sync_task_editor_with_selection();
     }
 
@@ -845,11 +2521,7 @@ impl App {
 
     fn sync_task_editor_with_selection(&mut self) {
         if let Some(selected_index) = self.ui.task_list.selected_index() {
-            let tasks = match self.current_tab {
-                ViewTab::Today => &self.today_cache,
-                ViewTab::Week => &self.week_cache,
-                ViewTab::Inbox => &self.inbox_cache,
-            };
+            let tasks = self.get_view_tasks(self.current_tab);
             if let Some(task) = tasks.get(selected_index) {
                 let date_str = if task.due_date.timestamp() > 0 {
                     let local: DateTime<Local> = task.due_date.with_timezone(&Local);
@@ -863,18 +2535,34 @@ impl App {
                 } else {
                     String::new()
                 };
-                self.ui
-                    .task_editor
-                    .set_values(&task.title, &task.content, &date_str, &time_str);
-                self.ui.task_editor.is_edit_mode = true;
+                let values = FieldValues {
+                    title: task.title.clone(),
+                    description: task.content.clone(),
+                    date: date_str,
+                    time: time_str,
+                    project: self.project_name(&task.project_id).unwrap_or_default(),
+                    priority: priority_field_text(&task.priority).to_string(),
+                    tags: format_tags(&task.tags),
+                    checklist: checklist_items(task),
+                    repeat: repeat_text(task).unwrap_or_default(),
+                    reminders: describe_reminders(&reminders(task), task.is_all_day),
+                    start: format_start(task),
+                };
+                let choices = self.project_choices();
+                let known_tags = self.known_tags();
+                let editor = &mut self.ui.task_editor;
+                editor.project_picker.set_projects(choices);
+                editor.tag_input.set_known_tags(known_tags);
+                editor.set_values(values);
+                editor.is_edit_mode = true;
             } else {
                 // Clear editor fields when selected index is out of range
-                self.ui.task_editor.set_values("", "", "", "");
+                self.ui.task_editor.set_values(FieldValues::default());
                 self.ui.task_editor.is_edit_mode = false;
             }
         } else {
             // Clear editor fields when no task is selected
-            self.ui.task_editor.set_values("", "", "", "");
+            self.ui.task_editor.set_values(FieldValues::default());
             self.ui.task_editor.is_edit_mode = false;
         }
     }
@@ -882,6 +2570,7 @@ impl App {
     fn enter_task_editor(&mut self) {
         if self.ui.task_list.selected_index().is_some() {
             self.task_editor_focused = true;
+            self.ui.task_editor.checklist_focused = false;
             // Task data is already synced by sync_task_editor_with_selection
         }
     }
@@ -912,18 +2601,48 @@ impl App {
         }
     }
 
+    /// Move around the task editor pane, from the fields or the checklist below them
+    fn move_in_editor(&mut self, action: Action, tx: UnboundedSender<Action>) {
+        if !self.task_editor_focused {
+            return;
+        }
+        if self.ui.task_editor.checklist_focused {
+            match action {
+                Action::NextField => self.ui.task_editor.leave_checklist(true),
+                Action::EditorUp | Action::PreviousField => {
+                    self.ui.task_editor.leave_checklist(false)
+                }
+                Action::EditorLeft => self.exit_task_editor(tx),
+                _ => {}
+            }
+            return;
+        }
+        match action {
+            Action::EditorLeft if self.ui.task_editor.is_at_line_start() => {
+                self.exit_task_editor(tx)
+            }
+            Action::EditorLeft => self.ui.task_editor.move_left(),
+            Action::EditorDown => self.ui.task_editor.move_down(),
+            Action::EditorUp => self.ui.task_editor.move_up(),
+            Action::EditorTop => self.ui.task_editor.navigate_to_first_field_or_line(),
+            Action::EditorBottom => self.ui.task_editor.navigate_to_last_field_or_line(),
+            Action::NextField => {
+                self.ui.task_editor.next_input_field();
+                self.ui.task_editor.position_cursor_at_desired_column();
+            }
+            Action::PreviousField => {
+                self.ui.task_editor.previous_input_field();
+                self.ui.task_editor.position_cursor_at_desired_column();
+            }
+            _ => {}
+        }
+    }
+
     fn save_task_from_editor(&mut self, tx: UnboundedSender<Action>) {
         if let Some(selected_index) = self.ui.task_list.selected_index() {
-            let tasks = match self.current_tab {
-                ViewTab::Today => &self.today_cache,
-                ViewTab::Week => &self.week_cache,
-                ViewTab::Inbox => &self.inbox_cache,
-            };
-
-            if let Some(task) = tasks.get(selected_index) {
-                let task_id = task.get_id().clone();
-                let project_id = task.project_id.clone();
+            let tasks = self.get_view_tasks(self.current_tab);
 
+            if let Some(task) = tasks.get(selected_index).cloned() {
                 let title = self.ui.task_editor.get_input_title();
                 let description = self.ui.task_editor.get_input_description();
                 let date_str = self.ui.task_editor.get_input_date();
@@ -942,328 +2661,178 @@ impl App {
                     None
                 };
 
-                let client = Arc::clone(&self.client);
-                self.mode = Mode::Processing;
-                self.task_editor_focused = false;
+                let project = self
+                    .ui
+                    .task_editor
+                    .project_picker
+                    .selected()
+                    .map(|project| project.id.clone())
+                    .filter(|project_id| project_id.0 != task.project_id.0);
 
-                tokio::spawn(async move {
-                    // Get fresh task data
-                    match client.get_project_data(&project_id).await {
-                        Ok(project_data) => {
-                            let task_id_str = format!("{:?}", task_id);
-                            if let Some(mut task) = project_data.tasks.into_iter().find(|t| {
-                                let t_id_str = format!("{:?}", t.get_id());
-                                t_id_str == task_id_str
-                            }) {
-                                let result = tasks::edit_task(
-                                    &mut task,
-                                    if !title.trim().is_empty() {
-                                        Some(title)
-                                    } else {
-                                        None
-                                    },
-                                    None, // project
-                                    if !description.trim().is_empty() {
-                                        Some(description)
-                                    } else {
-                                        None
-                                    },
-                                    None, // description (legacy)
-                                    None, // priority
-                                    parsed_date,
-                                    parsed_time,
-                                )
-                                .await;
-
-                                if let Err(e) = result {
-                                    let _ = tx.send(Action::Error(e));
-                                } else {
-                                    let _ = tx.send(Action::RefreshTasks);
-                                }
-                            } else {
-                                let _ = tx.send(Action::Error("Task not found".to_string()));
-                            }
-                        }
-                        Err(e) => {
-                            let _ = tx.send(Action::Error(format!(
-                                "Failed to get project data: {:?}",
-                                e
-                            )));
-                        }
-                    }
-                    let _ = tx.send(Action::ExitProcessing);
+                let priority = parse_priority_field(&self.ui.task_editor.get_input_priority())
+                    .ok()
+                    .filter(|p| priority_name(p) != priority_name(&task.priority));
+
+                let tags = Some(parse_tags(&self.ui.task_editor.tag_input.text()))
+                    .filter(|tags| *tags != task.tags);
+
+                let items = Some(self.ui.task_editor.checklist.items.clone())
+                    .filter(|items| *items != checklist_items(&task));
+
+                let repeat = parse_recurrence(&self.ui.task_editor.get_input_repeat())
+                    .ok()
+                    .filter(|repeat| *repeat != repeat_rule(&task));
+
+                let task_reminders = parse_reminders(&self.ui.task_editor.get_input_reminders())
+                    .ok()
+                    .filter(|triggers| *triggers != reminders(&task));
+
+                let start_text = self.ui.task_editor.get_input_start();
+                let start = parse_start(&start_text)
+                    .ok()
+                    .filter(|_| start_text.trim() != format_start(&task));
+
+                let changes = TaskChanges {
+                    title: if !title.trim().is_empty() {
+                        Some(title)
+                    } else {
+                        None
+                    },
+                    project,
+                    priority,
+                    tags,
+                    items,
+                    repeat,
+                    reminders: task_reminders,
+                    start,
+                    content: if !description.trim().is_empty() {
+                        Some(description)
+                    } else {
+                        None
+                    },
+                    date: parsed_date,
+                    time: parsed_time,
+                    ..Default::default()
+                };
+
+                self.task_editor_focused = false;
+                let (before, after) = self.edit_tasks(vec![(task, changes)], "edit", tx);
+                self.history.record(HistoryEntry::Edit {
+                    verb: "edit".to_string(),
+                    before,
+                    after,
                 });
             }
         }
     }
 
+    /// The action bound to a key in the first of `modes` that has one
+    fn bound_action(&mut self, modes: &[KeyMode], key: KeyEvent) -> Option<Action> {
+        self.config.keys.lookup(modes, &mut self.pending_keys, key)
+    }
+
+    /// What a bound action means on the current tab, or `None` where it doesn't apply
+    fn resolve_key_action(&self, action: Action) -> Option<Action> {
+        let on_projects = self.current_tab == ViewTab::Projects;
+        match action {
+            // Completed tasks can only be reopened and deleted ones restored
+            Action::StartCompleteTask if self.showing_tab(ViewTab::Completed) => {
+                Some(Action::ReopenTasks)
+            }
+            Action::ReopenTasks if !self.showing_tab(ViewTab::Completed) => None,
+            Action::RestoreTasks if !self.showing_tab(ViewTab::Trash) => None,
+            Action::StartCompleteTask
+            | Action::StartDeleteTask
+            | Action::StartPostponeTask
+            | Action::StartMoveTasks
+            | Action::StartTagTasks
+            | Action::SetPriority(_)
+            | Action::EnterTaskEditor
+            | Action::StartEditTask
+                if self.showing_removed() =>
+            {
+                None
+            }
+            // Opening a task opens the selected project or tag while browsing them
+            Action::EnterTaskEditor
+            | Action::StartEditTask
+            | Action::OpenProject
+            | Action::OpenTag
+                if self.is_browsing() =>
+            {
+                Some(if on_projects {
+                    Action::OpenProject
+                } else {
+                    Action::OpenTag
+                })
+            }
+            Action::EnterVisual
+            | Action::StartMoveTasks
+            | Action::StartTagTasks
+            | Action::SetPriority(_)
+                if self.is_browsing() =>
+            {
+                None
+            }
+            Action::CloseProject | Action::CloseTag => Some(if on_projects {
+                Action::CloseProject
+            } else {
+                Action::CloseTag
+            }),
+            action => Some(action),
+        }
+    }
+
     fn handle_key_event(
         &mut self,
         key: KeyEvent,
         action_tx: &mpsc::UnboundedSender<Action>,
     ) -> Result<()> {
-        use crossterm::event::KeyCode;
+        use crossterm::event::{KeyCode, KeyModifiers};
 
         // Handle modal key events first, regardless of mode
         if self.ui.task_list.has_modal() && self.mode != Mode::Insert {
-            // This is a confirmation modal
-            match key.code {
-                KeyCode::Char('y') | KeyCode::Char('Y') => {
-                    action_tx.send(Action::ConfirmInput)?;
-                    return Ok(());
-                }
-                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                    action_tx.send(Action::CancelInput)?;
-                    return Ok(());
-                }
-                _ => {
-                    // Ignore other keys for confirmation modals
-                    return Ok(());
-                }
+            // This is a confirmation modal, which ignores other keys
+            if let Some(action) = self.bound_action(&[KeyMode::Confirm], key) {
+                action_tx.send(action)?;
             }
+            return Ok(());
         }
 
         match self.mode {
             Mode::Normal => {
-                if self.task_editor_focused {
-                    // Handle keys when task editor is focused
-                    match key.code {
-                        KeyCode::Char('q') => action_tx.send(Action::Quit)?,
-                        KeyCode::Esc => {
-                            // Check current editor mode before handling Esc
-                            let editor = match self.ui.task_editor.current_input_field {
-                                crate::ui::InputField::Title => {
-                                    &self.ui.task_editor.input_title_editor
-                                }
-                                crate::ui::InputField::Description => {
-                                    &self.ui.task_editor.input_description_editor
-                                }
-                                crate::ui::InputField::Date => {
-                                    &self.ui.task_editor.input_date_editor
-                                }
-                                crate::ui::InputField::Time => {
-                                    &self.ui.task_editor.input_time_editor
-                                }
-                            };
-
-                            // If in Insert, Visual, or Search mode, let edtui handle Esc
-                            if editor.mode != edtui::EditorMode::Normal {
-                                let _ = self.ui.task_editor.handle_input_key_event(key);
-                            } else {
-                                // Already in Normal mode, exit task editor
-                                action_tx.send(Action::ExitTaskEditor)?
-                            }
-                        }
-                        KeyCode::Char('h') | KeyCode::Left => {
-                            // Try edtui first, fallback to pane navigation if at line start
-                            if self.ui.task_editor.is_at_line_start() {
-                                action_tx.send(Action::ExitTaskEditor)?
-                            } else {
-                                let _ = self.ui.task_editor.handle_input_key_event(key);
-                                // Update desired column after horizontal movement
-                                self.ui.task_editor.update_desired_column();
-                            }
-                        }
-                        KeyCode::Char('l') | KeyCode::Right => {
-                            // Always let edtui handle 'l' - it handles cursor movement within fields
-                            let _ = self.ui.task_editor.handle_input_key_event(key);
-                            // Update desired column after horizontal movement
-                            self.ui.task_editor.update_desired_column();
-                        }
-                        KeyCode::Char('j') | KeyCode::Down => {
-                            // Check if current editor is in insert mode first
-                            if self.ui.task_editor.is_current_editor_in_insert_mode() {
-                                // In insert mode, just let edtui handle it normally (insert 'j' character)
-                                let _ = self.ui.task_editor.handle_input_key_event(key);
-                            } else {
-                                // In normal mode, handle navigation logic
-                                // Handle j: try moving within multi-line field first, then between fields
-                                if self.ui.task_editor.current_input_field
-                                    == crate::ui::InputField::Description
-                                    && !self.ui.task_editor.is_at_last_line_in_multiline_field()
-                                {
-                                    // Let edtui handle line movement within description
-                                    let _ = self.ui.task_editor.handle_input_key_event(key);
-                                    // Update desired column after vertical movement within same field
-                                    self.ui.task_editor.update_desired_column();
-                                } else {
-                                    // Move to next field
-                                    if !self.ui.task_editor.handle_j_navigation() {
-                                        let _ = self.ui.task_editor.handle_input_key_event(key);
-                                        self.ui.task_editor.update_desired_column();
-                                    }
-                                }
-                            }
-                        }
-                        KeyCode::Char('k') | KeyCode::Up => {
-                            // Check if current editor is in insert mode first
-                            if self.ui.task_editor.is_current_editor_in_insert_mode() {
-                                // In insert mode, just let edtui handle it normally (insert 'k' character)
-                                let _ = self.ui.task_editor.handle_input_key_event(key);
-                            } else {
-                                // In normal mode, handle navigation logic
-                                // Handle k: try moving within multi-line field first, then between fields
-                                if self.ui.task_editor.current_input_field
-                                    == crate::ui::InputField::Description
-                                    && !self.ui.task_editor.is_at_first_line_in_multiline_field()
-                                {
-                                    // Let edtui handle line movement within description
-                                    let _ = self.ui.task_editor.handle_input_key_event(key);
-                                    // Update desired column after vertical movement within same field
-                                    self.ui.task_editor.update_desired_column();
-                                } else {
-                                    // Move to previous field
-                                    if !self.ui.task_editor.handle_k_navigation() {
-                                        let _ = self.ui.task_editor.handle_input_key_event(key);
-                                        self.ui.task_editor.update_desired_column();
-                                    }
-                                }
-                            }
-                        }
-                        KeyCode::Enter => {
-                            // For description field, let edtui handle Enter (newline)
-                            // For other fields, save the task
-                            match self.ui.task_editor.current_input_field {
-                                crate::ui::InputField::Description => {
-                                    let _ = self.ui.task_editor.handle_input_key_event(key);
-                                }
-                                _ => {
-                                    action_tx.send(Action::ConfirmInput)?;
-                                }
-                            }
-                        }
-                        KeyCode::Tab => {
-                            self.ui.task_editor.next_input_field();
-                            self.ui.task_editor.position_cursor_at_desired_column();
-                        }
-                        KeyCode::BackTab => {
-                            self.ui.task_editor.previous_input_field();
-                            self.ui.task_editor.position_cursor_at_desired_column();
-                        }
-                        KeyCode::Char('g') => {
-                            // Check if current editor is in insert mode first
-                            if self.ui.task_editor.is_current_editor_in_insert_mode() {
-                                // In insert mode, just let edtui handle it normally (insert 'g' character)
-                                let _ = self.ui.task_editor.handle_input_key_event(key);
-                            } else {
-                                // In normal mode, move to first line of first field or first line of current field if in description
-                                self.ui.task_editor.navigate_to_first_field_or_line();
-                            }
-                        }
-                        KeyCode::Char('G') => {
-                            // Check if current editor is in insert mode first
-                            if self.ui.task_editor.is_current_editor_in_insert_mode() {
-                                // In insert mode, just let edtui handle it normally (insert 'G' character)
-                                let _ = self.ui.task_editor.handle_input_key_event(key);
-                            } else {
-                                // In normal mode, move to last line of last field or last line of current field if in description
-                                self.ui.task_editor.navigate_to_last_field_or_line();
-                            }
-                        }
-                        KeyCode::Char('s') => {
-                            // Ctrl+S saves the task
-                            if key
-                                .modifiers
-                                .contains(crossterm::event::KeyModifiers::CONTROL)
-                            {
-                                action_tx.send(Action::ConfirmInput)?;
-                            } else {
-                                // Regular 's' command - let edtui handle it
-                                let _ = self.ui.task_editor.handle_input_key_event(key);
-                                self.ui.task_editor.update_desired_column();
-                            }
-                        }
-                        _ => {
-                            // Handle different types of keys for proper desired column behavior
-                            match key.code {
-                                // Keys that should preserve desired column (vertical movement)
-                                KeyCode::Up | KeyCode::Down => {
-                                    let _ = self.ui.task_editor.handle_input_key_event(key);
-                                    // Don't update desired_column for pure vertical movement
-                                }
-                                // Keys that should update desired column (horizontal movement, word movement, etc.)
-                                KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End => {
-                                    let _ = self.ui.task_editor.handle_input_key_event(key);
-                                    self.ui.task_editor.update_desired_column();
-                                }
-                                // Character keys like 'w', 'b', 'e', '0', '$', etc.
-                                KeyCode::Char(c) => match c {
-                                    // Horizontal movement commands that should update desired column
-                                    'w' | 'b' | 'e' | '0' | '$' | '_' | 'f' | 'F' | 't' | 'T' => {
-                                        let _ = self.ui.task_editor.handle_input_key_event(key);
-                                        self.ui.task_editor.update_desired_column();
-                                    }
-                                    // Commands that change to insert mode and should update desired column
-                                    'i' | 'a' | 'I' | 'A' | 'c' | 's' | 'x' | 'r' => {
-                                        let _ = self.ui.task_editor.handle_input_key_event(key);
-                                        self.ui.task_editor.update_desired_column();
-                                    }
-                                    // Vertical movement commands that should preserve desired column
-                                    'j' | 'k' => {
-                                        let _ = self.ui.task_editor.handle_input_key_event(key);
-                                        // Don't update desired_column for these
-                                    }
-                                    // All other character commands
-                                    _ => {
-                                        let _ = self.ui.task_editor.handle_input_key_event(key);
-                                        // For safety, update desired column for unknown commands
-                                        self.ui.task_editor.update_desired_column();
-                                    }
-                                },
-                                // For other keys, use the safe method that updates desired column
-                                _ => {
-                                    let _ = self
-                                        .ui
-                                        .task_editor
-                                        .handle_input_key_event_and_update_column(key);
-                                }
-                            }
+                if self.task_editor_focused && self.ui.task_editor.checklist_focused {
+                    // The checklist handles its own keys and leaves the rest to the keymap
+                    if !self.ui.task_editor.checklist.handle_key_event(key) {
+                        if let Some(action) = self.bound_action(&[KeyMode::Checklist], key) {
+                            action_tx.send(action)?;
                         }
                     }
+                } else if self.task_editor_focused {
+                    // Typing goes to the field, other keys to the keymap and then the field
+                    let action = if self.ui.task_editor.takes_key(key) {
+                        None
+                    } else {
+                        self.bound_action(&[KeyMode::Editor], key)
+                    };
+                    match action {
+                        Some(action) => action_tx.send(action)?,
+                        // The key started a sequence
+                        None if !self.pending_keys.is_empty() => {}
+                        None => self.ui.task_editor.edit_field(key),
+                    }
                 } else {
-                    // Handle keys when task list is focused
-                    match key.code {
-                        KeyCode::Char('q') => action_tx.send(Action::Quit)?,
-                        KeyCode::Esc => action_tx.send(Action::SelectNone)?,
-                        KeyCode::Char('r') => action_tx.send(Action::RefreshTasks)?,
-                        KeyCode::Char('n') => action_tx.send(Action::StartCreateTask)?,
-                        KeyCode::Char('e') => action_tx.send(Action::StartCompleteTask)?,
-                        KeyCode::Char('d') => action_tx.send(Action::StartDeleteTask)?,
-                        KeyCode::Char('p') => {
-                            if key
-                                .modifiers
-                                .contains(crossterm::event::KeyModifiers::CONTROL)
-                                || key
-                                    .modifiers
-                                    .contains(crossterm::event::KeyModifiers::SUPER)
-                            {
-                                action_tx.send(Action::StartPostponeTask)?
-                            }
-                        }
-                        KeyCode::Char('?') => action_tx.send(Action::ToggleHelp)?,
-                        KeyCode::Char('j') => action_tx.send(Action::SelectNext)?,
-                        KeyCode::Char('k') => action_tx.send(Action::SelectPrevious)?,
-                        KeyCode::Down => action_tx.send(Action::SelectNextCycling)?,
-                        KeyCode::Up => action_tx.send(Action::SelectPreviousCycling)?,
-                        KeyCode::Char('g') | KeyCode::Home => {
-                            action_tx.send(Action::SelectFirst)?
-                        }
-                        KeyCode::Char('G') | KeyCode::End => action_tx.send(Action::SelectLast)?,
-
-                        // Tab/BackTab now switch tabs
-                        KeyCode::Tab => action_tx.send(Action::NextTab)?,
-                        KeyCode::BackTab => action_tx.send(Action::PreviousTab)?,
-
-                        // 'l' enters task editor when task is selected
-                        KeyCode::Char('l') | KeyCode::Right => {
-                            if self.ui.task_list.selected_index().is_some() {
-                                action_tx.send(Action::EnterTaskEditor)?;
-                            }
-                        }
-
-                        KeyCode::Enter => action_tx.send(Action::StartEditTask)?,
-                        KeyCode::Char('v') => action_tx.send(Action::EnterVisual)?,
-                        _ => {}
+                    // Keys for the task list come from the keymap
+                    let modes: &[KeyMode] = if self.search.is_some() {
+                        &[KeyMode::Filtered, KeyMode::Normal]
+                    } else {
+                        &[KeyMode::Normal]
+                    };
+                    if let Some(action) = self
+                        .bound_action(modes, key)
+                        .and_then(|action| self.resolve_key_action(action))
+                    {
+                        action_tx.send(action)?;
                     }
                 }
             }
@@ -1274,101 +2843,1152 @@ impl App {
                     // This is a fallback for safety
                     let _ = self.ui.task_editor.handle_input_key_event(key);
                 } else {
-                    // Handle modal insert mode
-                    match key.code {
-                        KeyCode::Esc => {
-                            // If there's a modal, let it handle the Esc key first
-                            if self.ui.task_list.has_modal() {
-                                // Pass Esc to modal - it will return false if it wants the app to handle it
-                                let handled = self
-                                    .ui
-                                    .task_list
-                                    .handle_modal_key_event(key)
-                                    .unwrap_or(false);
-                                if !handled {
-                                    // Modal didn't handle it (already in normal mode), close the modal
-                                    action_tx.send(Action::CancelInput)?;
-                                }
-                            } else {
-                                // No overlay system anymore, should always have modal in Insert mode
-                                action_tx.send(Action::CancelInput)?;
-                            }
-                        }
-                        KeyCode::Enter => {
-                            // Cmd+Enter or Ctrl+Enter always confirms
-                            if key
-                                .modifiers
-                                .contains(crossterm::event::KeyModifiers::CONTROL)
-                                || key
-                                    .modifiers
-                                    .contains(crossterm::event::KeyModifiers::SUPER)
-                            {
-                                action_tx.send(Action::ConfirmInput)?;
-                            }
-                            // For modals, pass Enter to the modal to handle
-                            else {
-                                if self.ui.task_list.has_modal() {
-                                    // Pass Enter to modal - it will return false if it wants the app to handle it
-                                    let handled = self
-                                        .ui
-                                        .task_list
-                                        .handle_modal_key_event(key)
-                                        .unwrap_or(false);
-                                    if !handled {
-                                        // Modal didn't handle it (in normal mode), confirm the input
-                                        action_tx.send(Action::ConfirmInput)?;
-                                    }
-                                }
-                            }
-                        }
-                        _ => {
-                            if self.mode == Mode::Insert {
-                                if self.ui.task_list.has_modal() {
-                                    let _ = self.ui.task_list.handle_modal_key_event(key);
-                                }
-                                // No overlay system anymore
-                            }
+                    // The form gets keys first and passes the ones it leaves to the keymap
+                    let handled = self.ui.task_list.has_modal()
+                        && self
+                            .ui
+                            .task_list
+                            .handle_modal_key_event(key)
+                            .unwrap_or(false);
+                    if !handled {
+                        if let Some(action) = self.bound_action(&[KeyMode::Form], key) {
+                            action_tx.send(action)?;
                         }
                     }
                 }
             }
-            Mode::Visual => match key.code {
-                KeyCode::Esc => action_tx.send(Action::EnterNormal)?,
-                KeyCode::Char('j') => action_tx.send(Action::SelectNext)?,
-                KeyCode::Char('k') => action_tx.send(Action::SelectPrevious)?,
-                KeyCode::Down => action_tx.send(Action::SelectNextCycling)?,
-                KeyCode::Up => action_tx.send(Action::SelectPreviousCycling)?,
-                KeyCode::Tab => action_tx.send(Action::SelectNextCycling)?,
-                KeyCode::BackTab => action_tx.send(Action::SelectPreviousCycling)?,
-                KeyCode::Char('g') | KeyCode::Home => action_tx.send(Action::SelectFirst)?,
-                KeyCode::Char('G') | KeyCode::End => action_tx.send(Action::SelectLast)?,
-                KeyCode::Char('e') => action_tx.send(Action::StartCompleteTask)?,
-                KeyCode::Char('d') => action_tx.send(Action::StartDeleteTask)?,
-
-                KeyCode::Char('p') => {
-                    if key
-                        .modifiers
-                        .contains(crossterm::event::KeyModifiers::CONTROL)
-                        || key
-                            .modifiers
-                            .contains(crossterm::event::KeyModifiers::SUPER)
-                    {
-                        action_tx.send(Action::StartPostponeTask)?
-                    }
+            Mode::Visual => {
+                if let Some(action) = self
+                    .bound_action(&[KeyMode::Visual], key)
+                    .and_then(|action| self.resolve_key_action(action))
+                {
+                    action_tx.send(action)?;
                 }
-                _ => {}
-            },
+            }
             Mode::Processing => {
                 // Ignore input while processing
             }
-            Mode::Help => match key.code {
-                KeyCode::Char('?') | KeyCode::Esc | KeyCode::Char('q') => {
-                    action_tx.send(Action::ToggleHelp)?
+            Mode::Help => {
+                if let Some(action) = self.bound_action(&[KeyMode::Help], key) {
+                    action_tx.send(action)?;
                 }
-                _ => {}
-            },
+            }
+            Mode::Search => {
+                if let Some(action) = self.bound_action(&[KeyMode::Search], key) {
+                    action_tx.send(action)?;
+                    return Ok(());
+                }
+                // Keys that aren't bound edit the query
+                let query = self
+                    .search
+                    .as_ref()
+                    .map(|search| search.query.clone())
+                    .unwrap_or_default();
+                match key.code {
+                    KeyCode::Backspace => {
+                        let mut query = query;
+                        query.pop();
+                        action_tx.send(Action::SetSearch(query))?
+                    }
+                    // Chords like Ctrl+x aren't text
+                    KeyCode::Char(c)
+                        if matches!(key.modifiers, KeyModifiers::NONE | KeyModifiers::SHIFT) =>
+                    {
+                        action_tx.send(Action::SetSearch(format!("{}{}", query, c)))?
+                    }
+                    _ => {}
+                }
+            }
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::{task_fixture, MemoryBackend};
+    use crate::recurrence::set_repeat_rule;
+    use crate::ui::colors::Palette;
+    use chrono::Duration;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use ratatui::{backend::TestBackend, Terminal};
+    use tokio::sync::mpsc::UnboundedReceiver;
+
+    /// Drives an `App` against an in-memory backend and renders into a test terminal
+    struct Harness {
+        app: App,
+        backend: Arc<MemoryBackend>,
+        action_tx: UnboundedSender<Action>,
+        action_rx: UnboundedReceiver<Action>,
+        terminal: Terminal<TestBackend>,
+    }
+
+    impl Harness {
+        async fn new(backend: MemoryBackend) -> Self {
+            Self::with_config(backend, Config::default()).await
+        }
+
+        async fn with_config(backend: MemoryBackend, config: Config) -> Self {
+            let backend = Arc::new(backend);
+            let app = App::new(backend.clone(), None, config).unwrap();
+            let (action_tx, action_rx) = mpsc::unbounded_channel();
+            let terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+            let mut harness = Self {
+                app,
+                backend,
+                action_tx,
+                action_rx,
+                terminal,
+            };
+            harness.action_tx.send(Action::RefreshTasks).unwrap();
+            harness.settle().await;
+            harness
+        }
+
+        /// Process queued actions until spawned work has finished
+        async fn settle(&mut self) {
+            for _ in 0..200 {
+                let mut idle = true;
+                while let Ok(action) = self.action_rx.try_recv() {
+                    idle = false;
+                    self.app.update(action, &self.action_tx);
+                }
+                if idle
+                    && self.app.mode != Mode::Processing
+                    && self.app.refreshes == 0
+                    && self.app.rollbacks.is_empty()
+                {
+                    return;
+                }
+                // Let spawned backend calls run
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            }
+            panic!("app did not settle");
+        }
+
+        async fn press_with(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+            self.app
+                .handle_key_event(KeyEvent::new(code, modifiers), &self.action_tx)
+                .unwrap();
+            self.settle().await;
+        }
+
+        async fn press(&mut self, code: KeyCode) {
+            self.press_with(code, KeyModifiers::NONE).await;
+        }
+
+        async fn type_text(&mut self, text: &str) {
+            for c in text.chars() {
+                self.press(KeyCode::Char(c)).await;
+            }
+        }
+
+        /// Render the app and return the visible text
+        fn screen(&mut self) -> String {
+            let app = &mut self.app;
+            self.terminal.draw(|f| app.draw(f)).unwrap();
+            let buffer = self.terminal.backend().buffer();
+            let mut text = String::new();
+            for y in 0..buffer.area.height {
+                for x in 0..buffer.area.width {
+                    text.push_str(buffer[(x, y)].symbol());
+                }
+                text.push('\n');
+            }
+            text
+        }
+    }
+
+    fn due_in_days(days: i64) -> chrono::DateTime<chrono::Utc> {
+        (Local::now() + Duration::days(days))
+            .date_naive()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap()
+            .to_utc()
+    }
+
+    fn fixture_backend() -> MemoryBackend {
+        let mut water = task_fixture("t1", "inbox", "Water plants");
+        water.due_date = due_in_days(0);
+        let mut report = task_fixture("t2", "work", "Send report");
+        report.due_date = due_in_days(0);
+        let mut dentist = task_fixture("t3", "work", "Dentist");
+        dentist.due_date = due_in_days(3);
+        let someday = task_fixture("t4", "inbox", "Read a book");
+
+        MemoryBackend::new()
+            .with_project("work", "Work")
+            .with_task(water)
+            .with_task(report)
+            .with_task(dentist)
+            .with_task(someday)
+    }
+
+    #[tokio::test]
+    async fn test_initial_load_shows_today_tasks() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        let screen = harness.screen();
+        assert!(screen.contains("Water plants"));
+        assert!(screen.contains("Send report"));
+        assert!(!screen.contains("Dentist"));
+
+        harness.press(KeyCode::Tab).await;
+        let screen = harness.screen();
+        assert!(screen.contains("Dentist"));
+        assert!(!screen.contains("Water plants"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_keeps_mode() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('n')).await;
+        harness.type_text("Buy").await;
+
+        // A refresh started while typing doesn't interrupt the form
+        harness.app.update(Action::RefreshTasks, &harness.action_tx);
+        assert_eq!(harness.app.mode, Mode::Insert);
+        harness.settle().await;
+        assert_eq!(harness.app.mode, Mode::Insert);
+        harness.type_text(" milk").await;
+        harness.press(KeyCode::Enter).await;
+        assert!(harness
+            .backend
+            .tasks()
+            .iter()
+            .any(|t| t.title == "Buy milk"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_keeps_pending_changes() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('j')).await;
+        let title = harness.app.today_cache[0].title.clone();
+        harness.press(KeyCode::Char('e')).await;
+
+        // The refresh reads the server before the task is completed there
+        harness.app.update(Action::RefreshTasks, &harness.action_tx);
+        harness.press(KeyCode::Char('y')).await;
+        assert!(!harness.backend.tasks().iter().any(|t| t.title == title));
+        assert!(!harness.app.today_cache.iter().any(|t| t.title == title));
+        assert!(!harness.screen().contains(&title));
+    }
+
+    #[tokio::test]
+    async fn test_open_project_in_background() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        for _ in 0..4 {
+            harness.press(KeyCode::BackTab).await;
+        }
+        harness.press(KeyCode::Char('j')).await;
+
+        // The project's tasks load like a refresh, without blocking keys
+        harness.app.update(Action::OpenProject, &harness.action_tx);
+        assert_eq!(harness.app.mode, Mode::Normal);
+        harness.settle().await;
+        assert!(harness.screen().contains("Send report"));
+    }
+
+    #[tokio::test]
+    async fn test_create_task() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('n')).await;
+        assert_eq!(harness.app.mode, Mode::Insert);
+        harness.type_text("Buy milk").await;
+        harness.press(KeyCode::Enter).await;
+
+        assert_eq!(harness.app.mode, Mode::Normal);
+        assert!(harness
+            .backend
+            .tasks()
+            .iter()
+            .any(|t| t.title == "Buy milk"));
+        assert!(harness.screen().contains("Buy milk"));
+    }
+
+    #[tokio::test]
+    async fn test_quick_add_task() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('a')).await;
+        harness
+            .type_text("Call vendor today 3pm !high #billing ^work")
+            .await;
+        let screen = harness.screen();
+        assert!(screen.contains("Call vendor"));
+        assert!(screen.contains("#billing"));
+        harness.press(KeyCode::Enter).await;
+
+        assert_eq!(harness.app.mode, Mode::Normal);
+        let tasks = harness.backend.tasks();
+        let task = tasks.iter().find(|t| t.title == "Call vendor").unwrap();
+        assert_eq!(task.project_id.0, "work");
+        assert_eq!(task.tags, vec!["billing"]);
+        assert!(matches!(task.priority, ticks::tasks::TaskPriority::High));
+        assert!(!task.is_all_day);
+        assert!(harness.screen().contains("Call vendor"));
+    }
+
+    #[tokio::test]
+    async fn test_quick_add_rejects_unknown_project() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('a')).await;
+        harness.type_text("Call vendor ^nowhere").await;
+        harness.press(KeyCode::Enter).await;
+
+        assert_eq!(harness.app.mode, Mode::Insert);
+        assert!(harness.screen().contains("No project named \"nowhere\""));
+        assert_eq!(harness.backend.tasks().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_projects_tab() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        for _ in 0..4 {
+            harness.press(KeyCode::BackTab).await;
+        }
+        assert_eq!(harness.app.current_tab, ViewTab::Projects);
+        assert!(harness.screen().contains("Work"));
+
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Enter).await;
+        let screen = harness.screen();
+        assert!(screen.contains("Send report"));
+        assert!(screen.contains("Dentist"));
+        assert!(!screen.contains("Water plants"));
+
+        // New tasks go into the open project
+        harness.press(KeyCode::Char('n')).await;
+        assert!(harness.screen().contains("New Task in Work"));
+        harness.type_text("Draft agenda").await;
+        harness.press(KeyCode::Enter).await;
+        let tasks = harness.backend.tasks();
+        let task = tasks.iter().find(|t| t.title == "Draft agenda").unwrap();
+        assert_eq!(task.project_id.0, "work");
+        assert!(harness.screen().contains("Draft agenda"));
+
+        harness.press(KeyCode::Backspace).await;
+        assert!(harness.app.open_project.is_none());
+        assert!(!harness.screen().contains("Send report"));
+    }
+
+    #[tokio::test]
+    async fn test_move_tasks_in_visual_mode() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Tab).await;
+        assert_eq!(harness.app.current_tab, ViewTab::Inbox);
+
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Char('v')).await;
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Char('m')).await;
+        assert!(harness.screen().contains("Move 2 Tasks"));
+        harness.type_text("wk").await;
+        assert!(harness.screen().contains("Work"));
+        harness.press(KeyCode::Enter).await;
+
+        assert_eq!(harness.app.mode, Mode::Normal);
+        assert!(harness.app.inbox_cache.is_empty());
+        let tasks = harness.backend.tasks();
+        for title in ["Water plants", "Read a book"] {
+            let task = tasks.iter().find(|t| t.title == title).unwrap();
+            assert_eq!(task.project_id.0, "work");
+        }
+
+        harness.press(KeyCode::Char('u')).await;
+        assert_eq!(harness.app.inbox_cache.len(), 2);
+        assert!(harness
+            .backend
+            .tasks()
+            .iter()
+            .all(|t| t.title != "Read a book" || t.project_id.0 == "inbox"));
+    }
+
+    #[tokio::test]
+    async fn test_edit_task_project() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Enter).await;
+        assert!(harness.screen().contains("Edit Task"));
+
+        // Title -> Date -> Time -> Start -> Reminders -> Project, then replace "Inbox" with a search
+        for _ in 0..5 {
+            harness.press(KeyCode::Tab).await;
+        }
+        harness.press(KeyCode::Char('A')).await;
+        for _ in 0.."Inbox".len() {
+            harness.press(KeyCode::Backspace).await;
+        }
+        harness.type_text("work").await;
+        harness.press(KeyCode::Enter).await;
+
+        assert_eq!(harness.app.mode, Mode::Normal);
+        let tasks = harness.backend.tasks();
+        let task = tasks.iter().find(|t| t.title == "Water plants").unwrap();
+        assert_eq!(task.project_id.0, "work");
+        assert_eq!(harness.app.inbox_cache.len(), 1);
+
+        // Unknown projects are rejected
+        harness.press(KeyCode::Enter).await;
+        for _ in 0..5 {
+            harness.press(KeyCode::Tab).await;
+        }
+        harness.press(KeyCode::Char('A')).await;
+        harness.type_text("zzz").await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Insert);
+        assert!(harness.screen().contains("No project matches \"Inboxzzz\""));
+    }
+
+    #[tokio::test]
+    async fn test_set_priority() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Tab).await;
+        let priority_of = |harness: &Harness, title: &str| {
+            let tasks = harness.backend.tasks();
+            let task = tasks.iter().find(|t| t.title == title).unwrap();
+            priority_name(&task.priority)
+        };
+
+        // p and a number set the priority of every selected task
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Char('v')).await;
+        harness.press(KeyCode::Char('j')).await;
+        harness.type_text("p3").await;
+        assert_eq!(harness.app.mode, Mode::Normal);
+        assert_eq!(priority_of(&harness, "Water plants"), "high");
+        assert_eq!(priority_of(&harness, "Read a book"), "high");
+
+        harness.press(KeyCode::Char('u')).await;
+        assert_eq!(priority_of(&harness, "Water plants"), "none");
+        assert_eq!(priority_of(&harness, "Read a book"), "none");
+
+        // The edit modal sends the typed priority
+        harness.press(KeyCode::Char('g')).await;
+        harness.press(KeyCode::Enter).await;
+        for _ in 0..7 {
+            harness.press(KeyCode::Tab).await;
+        }
+        harness.press(KeyCode::Char('A')).await;
+        harness.type_text("urgent").await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Insert);
+        assert!(harness.screen().contains("Invalid priority"));
+
+        for _ in 0.."urgent".len() {
+            harness.press(KeyCode::Backspace).await;
+        }
+        harness.type_text("med").await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Normal);
+        assert_eq!(priority_of(&harness, "Water plants"), "medium");
+    }
+
+    #[tokio::test]
+    async fn test_edit_repeat() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Tab).await;
+
+        // Title -> Date -> Time -> Start -> Reminders -> Project -> Repeat
+        harness.press(KeyCode::Enter).await;
+        for _ in 0..6 {
+            harness.press(KeyCode::Tab).await;
+        }
+        harness.press(KeyCode::Char('A')).await;
+        harness.type_text("every sometimes").await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Insert);
+        assert!(harness.screen().contains("Invalid repeat"));
+
+        for _ in 0.."sometimes".len() {
+            harness.press(KeyCode::Backspace).await;
+        }
+        harness.type_text("weekday").await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Normal);
+        let tasks = harness.backend.tasks();
+        let task = tasks.iter().find(|t| t.title == "Water plants").unwrap();
+        assert_eq!(
+            repeat_rule(task).as_deref(),
+            Some("RRULE:FREQ=WEEKLY;INTERVAL=1;BYDAY=MO,TU,WE,TH,FR")
+        );
+        assert!(harness.screen().contains("↻ every weekday"));
+
+        // Completing it says the next one will be scheduled
+        harness.press(KeyCode::Char('e')).await;
+        assert!(harness.screen().contains("It repeats every weekday"));
+        harness.press(KeyCode::Char('y')).await;
+        assert!(harness.screen().contains("Next one scheduled"));
+    }
+
+    #[tokio::test]
+    async fn test_edit_reminders() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Tab).await;
+
+        // Reminders need a due date to count from
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Enter).await;
+        for _ in 0..4 {
+            harness.press(KeyCode::Tab).await;
+        }
+        harness.press(KeyCode::Char('A')).await;
+        harness.type_text("15m before").await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Insert);
+        assert!(harness.screen().contains("Reminders need a due date"));
+        harness.press(KeyCode::Esc).await;
+        harness.press(KeyCode::Esc).await;
+        assert_eq!(harness.app.mode, Mode::Normal);
+
+        harness.press(KeyCode::Char('k')).await;
+        harness.press(KeyCode::Enter).await;
+        for _ in 0..4 {
+            harness.press(KeyCode::Tab).await;
+        }
+        harness.press(KeyCode::Char('A')).await;
+        harness.type_text("15m before, at due time").await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Normal);
+        let tasks = harness.backend.tasks();
+        let task = tasks.iter().find(|t| t.title == "Water plants").unwrap();
+        assert_eq!(reminders(task), vec!["TRIGGER:-PT15M", "TRIGGER:PT0S"]);
+        assert!(harness.screen().contains("🔔 2 reminders"));
+    }
+
+    #[tokio::test]
+    async fn test_start_date() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Tab).await;
+        let task_named = |harness: &Harness, title: &str| {
+            let tasks = harness.backend.tasks();
+            tasks.into_iter().find(|t| t.title == title).unwrap()
+        };
+
+        // Title -> Date -> Time -> Start
+        harness.press(KeyCode::Enter).await;
+        for _ in 0..3 {
+            harness.press(KeyCode::Tab).await;
+        }
+        harness.press(KeyCode::Char('A')).await;
+        harness.type_text("2 hours before").await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Normal);
+        let water = task_named(&harness, "Water plants");
+        assert_eq!(
+            tasks::separate_start(&water),
+            Some(water.due_date - Duration::hours(2))
+        );
+
+        // A start counted from the due date needs one
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Enter).await;
+        for _ in 0..3 {
+            harness.press(KeyCode::Tab).await;
+        }
+        harness.press(KeyCode::Char('A')).await;
+        harness.type_text("2 hours before").await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Insert);
+        assert!(harness.screen().contains("Needs a due date"));
+
+        for _ in 0.."2 hours before".len() {
+            harness.press(KeyCode::Backspace).await;
+        }
+        harness.type_text("in 3 days").await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Normal);
+        let book = task_named(&harness, "Read a book");
+        assert!(tasks::separate_start(&book).is_some());
+        assert!(!has_started(&book));
+
+        harness.press(KeyCode::Char('H')).await;
+        let screen = harness.screen();
+        assert!(screen.contains("Not started hidden"));
+        assert!(!screen.contains("Read a book"));
+        harness.press(KeyCode::Char('H')).await;
+        assert!(harness.screen().contains("Read a book"));
+    }
+
+    #[tokio::test]
+    async fn test_tags() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Tab).await;
+        let tags_of = |harness: &Harness, title: &str| {
+            let tasks = harness.backend.tasks();
+            tasks
+                .iter()
+                .find(|t| t.title == title)
+                .unwrap()
+                .tags
+                .clone()
+        };
+
+        // Tag both inbox tasks at once
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Char('v')).await;
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Char('t')).await;
+        assert!(harness.screen().contains("Tag 2 Tasks"));
+        harness.type_text("#errands").await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Normal);
+        assert_eq!(tags_of(&harness, "Water plants"), vec!["errands"]);
+        assert_eq!(tags_of(&harness, "Read a book"), vec!["errands"]);
+        assert!(harness.screen().contains("#errands"));
+
+        // The Tags tab lists the tag and opens its tasks
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Tab).await;
+        assert_eq!(harness.app.current_tab, ViewTab::Tags);
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Enter).await;
+        let screen = harness.screen();
+        assert!(screen.contains("Water plants"));
+        assert!(screen.contains("Read a book"));
+        assert!(!screen.contains("Send report"));
+        harness.press(KeyCode::Backspace).await;
+        assert!(harness.app.open_tag.is_none());
+
+        // Filtering by tag narrows every view until cleared
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Char('#')).await;
+        harness.type_text("errands").await;
+        harness.press(KeyCode::Enter).await;
+        let screen = harness.screen();
+        assert!(screen.contains("Filter: #errands"));
+        assert!(screen.contains("Water plants"));
+        assert!(!screen.contains("Send report"));
+
+        harness.press(KeyCode::Char('#')).await;
+        for _ in 0.."errands".len() {
+            harness.press(KeyCode::Backspace).await;
+        }
+        harness.press(KeyCode::Enter).await;
+        assert!(harness.screen().contains("Send report"));
+    }
+
+    #[tokio::test]
+    async fn test_search() {
+        let mut harness = Harness::new(fixture_backend()).await;
+
+        // Typing narrows the current tab as you go
+        harness.press(KeyCode::Char('/')).await;
+        assert_eq!(harness.app.mode, Mode::Search);
+        harness.type_text("REPO").await;
+        harness
+            .press_with(KeyCode::Char('x'), KeyModifiers::CONTROL)
+            .await;
+        assert_eq!(harness.app.search.as_ref().unwrap().query, "REPO");
+        let screen = harness.screen();
+        assert!(screen.contains("Send report"));
+        assert!(!screen.contains("Water plants"));
+        assert_eq!(harness.app.ui.task_list.selected_index(), Some(0));
+
+        // Enter keeps the filter and Esc clears it
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Normal);
+        assert!(!harness.screen().contains("Water plants"));
+        harness.press(KeyCode::Esc).await;
+        assert!(harness.app.search.is_none());
+        assert!(harness.screen().contains("Water plants"));
+
+        // Tab searches every project instead of the current tab
+        harness.press(KeyCode::Char('/')).await;
+        harness.type_text("book").await;
+        assert!(harness.screen().contains("No matching tasks"));
+        harness.press(KeyCode::Tab).await;
+        let screen = harness.screen();
+        assert!(screen.contains("Read a book"));
+        assert!(screen.contains("in all projects"));
+        harness.press(KeyCode::Esc).await;
+        assert_eq!(harness.app.mode, Mode::Normal);
+        assert!(harness.app.all_cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_saved_query_tab() {
+        let mut harness = Harness::new(fixture_backend()).await;
+
+        harness.press(KeyCode::Char('S')).await;
+        assert!(harness.app.ui.task_list.is_query_modal());
+        harness.type_text("Soon").await;
+        harness.press(KeyCode::Tab).await;
+        harness.type_text("owner:me").await;
+        harness.press(KeyCode::Enter).await;
+        // An invalid query keeps the modal open with the parse error
+        assert_eq!(harness.app.mode, Mode::Insert);
+        assert!(harness.screen().contains("Unknown field 'owner'"));
+        for _ in 0.."owner:me".len() {
+            harness.press(KeyCode::Backspace).await;
+        }
+        harness.type_text("due:<=+3d project:work").await;
+        harness.press(KeyCode::Enter).await;
+
+        assert_eq!(harness.app.mode, Mode::Normal);
+        assert_eq!(harness.app.current_tab, ViewTab::Saved(0));
+        let screen = harness.screen();
+        assert!(screen.contains("Soon"));
+        assert!(screen.contains("Send report"));
+        assert!(screen.contains("Dentist"));
+        assert!(!screen.contains("Water plants"));
+        assert!(!screen.contains("Read a book"));
+
+        // Saved tabs sit between the inbox and the projects
+        harness.press(KeyCode::Tab).await;
+        assert_eq!(harness.app.current_tab, ViewTab::Projects);
+        harness.press(KeyCode::BackTab).await;
+        harness.press(KeyCode::BackTab).await;
+        assert_eq!(harness.app.current_tab, ViewTab::Inbox);
+        harness.press(KeyCode::Tab).await;
+        assert!(harness.screen().contains("Dentist"));
+
+        harness.press(KeyCode::Char('X')).await;
+        assert!(harness.app.saved_queries.is_empty());
+        assert_eq!(harness.app.current_tab, ViewTab::Inbox);
+        assert!(harness.app.ui.task_list.saved_tabs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_configured_tabs() {
+        let config = Config::parse(
+            r#"
+            [[tabs]]
+            project = "Work"
+
+            [[tabs]]
+            view = "today"
+            label = "Now"
+
+            [[tabs]]
+            label = "Someday"
+            query = "due:none"
+            "#,
+        )
+        .unwrap();
+        let mut harness = Harness::with_config(fixture_backend(), config).await;
+
+        // The first configured tab shows at startup
+        assert_eq!(harness.app.current_tab, ViewTab::Custom(0));
+        let screen = harness.screen();
+        assert!(screen.contains("Send report"));
+        assert!(screen.contains("Dentist"));
+        assert!(!screen.contains("Water plants"));
+        assert!(screen.contains("Now"));
+        assert_eq!(
+            harness.app.ui.task_list.tabs(),
+            vec![ViewTab::Custom(0), ViewTab::Today, ViewTab::Custom(1)]
+        );
+
+        // Number keys jump straight to a tab
+        harness.press(KeyCode::Char('3')).await;
+        assert_eq!(harness.app.current_tab, ViewTab::Custom(1));
+        assert!(harness.screen().contains("Read a book"));
+        harness.press(KeyCode::Char('2')).await;
+        assert_eq!(harness.app.current_tab, ViewTab::Today);
+        harness.press(KeyCode::Char('9')).await;
+        assert_eq!(harness.app.current_tab, ViewTab::Today);
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Tab).await;
+        assert_eq!(harness.app.current_tab, ViewTab::Custom(0));
+
+        // New tasks go to the tab's project
+        harness.press(KeyCode::Char('a')).await;
+        harness.type_text("Plan offsite").await;
+        harness.press(KeyCode::Enter).await;
+        let tasks = harness.backend.tasks();
+        let task = tasks.iter().find(|t| t.title == "Plan offsite").unwrap();
+        assert_eq!(task.project_id.0, "work");
+        harness.press(KeyCode::Char('j')).await;
+        harness.type_text("p3").await;
+        assert_eq!(harness.app.current_tab, ViewTab::Custom(0));
+        let selected = harness.app.selected_tasks();
+        assert_eq!(priority_name(&selected[0].priority), "high");
+    }
+
+    #[tokio::test]
+    async fn test_custom_keys() {
+        let config = Config::parse(
+            r#"
+            [keys.normal]
+            "g" = "none"
+            "g g" = "SelectFirst"
+            "J" = "SelectNext"
+            "ctrl+x" = "StartDeleteTask"
+
+            [keys.confirm]
+            "o" = "ConfirmInput"
+            "#,
+        )
+        .unwrap();
+        let mut harness = Harness::with_config(fixture_backend(), config).await;
+
+        harness.type_text("JJ").await;
+        assert_eq!(harness.app.ui.task_list.selected_index(), Some(1));
+        // A single g waits for the rest of the sequence
+        harness.press(KeyCode::Char('g')).await;
+        assert_eq!(harness.app.ui.task_list.selected_index(), Some(1));
+        harness.press(KeyCode::Char('g')).await;
+        assert_eq!(harness.app.ui.task_list.selected_index(), Some(0));
+
+        let title = harness.app.today_cache[0].title.clone();
+        harness
+            .press_with(KeyCode::Char('x'), KeyModifiers::CONTROL)
+            .await;
+        assert!(harness.screen().contains("Delete Task"));
+        harness.press(KeyCode::Char('o')).await;
+        assert_eq!(harness.backend.tasks().len(), 3);
+        assert!(!harness
+            .app
+            .today_cache
+            .iter()
+            .any(|task| task.title == title));
+
+        // The help overlay lists the keys as configured
+        harness.press(KeyCode::Char('?')).await;
+        let screen = harness.screen();
+        assert!(screen.contains("j / J"));
+        assert!(screen.contains("Home / g g"));
+    }
+
+    #[tokio::test]
+    async fn test_custom_editor_keys() {
+        let config = Config::parse(
+            r#"
+            [keys.editor]
+            "ctrl+n" = "EditorDown"
+            "q" = "none"
+            "#,
+        )
+        .unwrap();
+        let mut harness = Harness::with_config(fixture_backend(), config).await;
+        harness.press(KeyCode::Char('j')).await;
+        let title = harness.app.today_cache[0].title.clone();
+        harness.press(KeyCode::Char('l')).await;
+
+        harness
+            .press_with(KeyCode::Char('n'), KeyModifiers::CONTROL)
+            .await;
+        assert_eq!(
+            harness.app.ui.task_editor.current_input_field,
+            crate::ui::InputField::Date
+        );
+        harness.press(KeyCode::Char('k')).await;
+        // Unbound keys go to the field
+        harness.press(KeyCode::Char('q')).await;
+        assert!(!harness.app.should_quit);
+
+        // Text typed in insert mode goes to the field, even keys bound in the editor
+        harness.press(KeyCode::Char('A')).await;
+        harness.type_text(" jg").await;
+        harness.press(KeyCode::Esc).await;
+        assert!(harness.app.task_editor_focused);
+        harness.press(KeyCode::Esc).await;
+        assert!(!harness.app.task_editor_focused);
+        let expected = format!("{} jg", title);
+        assert!(harness.backend.tasks().iter().any(|t| t.title == expected));
+
+        // The help lists the editor keys as configured
+        let editor_help = harness
+            .app
+            .ui
+            .help
+            .iter()
+            .find(|section| section.title == "Task Details")
+            .unwrap();
+        assert!(editor_help
+            .lines
+            .iter()
+            .any(|(keys, _)| keys == "j / ↓ / Ctrl+n"));
+    }
+
+    #[tokio::test]
+    async fn test_switch_theme() {
+        let config = Config::parse("theme = \"light\"\n[themes.paper]\nbase = \"light\"").unwrap();
+        let mut harness = Harness::with_config(fixture_backend(), config).await;
+        assert_eq!(harness.app.config.theme, 1);
+
+        assert_eq!(harness.app.ui.palette, Palette::LIGHT);
+
+        harness.press(KeyCode::Char('T')).await;
+        assert!(harness.screen().contains("Theme: high-contrast"));
+        assert_eq!(harness.app.ui.palette, Palette::HIGH_CONTRAST);
+        harness.press(KeyCode::Char('T')).await;
+        assert!(harness.screen().contains("Theme: paper"));
+        // Back around to the first theme
+        harness.press(KeyCode::Char('T')).await;
+        assert_eq!(harness.app.config.theme, 0);
+    }
+
+    #[tokio::test]
+    async fn test_edit_checklist() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('j')).await;
+        let title = harness.app.today_cache[0].title.clone();
+        harness.press(KeyCode::Char('l')).await;
+        // Tab past the last field moves into the checklist
+        for _ in 0..10 {
+            harness.press(KeyCode::Tab).await;
+        }
+        assert!(harness.app.ui.task_editor.checklist_focused);
+
+        harness.press(KeyCode::Char('o')).await;
+        harness.type_text("Soil").await;
+        harness.press(KeyCode::Enter).await;
+        harness.press(KeyCode::Char('o')).await;
+        harness.type_text("Pot").await;
+        harness.press(KeyCode::Enter).await;
+        harness.press(KeyCode::Char('x')).await;
+        harness.press(KeyCode::Char('K')).await;
+        harness.press(KeyCode::Enter).await;
+        assert!(!harness.app.task_editor_focused);
+
+        let tasks = harness.backend.tasks();
+        let task = tasks.iter().find(|t| t.title == title).unwrap();
+        let items = checklist_items(task);
+        let titles: Vec<&str> = items.iter().map(|item| item.title.as_str()).collect();
+        assert_eq!(titles, vec!["Pot", "Soil"]);
+        assert!(items[0].is_checked());
+        assert!(!items[1].is_checked());
+        assert!(harness.screen().contains("☑ 1/2"));
+    }
+
+    #[tokio::test]
+    async fn test_complete_task() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('j')).await;
+        let title = harness.app.today_cache[0].title.clone();
+        harness.press(KeyCode::Char('e')).await;
+        assert!(harness.screen().contains("Complete Task"));
+        harness.press(KeyCode::Char('y')).await;
+
+        assert!(!harness.backend.tasks().iter().any(|t| t.title == title));
+        assert!(!harness.screen().contains(&title));
+    }
+
+    #[tokio::test]
+    async fn test_reopen_completed_task() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('j')).await;
+        let title = harness.app.today_cache[0].title.clone();
+        let id = harness.app.today_cache[0].get_id().clone();
+        harness.press(KeyCode::Char('e')).await;
+        harness.press(KeyCode::Char('y')).await;
+
+        // The Completed tab lists it with its completion time
+        harness.press(KeyCode::BackTab).await;
+        harness.press(KeyCode::BackTab).await;
+        assert_eq!(harness.app.current_tab, ViewTab::Completed);
+        assert_eq!(harness.app.completed.entries().len(), 1);
+        let screen = harness.screen();
+        assert!(screen.contains(&title));
+        assert!(screen.contains("Done"));
+
+        // Completed tasks can't be edited, only reopened
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Enter).await;
+        assert_eq!(harness.app.mode, Mode::Normal);
+        harness.press(KeyCode::Char('e')).await;
+        let screen = harness.screen();
+        assert!(screen.contains("Reopened"));
+        assert!(screen.contains("No completed tasks yet"));
+        assert!(harness.app.today_cache.iter().any(|t| t.title == title));
+
+        // It comes back as the same task rather than a copy
+        let tasks = harness.backend.tasks();
+        let reopened: Vec<&Task> = tasks.iter().filter(|t| t.title == title).collect();
+        assert_eq!(reopened.len(), 1);
+        assert!(same_task_id(reopened[0].get_id(), &id));
+
+        // Undoing the reopen completes it again
+        harness.press(KeyCode::Char('u')).await;
+        assert!(harness.screen().contains("Undid reopen"));
+        assert!(!harness.backend.tasks().iter().any(|t| t.title == title));
+        assert_eq!(harness.app.completed.entries().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_complete_is_rolled_back() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.backend.set_online(false);
+        harness.press(KeyCode::Char('j')).await;
+        let title = harness.app.today_cache[0].title.clone();
+        harness.press(KeyCode::Char('e')).await;
+        harness.press(KeyCode::Char('y')).await;
+
+        let screen = harness.screen();
+        assert!(screen.contains(&title));
+        assert!(screen.contains("Failed to complete 1 task(s)"));
+        assert_eq!(harness.app.today_cache.len(), 2);
+        assert_eq!(harness.backend.tasks().len(), 4);
+        assert!(harness.app.completed.entries().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_undo_and_redo_complete() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('j')).await;
+        let title = harness.app.today_cache[0].title.clone();
+        harness.press(KeyCode::Char('e')).await;
+        harness.press(KeyCode::Char('y')).await;
+        assert_eq!(harness.backend.tasks().len(), 3);
+
+        harness.press(KeyCode::Char('u')).await;
+        let screen = harness.screen();
+        assert!(screen.contains(&format!("Undid complete \"{}\"", title)));
+        assert!(screen.contains(&title));
+        assert!(harness.backend.tasks().iter().any(|t| t.title == title));
+
+        harness
+            .press_with(KeyCode::Char('r'), KeyModifiers::CONTROL)
+            .await;
+        assert!(harness.screen().contains("Redid complete"));
+        assert!(!harness.backend.tasks().iter().any(|t| t.title == title));
+    }
+
+    #[tokio::test]
+    async fn test_undo_complete_of_repeating_task() {
+        let mut plants = task_fixture("t1", "inbox", "Water plants");
+        plants.due_date = due_in_days(0);
+        set_repeat_rule(&mut plants, Some("RRULE:FREQ=DAILY;INTERVAL=1"));
+        let backend = MemoryBackend::new().with_task(plants.clone());
+        let mut harness = Harness::new(backend).await;
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Char('e')).await;
+        harness.press(KeyCode::Char('y')).await;
+
+        // The server keeps the series going at its next date
+        let tasks = harness.backend.tasks();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].due_date, plants.due_date + Duration::days(1));
+
+        // Undo moves the same task back instead of starting a second series
+        harness.press(KeyCode::Char('u')).await;
+        assert!(harness.screen().contains("Undid complete \"Water plants\""));
+        let tasks = harness.backend.tasks();
+        assert_eq!(tasks.len(), 1);
+        assert!(same_task_id(tasks[0].get_id(), plants.get_id()));
+        assert_eq!(tasks[0].due_date, plants.due_date);
+        assert!(repeat_rule(&tasks[0]).is_some());
+        assert_eq!(harness.app.today_cache.len(), 1);
+        assert!(harness.app.completed.entries().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_undo_postpone_restores_due_date() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('j')).await;
+        let task = harness.app.today_cache[0].clone();
+        harness
+            .press_with(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .await;
+        harness.type_text("1day").await;
+        harness.press(KeyCode::Enter).await;
+        assert!(!harness.screen().contains(&task.title));
+
+        harness.press(KeyCode::Char('u')).await;
+        assert!(harness.screen().contains(&task.title));
+        let restored = harness
+            .backend
+            .tasks()
+            .into_iter()
+            .find(|t| t.title == task.title)
+            .unwrap();
+        assert_eq!(restored.due_date, task.due_date);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_delete_keeps_task() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Char('d')).await;
+        harness.press(KeyCode::Char('n')).await;
+
+        assert_eq!(harness.backend.tasks().len(), 4);
+        assert!(!harness.app.ui.task_list.has_modal());
+    }
+
+    #[tokio::test]
+    async fn test_delete_task() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('j')).await;
+        let title = harness.app.today_cache[0].title.clone();
+        harness.press(KeyCode::Char('d')).await;
+        assert!(harness.screen().contains("Delete Task"));
+        harness.press(KeyCode::Char('y')).await;
+
+        assert_eq!(harness.backend.tasks().len(), 3);
+        assert!(!harness.screen().contains(&title));
+    }
+
+    #[tokio::test]
+    async fn test_restore_from_trash() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Tab).await;
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Char('d')).await;
+        harness.press(KeyCode::Char('y')).await;
+        assert!(!harness
+            .backend
+            .tasks()
+            .iter()
+            .any(|t| t.title == "Water plants"));
+
+        // The Trash tab keeps a snapshot of the deleted task
+        harness.press(KeyCode::BackTab).await;
+        harness.press(KeyCode::BackTab).await;
+        harness.press(KeyCode::BackTab).await;
+        assert_eq!(harness.app.current_tab, ViewTab::Trash);
+        let screen = harness.screen();
+        assert!(screen.contains("Water plants"));
+        assert!(screen.contains("Deleted"));
+
+        harness.press(KeyCode::Char('j')).await;
+        harness.press(KeyCode::Char('R')).await;
+        let restored = harness
+            .backend
+            .tasks()
+            .into_iter()
+            .find(|t| t.title == "Water plants")
+            .expect("restored task");
+        assert!(!restored.is_all_day);
+        assert!(harness.screen().contains("Trash is empty"));
+
+        // Undoing the restore deletes it again
+        harness.press(KeyCode::Char('u')).await;
+        assert!(!harness
+            .backend
+            .tasks()
+            .iter()
+            .any(|t| t.title == "Water plants"));
+        assert_eq!(harness.app.trash.entries().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_postpone_task() {
+        let mut harness = Harness::new(fixture_backend()).await;
+        harness.press(KeyCode::Char('j')).await;
+        let title = harness.app.today_cache[0].title.clone();
+        harness
+            .press_with(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .await;
+        assert!(harness.screen().contains("Postpone Task"));
+        harness.type_text("1day").await;
+        harness.press(KeyCode::Enter).await;
+
+        assert!(!harness.screen().contains(&title));
+        harness.press(KeyCode::Tab).await;
+        assert!(harness.screen().contains(&title));
+    }
+}
@@ -5,8 +5,6 @@ use std::sync::Arc;
 use ticks::{AccessToken, Authorization};
 use tokio::sync::Mutex;
 
-const REDIRECT_URI: &str = "http://localhost:8080/callback";
-
 #[derive(Debug, Deserialize, Clone)]
 pub struct AuthCallback {
     pub code: Option<String>,
@@ -25,6 +23,29 @@ pub fn get_client_id() -> Option<(String, String)> {
     Some((client_id, client_secret))
 }
 
+/// Port and path to serve the sign-in callback on, from a local `http://` redirect URI
+pub fn callback_address(redirect_uri: &str) -> Result<(u16, String), String> {
+    let rest = redirect_uri
+        .strip_prefix("http://")
+        .ok_or("must start with http://")?;
+    let (authority, path) = match rest.find('/') {
+        Some(i) => rest.split_at(i),
+        None => (rest, "/"),
+    };
+    let (host, port) = authority
+        .rsplit_once(':')
+        .ok_or("needs a port, e.g. http://localhost:8080/callback")?;
+    if host != "localhost" && host != "127.0.0.1" {
+        return Err("must point at localhost or 127.0.0.1".to_string());
+    }
+    let port = port
+        .parse::<u16>()
+        .ok()
+        .filter(|&port| port > 0)
+        .ok_or_else(|| format!("invalid port '{}'", port))?;
+    Ok((port, path.to_string()))
+}
+
 fn get_token_cache_path() -> PathBuf {
     let mut path = dirs::home_dir().expect("Could not determine home directory");
     path.push(".automatick");
@@ -60,8 +81,10 @@ pub fn clear_token_cache() {
 pub async fn perform_authorization(
     client_id: String,
     client_secret: String,
+    redirect_uri: &str,
 ) -> Option<AccessToken> {
-    let redirect_uri = REDIRECT_URI.to_string();
+    let (port, callback_path) = callback_address(redirect_uri).ok()?;
+    let redirect_uri = redirect_uri.to_string();
     let auth_result = Authorization::begin_auth(client_id.clone(), redirect_uri.clone());
     let awaiting_auth = match auth_result {
         Ok(auth) => auth,
@@ -105,8 +128,8 @@ pub async fn perform_authorization(
             }
         }
     };
-    let app = Router::new().route("/callback", get(callback_handler));
-    let listener = match tokio::net::TcpListener::bind("127.0.0.1:8080").await {
+    let app = Router::new().route(&callback_path, get(callback_handler));
+    let listener = match tokio::net::TcpListener::bind(("127.0.0.1", port)).await {
         Ok(l) => l,
         Err(_e) => {
             return None;
@@ -138,11 +161,15 @@ pub async fn perform_authorization(
     }
 }
 
-pub async fn get_access_token(client_id: String, client_secret: String) -> Option<AccessToken> {
+pub async fn get_access_token(
+    client_id: String,
+    client_secret: String,
+    redirect_uri: &str,
+) -> Option<AccessToken> {
     if let Some(token) = load_cached_token() {
         return Some(token);
     }
-    match perform_authorization(client_id, client_secret).await {
+    match perform_authorization(client_id, client_secret, redirect_uri).await {
         Some(token) => {
             let _ = save_token_cache(&token);
             Some(token)
@@ -150,3 +177,24 @@ pub async fn get_access_token(client_id: String, client_secret: String) -> Optio
         None => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_callback_address() {
+        assert_eq!(
+            callback_address("http://localhost:8080/callback"),
+            Ok((8080, "/callback".to_string()))
+        );
+        assert_eq!(
+            callback_address("http://127.0.0.1:9000"),
+            Ok((9000, "/".to_string()))
+        );
+        assert!(callback_address("https://localhost:8080/callback").is_err());
+        assert!(callback_address("http://example.com:8080/callback").is_err());
+        assert!(callback_address("http://localhost/callback").is_err());
+        assert!(callback_address("http://localhost:99999/callback").is_err());
+    }
+}
@@ -0,0 +1,224 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Mutex,
+};
+
+use async_trait::async_trait;
+use chrono::Duration;
+use ticks::{
+    projects::ProjectID,
+    tasks::{Task, TaskID},
+};
+
+use super::{is_inbox_project, local_task, same_task_id, NewTask, ProjectInfo, TaskBackend};
+use crate::recurrence::repeat_rule;
+
+/// Build a task with the given id, project and title
+pub fn task_fixture(id: &str, project_id: &str, title: &str) -> Task {
+    local_task(
+        id,
+        &NewTask {
+            title: title.to_string(),
+            project_id: ProjectID(project_id.to_string()),
+            content: None,
+            due_date: None,
+            is_all_day: false,
+            priority: None,
+            tags: Vec::new(),
+            items: Vec::new(),
+            repeat: None,
+            reminders: Vec::new(),
+            start_date: None,
+        },
+    )
+}
+
+/// In-memory task store for tests
+///
+/// Tasks whose project id starts with `inbox` are treated as inbox tasks.
+pub struct MemoryBackend {
+    projects: Mutex<Vec<ProjectInfo>>,
+    tasks: Mutex<Vec<Task>>,
+    /// Completed tasks, which updating brings back like clearing their status does
+    completed: Mutex<Vec<Task>>,
+    next_id: Mutex<usize>,
+    online: AtomicBool,
+    /// Makes the next create wait, see `hold_next_create`
+    held_create: Mutex<Option<tokio::sync::oneshot::Receiver<()>>>,
+}
+
+impl MemoryBackend {
+    pub fn new() -> Self {
+        Self {
+            projects: Mutex::new(Vec::new()),
+            tasks: Mutex::new(Vec::new()),
+            completed: Mutex::new(Vec::new()),
+            next_id: Mutex::new(1),
+            online: AtomicBool::new(true),
+            held_create: Mutex::new(None),
+        }
+    }
+
+    pub fn with_project(self, id: &str, name: &str) -> Self {
+        self.projects.lock().unwrap().push(ProjectInfo {
+            id: ProjectID(id.to_string()),
+            name: name.to_string(),
+        });
+        self
+    }
+
+    pub fn with_task(self, task: Task) -> Self {
+        self.tasks.lock().unwrap().push(task);
+        self
+    }
+
+    /// Make the next `create_task` wait until the returned sender fires or is dropped
+    pub fn hold_next_create(&self) -> tokio::sync::oneshot::Sender<()> {
+        let (release, held) = tokio::sync::oneshot::channel();
+        *self.held_create.lock().unwrap() = Some(held);
+        release
+    }
+
+    /// Snapshot of every stored task
+    pub fn tasks(&self) -> Vec<Task> {
+        self.tasks.lock().unwrap().clone()
+    }
+
+    /// Simulate losing or regaining the network
+    pub fn set_online(&self, online: bool) {
+        self.online.store(online, Ordering::SeqCst);
+    }
+
+    fn check_online(&self) -> Result<(), String> {
+        if self.online.load(Ordering::SeqCst) {
+            Ok(())
+        } else {
+            Err("Network unreachable".to_string())
+        }
+    }
+
+    fn is_inbox(task: &Task) -> bool {
+        is_inbox_project(&task.project_id)
+    }
+}
+
+#[async_trait]
+impl TaskBackend for MemoryBackend {
+    async fn get_all_tasks(&self) -> Result<Vec<Task>, String> {
+        self.check_online()?;
+        let tasks = self.tasks.lock().unwrap();
+        Ok(tasks
+            .iter()
+            .filter(|t| !Self::is_inbox(t))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_inbox_tasks(&self) -> Result<Vec<Task>, String> {
+        self.check_online()?;
+        let tasks = self.tasks.lock().unwrap();
+        Ok(tasks
+            .iter()
+            .filter(|t| Self::is_inbox(t))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_project_tasks(&self, project_id: &ProjectID) -> Result<Vec<Task>, String> {
+        self.check_online()?;
+        let tasks = self.tasks.lock().unwrap();
+        Ok(tasks
+            .iter()
+            .filter(|t| t.project_id.0 == project_id.0)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_task(&self, _project_id: &ProjectID, task_id: &TaskID) -> Result<Task, String> {
+        self.check_online()?;
+        let tasks = self.tasks.lock().unwrap();
+        tasks
+            .iter()
+            .find(|t| same_task_id(t.get_id(), task_id))
+            .cloned()
+            .ok_or_else(|| "Task not found in project".to_string())
+    }
+
+    async fn get_projects(&self) -> Result<Vec<ProjectInfo>, String> {
+        self.check_online()?;
+        Ok(self.projects.lock().unwrap().clone())
+    }
+
+    async fn create_task(&self, new_task: NewTask) -> Result<Task, String> {
+        let held = self.held_create.lock().unwrap().take();
+        if let Some(held) = held {
+            let _ = held.await;
+        }
+        self.check_online()?;
+        let id = {
+            let mut next_id = self.next_id.lock().unwrap();
+            let id = format!("mem-{}", *next_id);
+            *next_id += 1;
+            id
+        };
+        let task = local_task(&id, &new_task);
+        self.tasks.lock().unwrap().push(task.clone());
+        Ok(task)
+    }
+
+    async fn update_task(&self, task: &mut Task) -> Result<(), String> {
+        self.check_online()?;
+        let mut tasks = self.tasks.lock().unwrap();
+        if let Some(stored) = tasks
+            .iter_mut()
+            .find(|t| same_task_id(t.get_id(), task.get_id()))
+        {
+            *stored = task.clone();
+            return Ok(());
+        }
+        let mut completed = self.completed.lock().unwrap();
+        match completed
+            .iter()
+            .position(|t| same_task_id(t.get_id(), task.get_id()))
+        {
+            Some(index) => {
+                completed.remove(index);
+                tasks.push(task.clone());
+                Ok(())
+            }
+            None => Err("Task not found in project".to_string()),
+        }
+    }
+
+    async fn complete_task(&self, _project_id: &ProjectID, task_id: &TaskID) -> Result<(), String> {
+        self.check_online()?;
+        let mut tasks = self.tasks.lock().unwrap();
+        let index = tasks
+            .iter()
+            .position(|t| same_task_id(t.get_id(), task_id))
+            .ok_or_else(|| "Task not found in project".to_string())?;
+        if repeat_rule(&tasks[index]).is_some() {
+            // Like the server, keep a repeating task open at its next date (a day later
+            // is close enough here)
+            let task = &mut tasks[index];
+            task.due_date += Duration::days(1);
+            task.start_date += Duration::days(1);
+        } else {
+            let task = tasks.remove(index);
+            self.completed.lock().unwrap().push(task);
+        }
+        Ok(())
+    }
+
+    async fn delete_task(&self, _project_id: &ProjectID, task_id: &TaskID) -> Result<(), String> {
+        self.check_online()?;
+        let mut tasks = self.tasks.lock().unwrap();
+        let before = tasks.len();
+        tasks.retain(|t| !same_task_id(t.get_id(), task_id));
+        if tasks.len() < before {
+            Ok(())
+        } else {
+            Err("Task not found in project".to_string())
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ticks::{
+    projects::ProjectID,
+    tasks::{Task, TaskID, TaskPriority},
+};
+
+use crate::checklist::{checklist_items, set_checklist_items, ChecklistItem};
+use crate::recurrence::{repeat_rule, set_repeat_rule};
+use crate::reminders::{reminders, set_reminders};
+use crate::tasks::separate_start;
+
+#[cfg(test)]
+pub mod memory;
+pub mod offline;
+pub mod ticktick;
+
+/// Fields needed to create a new task through a backend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewTask {
+    pub title: String,
+    pub project_id: ProjectID,
+    pub content: Option<String>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub is_all_day: bool,
+    #[serde(default)]
+    pub priority: Option<TaskPriority>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub items: Vec<ChecklistItem>,
+    /// Recurrence as an RRULE string
+    #[serde(default)]
+    pub repeat: Option<String>,
+    /// Reminders as `TRIGGER:` strings
+    #[serde(default)]
+    pub reminders: Vec<String>,
+    /// Start date, when it differs from the due date
+    #[serde(default)]
+    pub start_date: Option<DateTime<Utc>>,
+}
+
+impl NewTask {
+    /// Fields needed to recreate an existing task
+    pub fn from_task(task: &Task) -> Self {
+        Self {
+            title: task.title.clone(),
+            project_id: task.project_id.clone(),
+            content: if task.content.is_empty() {
+                None
+            } else {
+                Some(task.content.clone())
+            },
+            due_date: if task.due_date.timestamp() > 0 {
+                Some(task.due_date)
+            } else {
+                None
+            },
+            is_all_day: task.is_all_day,
+            priority: Some(task.priority.clone()),
+            tags: task.tags.clone(),
+            items: checklist_items(task),
+            repeat: repeat_rule(task),
+            reminders: reminders(task),
+            start_date: separate_start(task),
+        }
+    }
+}
+
+/// Minimal project information used for listing projects
+#[derive(Debug, Clone)]
+pub struct ProjectInfo {
+    pub id: ProjectID,
+    pub name: String,
+}
+
+/// Storage backend for tasks
+///
+/// The app only talks to tasks through this trait so the TickTick client can be
+/// swapped for other stores or test doubles.
+#[async_trait]
+pub trait TaskBackend: Send + Sync {
+    /// Fetch tasks from every project (excluding the inbox)
+    async fn get_all_tasks(&self) -> Result<Vec<Task>, String>;
+
+    /// Fetch tasks from the inbox
+    async fn get_inbox_tasks(&self) -> Result<Vec<Task>, String>;
+
+    /// Fetch tasks from a specific project
+    async fn get_project_tasks(&self, project_id: &ProjectID) -> Result<Vec<Task>, String>;
+
+    /// Fetch a single task
+    async fn get_task(&self, project_id: &ProjectID, task_id: &TaskID) -> Result<Task, String>;
+
+    /// List all projects
+    async fn get_projects(&self) -> Result<Vec<ProjectInfo>, String>;
+
+    /// Create a new task, returning it as stored
+    async fn create_task(&self, new_task: NewTask) -> Result<Task, String>;
+
+    /// Publish changes made to an existing task
+    async fn update_task(&self, task: &mut Task) -> Result<(), String>;
+
+    /// Mark a task as completed
+    async fn complete_task(&self, project_id: &ProjectID, task_id: &TaskID) -> Result<(), String>;
+
+    /// Delete a task
+    async fn delete_task(&self, project_id: &ProjectID, task_id: &TaskID) -> Result<(), String>;
+
+    /// Mark a completed task as not done, keeping its id
+    ///
+    /// The task is updated in place with its status cleared, so a repeating task that
+    /// the server already moved to its next date goes back to this one instead of
+    /// being duplicated.
+    async fn reopen_task(&self, task: &Task) -> Result<(), String> {
+        let mut task = task.clone();
+        set_task_field(&mut task, "status", serde_json::Value::from(0));
+        self.update_task(&mut task).await
+    }
+
+    /// Send any locally queued changes, returning how many were sent
+    async fn sync(&self) -> Result<usize, String> {
+        Ok(0)
+    }
+
+    /// Whether the backend currently can't reach its server
+    fn is_offline(&self) -> bool {
+        false
+    }
+
+    /// Tasks with local changes that haven't been sent yet
+    fn pending_task_ids(&self) -> Vec<TaskID> {
+        Vec::new()
+    }
+}
+
+/// Compare task IDs (TaskID only exposes Debug)
+pub fn same_task_id(a: &TaskID, b: &TaskID) -> bool {
+    format!("{:?}", a) == format!("{:?}", b)
+}
+
+/// The raw id string of a task, as used by the API
+pub fn task_id_string(task_id: &TaskID) -> String {
+    match serde_json::to_value(task_id) {
+        Ok(serde_json::Value::String(id)) => id,
+        _ => format!("{:?}", task_id),
+    }
+}
+
+/// Whether a project is the inbox
+///
+/// TickTick accepts `inbox` as an alias, but tasks carry the real `inbox<user id>` id.
+pub fn is_inbox_project(project_id: &ProjectID) -> bool {
+    project_id.0.starts_with("inbox")
+}
+
+/// Copy every user-editable field from one task to another
+pub fn copy_task_fields(from: &Task, to: &mut Task) {
+    to.title = from.title.clone();
+    to.content = from.content.clone();
+    to.project_id = from.project_id.clone();
+    to.due_date = from.due_date;
+    to.start_date = from.start_date;
+    to.is_all_day = from.is_all_day;
+    to.priority = from.priority.clone();
+    to.tags = from.tags.clone();
+    set_checklist_items(to, &checklist_items(from));
+    set_repeat_rule(to, repeat_rule(from).as_deref());
+    set_reminders(to, &reminders(from));
+}
+
+/// Read a task field the client doesn't expose, by its API (camelCase) name
+pub fn task_field(task: &Task, name: &str) -> Option<serde_json::Value> {
+    serde_json::to_value(task)
+        .ok()
+        .and_then(|mut value| value.get_mut(name).map(|field| field.take()))
+}
+
+/// Set a task field the client doesn't expose, by its API (camelCase) name
+///
+/// Like [`local_task`], this goes through the task's JSON shape. The task is left
+/// unchanged if the client doesn't keep the field.
+pub fn set_task_field(task: &mut Task, name: &str, field: serde_json::Value) {
+    let Ok(mut value) = serde_json::to_value(&*task) else {
+        return;
+    };
+    value[name] = field;
+    if let Ok(updated) = serde_json::from_value(value) {
+        *task = updated;
+    }
+}
+
+/// Build a task that only exists locally
+///
+/// Tasks can only be constructed through deserialization, so this starts from the
+/// API's JSON shape and sets the remaining public fields directly.
+pub fn local_task(id: &str, new_task: &NewTask) -> Task {
+    let mut task: Task = serde_json::from_value(serde_json::json!({
+        "id": id,
+        "projectId": new_task.project_id.0,
+        "title": new_task.title,
+    }))
+    .expect("local task should deserialize");
+    if let Some(content) = &new_task.content {
+        task.content = content.clone();
+    }
+    if let Some(due_date) = new_task.due_date {
+        task.due_date = due_date;
+        task.is_all_day = new_task.is_all_day;
+    }
+    if let Some(priority) = &new_task.priority {
+        task.priority = priority.clone();
+    }
+    task.tags = new_task.tags.clone();
+    if !new_task.items.is_empty() {
+        set_checklist_items(&mut task, &new_task.items);
+    }
+    if new_task.repeat.is_some() {
+        set_repeat_rule(&mut task, new_task.repeat.as_deref());
+    }
+    if !new_task.reminders.is_empty() {
+        set_reminders(&mut task, &new_task.reminders);
+    }
+    task.start_date = new_task.start_date.unwrap_or(task.due_date);
+    task
+}
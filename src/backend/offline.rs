@@ -0,0 +1,705 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use ticks::{
+    projects::ProjectID,
+    tasks::{Task, TaskID},
+};
+
+use super::{
+    copy_task_fields, is_inbox_project, local_task, same_task_id, NewTask, ProjectInfo, TaskBackend,
+};
+use crate::cache::TaskCache;
+
+const LOCAL_ID_PREFIX: &str = "local-";
+
+/// A change made while offline, waiting to be sent to the server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PendingOperation {
+    Create {
+        local_id: String,
+        new_task: NewTask,
+    },
+    Update {
+        original_project_id: ProjectID,
+        task: Task,
+    },
+    Complete {
+        project_id: ProjectID,
+        task_id: TaskID,
+    },
+    Delete {
+        project_id: ProjectID,
+        task_id: TaskID,
+    },
+}
+
+impl PendingOperation {
+    fn task_id(&self) -> TaskID {
+        match self {
+            PendingOperation::Create { local_id, new_task } => {
+                local_task(local_id, new_task).get_id().clone()
+            }
+            PendingOperation::Update { task, .. } => task.get_id().clone(),
+            PendingOperation::Complete { task_id, .. }
+            | PendingOperation::Delete { task_id, .. } => task_id.clone(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct OfflineState {
+    /// Last known server state for project tasks
+    project_tasks: Vec<Task>,
+    /// Last known server state for inbox tasks
+    inbox_tasks: Vec<Task>,
+    /// Whether any server state is known at all
+    has_snapshot: bool,
+    pending: Vec<PendingOperation>,
+}
+
+/// Backend wrapper that keeps working when the server is unreachable
+///
+/// Reads fall back to the last known tasks, and mutations are applied locally and
+/// queued in `~/.automatick/pending.json` until `sync` can replay them.
+pub struct OfflineBackend {
+    inner: Arc<dyn TaskBackend>,
+    cache: TaskCache,
+    state: Mutex<OfflineState>,
+    offline: AtomicBool,
+    sync_lock: tokio::sync::Mutex<()>,
+}
+
+impl OfflineBackend {
+    pub fn new(inner: Arc<dyn TaskBackend>, cache: TaskCache) -> Self {
+        let mut state = OfflineState {
+            pending: cache.load_pending(),
+            ..Default::default()
+        };
+
+        // Seed the last known server state from the saved views
+        if let Some(snapshot) = cache.load_snapshot() {
+            for task in snapshot.today.into_iter().chain(snapshot.week) {
+                let in_inbox = snapshot
+                    .inbox
+                    .iter()
+                    .any(|t| same_task_id(t.get_id(), task.get_id()));
+                let seen = state
+                    .project_tasks
+                    .iter()
+                    .any(|t| same_task_id(t.get_id(), task.get_id()));
+                if !in_inbox && !seen {
+                    state.project_tasks.push(task);
+                }
+            }
+            state.inbox_tasks = snapshot.inbox;
+            state.has_snapshot = true;
+        }
+
+        Self {
+            inner,
+            cache,
+            state: Mutex::new(state),
+            offline: AtomicBool::new(false),
+            sync_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    fn is_local_id(task_id: &TaskID) -> bool {
+        format!("{:?}", task_id).contains(LOCAL_ID_PREFIX)
+    }
+
+    fn save_pending(&self, pending: &[PendingOperation]) {
+        let _ = self.cache.save_pending(pending);
+    }
+
+    fn queue(&self, operation: PendingOperation) {
+        let mut state = self.state.lock().unwrap();
+        state.pending.push(operation);
+        self.save_pending(&state.pending);
+    }
+
+    /// Check whether the server is reachable and record the result
+    async fn check_offline(&self) -> bool {
+        let offline = self.inner.get_projects().await.is_err();
+        self.offline.store(offline, Ordering::SeqCst);
+        offline
+    }
+
+    /// Apply queued changes on top of a list of tasks from the server
+    fn apply_pending(pending: &[PendingOperation], mut tasks: Vec<Task>, inbox: bool) -> Vec<Task> {
+        for operation in pending {
+            match operation {
+                PendingOperation::Create { local_id, new_task } => {
+                    let is_inbox_task = is_inbox_project(&new_task.project_id);
+                    let task = local_task(local_id, new_task);
+                    if is_inbox_task == inbox
+                        && !tasks
+                            .iter()
+                            .any(|t| same_task_id(t.get_id(), task.get_id()))
+                    {
+                        tasks.push(task);
+                    }
+                }
+                PendingOperation::Update { task, .. } => {
+                    if let Some(existing) = tasks
+                        .iter_mut()
+                        .find(|t| same_task_id(t.get_id(), task.get_id()))
+                    {
+                        *existing = task.clone();
+                    }
+                }
+                PendingOperation::Complete { task_id, .. }
+                | PendingOperation::Delete { task_id, .. } => {
+                    tasks.retain(|t| !same_task_id(t.get_id(), task_id));
+                }
+            }
+        }
+        tasks
+    }
+
+    /// Last known server state with queued changes applied
+    fn local_tasks(&self, inbox: bool) -> Vec<Task> {
+        let state = self.state.lock().unwrap();
+        let tasks = if inbox {
+            state.inbox_tasks.clone()
+        } else {
+            state.project_tasks.clone()
+        };
+        Self::apply_pending(&state.pending, tasks, inbox)
+    }
+
+    fn known_tasks(&self, inbox: bool) -> Result<Vec<Task>, String> {
+        if !self.state.lock().unwrap().has_snapshot {
+            return Err("Offline and no cached tasks available".to_string());
+        }
+        Ok(self.local_tasks(inbox))
+    }
+
+    fn find_known_task(&self, task_id: &TaskID) -> Option<Task> {
+        let project_tasks = self.local_tasks(false);
+        let inbox_tasks = self.local_tasks(true);
+        project_tasks
+            .into_iter()
+            .chain(inbox_tasks)
+            .find(|t| same_task_id(t.get_id(), task_id))
+    }
+
+    /// Send an edited task by applying its fields to a fresh copy from the server
+    ///
+    /// Tasks loaded from the local cache have no client context, so they can't be
+    /// published directly.
+    async fn publish_update(
+        &self,
+        original_project_id: &ProjectID,
+        task: &Task,
+    ) -> Result<(), String> {
+        let mut fresh = self
+            .inner
+            .get_task(original_project_id, task.get_id())
+            .await?;
+        copy_task_fields(task, &mut fresh);
+        self.inner.update_task(&mut fresh).await
+    }
+
+    /// Send a queued change, returning the server's task for a create
+    async fn replay(&self, operation: &PendingOperation) -> Result<Option<Task>, String> {
+        match operation {
+            PendingOperation::Create { new_task, .. } => {
+                self.inner.create_task(new_task.clone()).await.map(Some)
+            }
+            PendingOperation::Update {
+                original_project_id,
+                task,
+            } => self
+                .publish_update(original_project_id, task)
+                .await
+                .map(|()| None),
+            PendingOperation::Complete {
+                project_id,
+                task_id,
+            } => self
+                .inner
+                .complete_task(project_id, task_id)
+                .await
+                .map(|()| None),
+            PendingOperation::Delete {
+                project_id,
+                task_id,
+            } => self
+                .inner
+                .delete_task(project_id, task_id)
+                .await
+                .map(|()| None),
+        }
+    }
+
+    /// Take a replayed operation off the queue
+    ///
+    /// Tasks created offline can be edited or removed while their create is replayed, so
+    /// the operation is found again by task id. An edit made meanwhile stays queued as an
+    /// update of the task the server created.
+    fn finish_replay(&self, replayed: &PendingOperation, created: Option<Task>) {
+        let mut state = self.state.lock().unwrap();
+        let task_id = replayed.task_id();
+        let Some(index) = state
+            .pending
+            .iter()
+            .position(|queued| same_task_id(&queued.task_id(), &task_id))
+        else {
+            return;
+        };
+        let edited =
+            serde_json::to_value(&state.pending[index]).ok() != serde_json::to_value(replayed).ok();
+        let edited_task = match &state.pending[index] {
+            PendingOperation::Create { local_id, new_task } if edited => {
+                Some(local_task(local_id, new_task))
+            }
+            _ => None,
+        };
+        match (edited_task, created) {
+            (Some(edited_task), Some(mut created)) => {
+                let original_project_id = created.project_id.clone();
+                copy_task_fields(&edited_task, &mut created);
+                state.pending[index] = PendingOperation::Update {
+                    original_project_id,
+                    task: created,
+                };
+            }
+            _ => {
+                state.pending.remove(index);
+            }
+        }
+        self.save_pending(&state.pending);
+    }
+
+    /// Fold a change to a task that was created offline into its queued create
+    fn update_local_task(&self, task: &Task) {
+        let mut state = self.state.lock().unwrap();
+        for operation in state.pending.iter_mut() {
+            if let PendingOperation::Create { local_id, new_task } = operation {
+                if same_task_id(local_task(local_id, new_task).get_id(), task.get_id()) {
+                    *new_task = NewTask::from_task(task);
+                }
+            }
+        }
+        self.save_pending(&state.pending);
+    }
+
+    /// Drop the queued create for a task that was created offline
+    fn remove_local_task(&self, task_id: &TaskID) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .pending
+            .retain(|operation| !same_task_id(&operation.task_id(), task_id));
+        self.save_pending(&state.pending);
+    }
+
+    async fn fetch_with_fallback(&self, inbox: bool) -> Result<Vec<Task>, String> {
+        if self.has_pending() {
+            let _ = self.sync().await;
+        }
+        if !self.is_offline() {
+            let result = if inbox {
+                self.inner.get_inbox_tasks().await
+            } else {
+                self.inner.get_all_tasks().await
+            };
+            match result {
+                Ok(tasks) => {
+                    let mut state = self.state.lock().unwrap();
+                    if inbox {
+                        state.inbox_tasks = tasks.clone();
+                    } else {
+                        state.project_tasks = tasks.clone();
+                    }
+                    state.has_snapshot = true;
+                    return Ok(Self::apply_pending(&state.pending, tasks, inbox));
+                }
+                Err(e) => {
+                    self.offline.store(true, Ordering::SeqCst);
+                    if !self.state.lock().unwrap().has_snapshot {
+                        return Err(e);
+                    }
+                }
+            }
+        } else {
+            // Still offline, but check again in case the network is back
+            self.check_offline().await;
+        }
+        self.known_tasks(inbox)
+    }
+
+    fn has_pending(&self) -> bool {
+        !self.state.lock().unwrap().pending.is_empty()
+    }
+}
+
+#[async_trait]
+impl TaskBackend for OfflineBackend {
+    async fn get_all_tasks(&self) -> Result<Vec<Task>, String> {
+        self.fetch_with_fallback(false).await
+    }
+
+    async fn get_inbox_tasks(&self) -> Result<Vec<Task>, String> {
+        self.fetch_with_fallback(true).await
+    }
+
+    async fn get_project_tasks(&self, project_id: &ProjectID) -> Result<Vec<Task>, String> {
+        if !self.is_offline() {
+            match self.inner.get_project_tasks(project_id).await {
+                Ok(tasks) => return Ok(tasks),
+                Err(e) => {
+                    if !self.check_offline().await || !self.state.lock().unwrap().has_snapshot {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+        let inbox = is_inbox_project(project_id);
+        Ok(self
+            .known_tasks(inbox)?
+            .into_iter()
+            .filter(|task| inbox || task.project_id.0 == project_id.0)
+            .collect())
+    }
+
+    async fn get_task(&self, project_id: &ProjectID, task_id: &TaskID) -> Result<Task, String> {
+        if Self::is_local_id(task_id) || self.is_offline() {
+            return self
+                .find_known_task(task_id)
+                .ok_or_else(|| "Task not found in local cache".to_string());
+        }
+        let error = match self.inner.get_task(project_id, task_id).await {
+            Ok(task) => return Ok(task),
+            Err(e) => e,
+        };
+        if self.check_offline().await {
+            if let Some(task) = self.find_known_task(task_id) {
+                return Ok(task);
+            }
+        }
+        Err(error)
+    }
+
+    async fn get_projects(&self) -> Result<Vec<ProjectInfo>, String> {
+        self.inner.get_projects().await
+    }
+
+    async fn create_task(&self, new_task: NewTask) -> Result<Task, String> {
+        if !self.is_offline() {
+            match self.inner.create_task(new_task.clone()).await {
+                Ok(task) => return Ok(task),
+                Err(e) => {
+                    if !self.check_offline().await {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+        let local_id = format!(
+            "{}{}",
+            LOCAL_ID_PREFIX,
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        );
+        let task = local_task(&local_id, &new_task);
+        self.queue(PendingOperation::Create { local_id, new_task });
+        Ok(task)
+    }
+
+    async fn update_task(&self, task: &mut Task) -> Result<(), String> {
+        if Self::is_local_id(task.get_id()) {
+            self.update_local_task(task);
+            return Ok(());
+        }
+        let original_project_id = self
+            .find_known_task(task.get_id())
+            .map(|known| known.project_id)
+            .unwrap_or_else(|| task.project_id.clone());
+        if !self.is_offline() {
+            match self.publish_update(&original_project_id, task).await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    if !self.check_offline().await {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+        self.queue(PendingOperation::Update {
+            original_project_id,
+            task: task.clone(),
+        });
+        Ok(())
+    }
+
+    async fn complete_task(&self, project_id: &ProjectID, task_id: &TaskID) -> Result<(), String> {
+        if Self::is_local_id(task_id) {
+            self.remove_local_task(task_id);
+            return Ok(());
+        }
+        if !self.is_offline() {
+            match self.inner.complete_task(project_id, task_id).await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    if !self.check_offline().await {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+        self.queue(PendingOperation::Complete {
+            project_id: project_id.clone(),
+            task_id: task_id.clone(),
+        });
+        Ok(())
+    }
+
+    async fn delete_task(&self, project_id: &ProjectID, task_id: &TaskID) -> Result<(), String> {
+        if Self::is_local_id(task_id) {
+            self.remove_local_task(task_id);
+            return Ok(());
+        }
+        if !self.is_offline() {
+            match self.inner.delete_task(project_id, task_id).await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    if !self.check_offline().await {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+        self.queue(PendingOperation::Delete {
+            project_id: project_id.clone(),
+            task_id: task_id.clone(),
+        });
+        Ok(())
+    }
+
+    async fn sync(&self) -> Result<usize, String> {
+        // Only one sync at a time
+        let _guard = match self.sync_lock.try_lock() {
+            Ok(guard) => guard,
+            Err(_) => return Ok(0),
+        };
+
+        let mut synced = 0;
+        let mut errors = Vec::new();
+        loop {
+            let next = self.state.lock().unwrap().pending.first().cloned();
+            let Some(operation) = next else {
+                self.offline.store(false, Ordering::SeqCst);
+                break;
+            };
+
+            match self.replay(&operation).await {
+                Ok(created) => {
+                    synced += 1;
+                    self.finish_replay(&operation, created);
+                }
+                Err(e) => {
+                    if self.check_offline().await {
+                        break;
+                    }
+                    // The server rejected this change, so drop it rather than retrying forever
+                    errors.push(e);
+                    self.finish_replay(&operation, None);
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(format!(
+                "Failed to sync {} change(s): {}",
+                errors.len(),
+                errors.join(", ")
+            ));
+        }
+        Ok(synced)
+    }
+
+    fn is_offline(&self) -> bool {
+        self.offline.load(Ordering::SeqCst)
+    }
+
+    fn pending_task_ids(&self) -> Vec<TaskID> {
+        self.state
+            .lock()
+            .unwrap()
+            .pending
+            .iter()
+            .map(|operation| operation.task_id())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::{task_fixture, MemoryBackend};
+
+    fn temp_cache() -> TaskCache {
+        let dir = std::env::temp_dir().join(format!(
+            "automatick-test-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        TaskCache::new(dir)
+    }
+
+    #[tokio::test]
+    async fn test_offline_changes_are_queued_and_replayed() {
+        let memory = Arc::new(
+            MemoryBackend::new()
+                .with_task(task_fixture("t1", "inbox", "Water plants"))
+                .with_task(task_fixture("t2", "inbox", "Read a book")),
+        );
+        let cache = temp_cache();
+        let backend = OfflineBackend::new(memory.clone(), cache.clone());
+        assert_eq!(backend.get_inbox_tasks().await.unwrap().len(), 2);
+
+        memory.set_online(false);
+        let task_id = task_fixture("t1", "inbox", "").get_id().clone();
+        backend
+            .complete_task(&ProjectID("inbox".to_string()), &task_id)
+            .await
+            .unwrap();
+        backend
+            .create_task(NewTask {
+                title: "Buy milk".to_string(),
+                project_id: ProjectID("inbox".to_string()),
+                content: None,
+                due_date: None,
+                is_all_day: false,
+                priority: None,
+                tags: Vec::new(),
+                items: Vec::new(),
+                repeat: None,
+                reminders: Vec::new(),
+                start_date: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(backend.is_offline());
+        assert_eq!(backend.pending_task_ids().len(), 2);
+        assert_eq!(cache.load_pending().len(), 2);
+        let titles: Vec<String> = backend
+            .get_inbox_tasks()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.title)
+            .collect();
+        assert_eq!(titles, vec!["Read a book", "Buy milk"]);
+
+        memory.set_online(true);
+        assert_eq!(backend.sync().await.unwrap(), 2);
+        assert!(!backend.is_offline());
+        assert!(backend.pending_task_ids().is_empty());
+        let titles: Vec<String> = memory.tasks().into_iter().map(|t| t.title).collect();
+        assert_eq!(titles, vec!["Read a book", "Buy milk"]);
+    }
+
+    #[tokio::test]
+    async fn test_changes_to_offline_created_task_fold_into_create() {
+        let memory = Arc::new(MemoryBackend::new());
+        memory.set_online(false);
+        let backend = OfflineBackend::new(memory.clone(), temp_cache());
+        backend
+            .create_task(NewTask {
+                title: "Draft".to_string(),
+                project_id: ProjectID("inbox".to_string()),
+                content: None,
+                due_date: None,
+                is_all_day: false,
+                priority: None,
+                tags: Vec::new(),
+                items: Vec::new(),
+                repeat: None,
+                reminders: Vec::new(),
+                start_date: None,
+            })
+            .await
+            .unwrap();
+
+        let task_id = backend.pending_task_ids()[0].clone();
+        let inbox = ProjectID("inbox".to_string());
+        let mut task = backend.get_task(&inbox, &task_id).await.unwrap();
+        task.title = "Final".to_string();
+        backend.update_task(&mut task).await.unwrap();
+        assert_eq!(backend.pending_task_ids().len(), 1);
+
+        memory.set_online(true);
+        backend.sync().await.unwrap();
+        assert_eq!(memory.tasks()[0].title, "Final");
+    }
+
+    #[tokio::test]
+    async fn test_edit_during_sync_is_sent() {
+        let memory = Arc::new(MemoryBackend::new());
+        memory.set_online(false);
+        let backend = OfflineBackend::new(memory.clone(), temp_cache());
+        backend
+            .create_task(NewTask {
+                title: "Draft".to_string(),
+                project_id: ProjectID("inbox".to_string()),
+                content: None,
+                due_date: None,
+                is_all_day: false,
+                priority: None,
+                tags: Vec::new(),
+                items: Vec::new(),
+                repeat: None,
+                reminders: Vec::new(),
+                start_date: None,
+            })
+            .await
+            .unwrap();
+        let task_id = backend.pending_task_ids()[0].clone();
+        let inbox = ProjectID("inbox".to_string());
+
+        // Edit the task while the server is still creating it
+        memory.set_online(true);
+        let release = memory.hold_next_create();
+        let offline = &backend;
+        let edit = async move {
+            tokio::task::yield_now().await;
+            let mut task = offline.get_task(&inbox, &task_id).await.unwrap();
+            task.title = "Final".to_string();
+            offline.update_task(&mut task).await.unwrap();
+            let _ = release.send(());
+        };
+        let (synced, ()) = tokio::join!(offline.sync(), edit);
+
+        // The edit is sent as an update of the created task
+        assert_eq!(synced.unwrap(), 2);
+        assert!(backend.pending_task_ids().is_empty());
+        let tasks = memory.tasks();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "Final");
+    }
+
+    #[tokio::test]
+    async fn test_project_tasks_offline() {
+        let memory = Arc::new(
+            MemoryBackend::new()
+                .with_task(task_fixture("t1", "work", "Send report"))
+                .with_task(task_fixture("t2", "home", "Water plants")),
+        );
+        let backend = OfflineBackend::new(memory.clone(), temp_cache());
+        let work = ProjectID("work".to_string());
+        assert_eq!(backend.get_project_tasks(&work).await.unwrap().len(), 1);
+        assert!(backend.get_all_tasks().await.is_ok());
+
+        memory.set_online(false);
+        let tasks = backend.get_project_tasks(&work).await.unwrap();
+        assert!(backend.is_offline());
+        let titles: Vec<&str> = tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Send report"]);
+    }
+}
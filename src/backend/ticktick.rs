@@ -0,0 +1,131 @@
+use async_trait::async_trait;
+use ticks::{
+    projects::ProjectID,
+    tasks::{Task, TaskID},
+    TickTick,
+};
+
+use super::{same_task_id, NewTask, ProjectInfo, TaskBackend};
+use crate::{
+    checklist::set_checklist_items, recurrence::set_repeat_rule, reminders::set_reminders,
+};
+
+#[async_trait]
+impl TaskBackend for TickTick {
+    async fn get_all_tasks(&self) -> Result<Vec<Task>, String> {
+        self.get_all_tasks_in_projects()
+            .await
+            .map_err(|e| format!("Failed to fetch tasks: {:?}", e))
+    }
+
+    async fn get_inbox_tasks(&self) -> Result<Vec<Task>, String> {
+        // "inbox" resolves to the user's actual inbox project
+        let inbox_id = ProjectID("inbox".to_string());
+        match self.get_project_data(&inbox_id).await {
+            Ok(project_data) => Ok(project_data.tasks),
+            Err(e) => Err(format!("Failed to fetch inbox tasks: {:?}", e)),
+        }
+    }
+
+    async fn get_project_tasks(&self, project_id: &ProjectID) -> Result<Vec<Task>, String> {
+        match self.get_project(project_id).await {
+            Ok(project) => match project.get_tasks().await {
+                Ok(tasks) => Ok(tasks),
+                Err(e) => Err(format!("Failed to fetch tasks from project: {:?}", e)),
+            },
+            Err(e) => Err(format!("Failed to get project: {:?}", e)),
+        }
+    }
+
+    async fn get_task(&self, project_id: &ProjectID, task_id: &TaskID) -> Result<Task, String> {
+        // Get a fresh task instance from the API with proper client context
+        match self.get_project_data(project_id).await {
+            Ok(project_data) => project_data
+                .tasks
+                .into_iter()
+                .find(|t| same_task_id(t.get_id(), task_id))
+                .ok_or_else(|| "Task not found in project".to_string()),
+            Err(e) => Err(format!("Failed to get project data: {:?}", e)),
+        }
+    }
+
+    async fn get_projects(&self) -> Result<Vec<ProjectInfo>, String> {
+        match self.get_all_projects().await {
+            Ok(projects) => Ok(projects
+                .into_iter()
+                .map(|project| ProjectInfo {
+                    id: project.get_id().clone(),
+                    name: project.name.clone(),
+                })
+                .collect()),
+            Err(e) => Err(format!("Failed to fetch projects: {:?}", e)),
+        }
+    }
+
+    async fn create_task(&self, new_task: NewTask) -> Result<Task, String> {
+        let mut builder = Task::builder(self, &new_task.title);
+        builder = builder.project_id(new_task.project_id);
+
+        if let Some(c) = new_task.content {
+            builder = builder.content(&c);
+        }
+
+        if let Some(due_date) = new_task.due_date {
+            if new_task.is_all_day {
+                builder = builder.is_all_day(true);
+            }
+            builder = builder.due_date(due_date);
+        }
+        let mut task = match builder.build_and_publish().await {
+            Ok(task) => task,
+            Err(e) => return Err(format!("Failed to create task: {:?}", e)),
+        };
+
+        // The builder only covers the basic fields, so the rest is sent as an update
+        if new_task.priority.is_some()
+            || !new_task.tags.is_empty()
+            || !new_task.items.is_empty()
+            || new_task.repeat.is_some()
+            || !new_task.reminders.is_empty()
+            || new_task.start_date.is_some()
+        {
+            if let Some(priority) = new_task.priority {
+                task.priority = priority;
+            }
+            task.tags = new_task.tags;
+            set_checklist_items(&mut task, &new_task.items);
+            set_repeat_rule(&mut task, new_task.repeat.as_deref());
+            set_reminders(&mut task, &new_task.reminders);
+            if let Some(start_date) = new_task.start_date {
+                task.start_date = start_date;
+            }
+            TaskBackend::update_task(self, &mut task).await?;
+        }
+        Ok(task)
+    }
+
+    async fn update_task(&self, task: &mut Task) -> Result<(), String> {
+        task.publish_changes()
+            .await
+            .map_err(|e| format!("Failed to edit task: {:?}", e))
+    }
+
+    async fn complete_task(&self, project_id: &ProjectID, task_id: &TaskID) -> Result<(), String> {
+        let mut task = TaskBackend::get_task(self, project_id, task_id).await?;
+        match task.complete().await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Failed to complete task: {:?}", e)),
+        }
+    }
+
+    async fn delete_task(&self, project_id: &ProjectID, task_id: &TaskID) -> Result<(), String> {
+        let task = match Task::get(self, project_id, task_id).await {
+            Ok(task) => task,
+            Err(e) => return Err(format!("Failed to fetch task: {:?}", e)),
+        };
+        match task.delete().await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Failed to delete task: {:?}", e)),
+        }
+    }
+}
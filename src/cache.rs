@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use ticks::tasks::Task;
+
+use crate::backend::offline::PendingOperation;
+use crate::completed::CompletedLog;
+use crate::query::SavedQuery;
+use crate::trash::Trash;
+
+const SNAPSHOT_FILE: &str = "tasks.json";
+const PENDING_FILE: &str = "pending.json";
+const COMPLETED_FILE: &str = "completed.json";
+const TRASH_FILE: &str = "trash.json";
+const QUERIES_FILE: &str = "queries.json";
+
+/// Last successfully fetched task views
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaskSnapshot {
+    pub today: Vec<Task>,
+    pub week: Vec<Task>,
+    pub inbox: Vec<Task>,
+}
+
+/// Local task storage under `~/.automatick/`
+#[derive(Debug, Clone)]
+pub struct TaskCache {
+    dir: PathBuf,
+}
+
+impl TaskCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn default_location() -> Self {
+        let mut path = dirs::home_dir().expect("Could not determine home directory");
+        path.push(".automatick");
+        Self::new(path)
+    }
+
+    fn get_path(&self, file_name: &str) -> PathBuf {
+        std::fs::create_dir_all(&self.dir).ok();
+        self.dir.join(file_name)
+    }
+
+    pub fn load_snapshot(&self) -> Option<TaskSnapshot> {
+        let content = std::fs::read_to_string(self.get_path(SNAPSHOT_FILE)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    pub fn save_snapshot(&self, snapshot: &TaskSnapshot) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string(snapshot)?;
+        std::fs::write(self.get_path(SNAPSHOT_FILE), json)?;
+        Ok(())
+    }
+
+    pub fn load_pending(&self) -> Vec<PendingOperation> {
+        std::fs::read_to_string(self.get_path(PENDING_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_pending(
+        &self,
+        pending: &[PendingOperation],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string(pending)?;
+        std::fs::write(self.get_path(PENDING_FILE), json)?;
+        Ok(())
+    }
+
+    pub fn load_completed(&self) -> CompletedLog {
+        std::fs::read_to_string(self.get_path(COMPLETED_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_completed(&self, log: &CompletedLog) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string(log)?;
+        std::fs::write(self.get_path(COMPLETED_FILE), json)?;
+        Ok(())
+    }
+
+    pub fn load_trash(&self) -> Trash {
+        std::fs::read_to_string(self.get_path(TRASH_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_trash(&self, trash: &Trash) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string(trash)?;
+        std::fs::write(self.get_path(TRASH_FILE), json)?;
+        Ok(())
+    }
+
+    pub fn load_queries(&self) -> Vec<SavedQuery> {
+        std::fs::read_to_string(self.get_path(QUERIES_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_queries(&self, queries: &[SavedQuery]) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string(queries)?;
+        std::fs::write(self.get_path(QUERIES_FILE), json)?;
+        Ok(())
+    }
+}
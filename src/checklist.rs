@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use ticks::tasks::Task;
+
+use crate::backend::{set_task_field, task_field};
+
+/// TickTick status of an unchecked item
+const STATUS_NORMAL: i32 = 0;
+/// TickTick status of a checked item
+const STATUS_COMPLETED: i32 = 1;
+
+/// One checklist item (subtask) of a task
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistItem {
+    /// Server id, empty for items that haven't been sent yet
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub status: i32,
+    #[serde(default)]
+    pub sort_order: i64,
+}
+
+impl ChecklistItem {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.status != STATUS_NORMAL
+    }
+
+    pub fn set_checked(&mut self, checked: bool) {
+        self.status = if checked {
+            STATUS_COMPLETED
+        } else {
+            STATUS_NORMAL
+        };
+    }
+}
+
+/// The checklist items of a task, in display order
+///
+/// Items are read through the task's JSON shape, the same way the API sends them.
+pub fn checklist_items(task: &Task) -> Vec<ChecklistItem> {
+    let mut items: Vec<ChecklistItem> = task_field(task, "items")
+        .and_then(|items| serde_json::from_value(items).ok())
+        .unwrap_or_default();
+    items.sort_by_key(|item| item.sort_order);
+    items
+}
+
+/// Replace the checklist items of a task, numbering them in the given order
+pub fn set_checklist_items(task: &mut Task, items: &[ChecklistItem]) {
+    let items: Vec<ChecklistItem> = items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| ChecklistItem {
+            sort_order: i as i64,
+            ..item.clone()
+        })
+        .collect();
+    if let Ok(items) = serde_json::to_value(items) {
+        set_task_field(task, "items", items);
+    }
+}
+
+/// Checked and total item counts, if the task has a checklist
+pub fn checklist_progress(task: &Task) -> Option<(usize, usize)> {
+    let items = checklist_items(task);
+    if items.is_empty() {
+        return None;
+    }
+    let checked = items.iter().filter(|item| item.is_checked()).count();
+    Some((checked, items.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::task_fixture;
+
+    #[test]
+    fn test_checklist_round_trip() {
+        let mut task = task_fixture("t1", "inbox", "Pack");
+        assert!(checklist_items(&task).is_empty());
+        assert_eq!(checklist_progress(&task), None);
+
+        let mut socks = ChecklistItem::new("Socks");
+        socks.set_checked(true);
+        set_checklist_items(&mut task, &[ChecklistItem::new("Charger"), socks]);
+
+        let items = checklist_items(&task);
+        let titles: Vec<&str> = items.iter().map(|item| item.title.as_str()).collect();
+        assert_eq!(titles, vec!["Charger", "Socks"]);
+        assert_eq!(items[1].sort_order, 1);
+        assert!(items[1].is_checked());
+        assert_eq!(checklist_progress(&task), Some((1, 2)));
+        assert_eq!(task.title, "Pack");
+    }
+}
@@ -0,0 +1,650 @@
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, SecondsFormat, Utc};
+use serde::Serialize;
+use std::path::PathBuf;
+use ticks::tasks::Task;
+
+use crate::{
+    backend::{task_id_string, TaskBackend},
+    quick_add::priority_name,
+    recurrence::{repeat_rule, repeat_text},
+    reminders::reminders,
+    tasks,
+    utils::{self, parse_date_and_time},
+};
+
+pub const USAGE: &str = "Usage: automatick [--config <file>] [COMMAND] [--format text|json]
+
+Starts the TUI when no command is given.
+
+Commands:
+  add <title> [--due <when>] [--content <text>]  Create a task in the inbox
+  list [--view today|week|inbox]                 List tasks (default: today)
+  complete <id>                                  Mark a task as complete
+  postpone <id> <duration>                       Postpone a task, e.g. 2days or \"now + 1h\"
+  delete <id>                                    Delete a task
+  help                                           Show this message
+
+<when> is a date, a time or both, e.g. 12/25, 5pm or \"12/25/2025 5pm\".
+<id> can be shortened to any unique prefix of the id shown by `list`.
+--format json prints tasks and command results as JSON for scripts.
+--config reads settings from <file> instead of ~/.automatick/config.toml.";
+
+/// Task views available to `list`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    Today,
+    Week,
+    Inbox,
+}
+
+/// Output format of a subcommand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+/// A headless subcommand
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Add {
+        title: String,
+        content: Option<String>,
+        date: Option<NaiveDate>,
+        time: Option<NaiveTime>,
+    },
+    List {
+        view: View,
+    },
+    Complete {
+        id: String,
+    },
+    Postpone {
+        id: String,
+        duration: String,
+    },
+    Delete {
+        id: String,
+    },
+    Help,
+}
+
+/// A parsed command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invocation {
+    pub command: Command,
+    pub format: Format,
+}
+
+/// Positional arguments and `--name value` options of a subcommand
+struct Args {
+    positional: Vec<String>,
+    options: Vec<(String, String)>,
+}
+
+impl Args {
+    fn parse(args: &[String], allowed_options: &[&str]) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut options = Vec::new();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            if let Some(option) = arg.strip_prefix("--") {
+                // Accept both `--name value` and `--name=value`
+                let (name, value) = match option.split_once('=') {
+                    Some((name, value)) => (name.to_string(), value.to_string()),
+                    None => {
+                        let value = iter
+                            .next()
+                            .ok_or_else(|| format!("Missing value for --{}", option))?;
+                        (option.to_string(), value.clone())
+                    }
+                };
+                if name != "format" && !allowed_options.contains(&name.as_str()) {
+                    return Err(format!("Unknown option: --{}", name));
+                }
+                options.push((name, value));
+            } else {
+                positional.push(arg.clone());
+            }
+        }
+        Ok(Self {
+            positional,
+            options,
+        })
+    }
+
+    fn option(&self, name: &str) -> Option<String> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(option, _)| option == name)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Take exactly `count` positional arguments
+    fn expect_positional(self, command: &str, names: &[&str]) -> Result<Vec<String>, String> {
+        if self.positional.len() != names.len() {
+            return Err(format!(
+                "Usage: automatick {} {}",
+                command,
+                names
+                    .iter()
+                    .map(|name| format!("<{}>", name))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ));
+        }
+        Ok(self.positional)
+    }
+}
+
+/// Take the global `--config <file>` option out of the arguments
+pub fn take_config_path(args: &mut Vec<String>) -> Result<Option<PathBuf>, String> {
+    let mut path = None;
+    let mut i = 0;
+    while i < args.len() {
+        if let Some(value) = args[i].strip_prefix("--config=") {
+            path = Some(PathBuf::from(value));
+            args.remove(i);
+        } else if args[i] == "--config" {
+            if i + 1 >= args.len() {
+                return Err("Missing value for --config".to_string());
+            }
+            path = Some(PathBuf::from(args.remove(i + 1)));
+            args.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    Ok(path)
+}
+
+/// The format asked for with `--format`, even when the rest of the line doesn't parse
+///
+/// Lets argument errors be reported the same way as the command's other results.
+pub fn requested_format(args: &[String]) -> Format {
+    let mut format = Format::Text;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = match arg.strip_prefix("--format") {
+            Some("") => iter.next().map(String::as_str),
+            Some(rest) => rest.strip_prefix('='),
+            None => None,
+        };
+        match value {
+            Some("json") => format = Format::Json,
+            Some(_) => format = Format::Text,
+            None => {}
+        }
+    }
+    format
+}
+
+/// Parse command line arguments (without the program name)
+///
+/// Returns `None` when no subcommand was given and the TUI should start.
+pub fn parse_args(args: &[String]) -> Result<Option<Invocation>, String> {
+    let Some((command, rest)) = args.split_first() else {
+        return Ok(None);
+    };
+
+    let format = match Args::parse(rest, &["due", "content", "view"])?
+        .option("format")
+        .as_deref()
+    {
+        None | Some("text") => Format::Text,
+        Some("json") => Format::Json,
+        Some(other) => return Err(format!("Unknown format: {}. Use text or json", other)),
+    };
+
+    let command = match command.as_str() {
+        "add" => {
+            let args = Args::parse(rest, &["due", "content"])?;
+            let (date, time) = match args.option("due") {
+                Some(when) => parse_due(&when)?,
+                None => (None, None),
+            };
+            let content = args.option("content");
+            if args.positional.is_empty() {
+                return Err("Usage: automatick add <title> [--due <when>]".to_string());
+            }
+            Command::Add {
+                title: args.positional.join(" "),
+                content,
+                date,
+                time,
+            }
+        }
+        "list" => {
+            let args = Args::parse(rest, &["view"])?;
+            let view = match args.option("view").as_deref() {
+                None | Some("today") => View::Today,
+                Some("week") => View::Week,
+                Some("inbox") => View::Inbox,
+                Some(other) => {
+                    return Err(format!("Unknown view: {}. Use today, week or inbox", other))
+                }
+            };
+            args.expect_positional("list", &[])?;
+            Command::List { view }
+        }
+        "complete" => {
+            let mut positional = Args::parse(rest, &[])?.expect_positional("complete", &["id"])?;
+            Command::Complete {
+                id: positional.remove(0),
+            }
+        }
+        "postpone" => {
+            let args = Args::parse(rest, &[])?;
+            if args.positional.len() < 2 {
+                return Err("Usage: automatick postpone <id> <duration>".to_string());
+            }
+            let mut positional = args.positional;
+            let id = positional.remove(0);
+            // Allow unquoted durations like `now + 1h`
+            let duration = positional.join(" ");
+            utils::parse_duration(&duration)?;
+            Command::Postpone { id, duration }
+        }
+        "delete" => {
+            let mut positional = Args::parse(rest, &[])?.expect_positional("delete", &["id"])?;
+            Command::Delete {
+                id: positional.remove(0),
+            }
+        }
+        "help" | "--help" | "-h" => Command::Help,
+        other => return Err(format!("Unknown command: {}", other)),
+    };
+
+    Ok(Some(Invocation { command, format }))
+}
+
+/// Parse a due date given as a date, a time (today) or a date followed by a time
+fn parse_due(when: &str) -> Result<(Option<NaiveDate>, Option<NaiveTime>), String> {
+    let (date, time) = parse_date_and_time(when)
+        .map_err(|hint| format!("Invalid due date: '{}'. {}", when.trim(), hint))?;
+    Ok((
+        Some(date.unwrap_or_else(|| Local::now().date_naive())),
+        time,
+    ))
+}
+
+/// Result of a subcommand, rendered as text or JSON
+#[derive(Debug)]
+pub enum Output {
+    Tasks(Vec<Task>),
+    Mutation { action: &'static str, task: Task },
+    Message(String),
+}
+
+/// A task as printed by `--format json`
+#[derive(Serialize)]
+struct TaskJson {
+    id: String,
+    project_id: String,
+    title: String,
+    content: String,
+    priority: &'static str,
+    /// RFC 3339, or null when the task has no due date
+    due_date: Option<String>,
+    is_all_day: bool,
+    /// RRULE, or null when the task doesn't repeat
+    repeat: Option<String>,
+    /// `TRIGGER:` durations from the due time
+    reminders: Vec<String>,
+}
+
+impl TaskJson {
+    fn new(task: &Task) -> Self {
+        Self {
+            id: task_id_string(task.get_id()),
+            project_id: task.project_id.0.clone(),
+            title: task.title.clone(),
+            content: task.content.clone(),
+            priority: priority_name(&task.priority),
+            due_date: (task.due_date.timestamp() != 0)
+                .then(|| task.due_date.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            is_all_day: task.is_all_day,
+            repeat: repeat_rule(task),
+            reminders: reminders(task),
+        }
+    }
+}
+
+impl Output {
+    pub fn render(&self, format: Format) -> String {
+        match (format, self) {
+            (Format::Text, Output::Tasks(tasks)) => tasks
+                .iter()
+                .map(format_task_line)
+                .collect::<Vec<_>>()
+                .join("\n"),
+            (Format::Text, Output::Mutation { action, task }) => {
+                let mut action = action.to_string();
+                action[..1].make_ascii_uppercase();
+                format!("{} {}", action, format_task_line(task))
+            }
+            (Format::Text, Output::Message(message)) => message.clone(),
+            (Format::Json, Output::Tasks(tasks)) => {
+                let tasks: Vec<TaskJson> = tasks.iter().map(TaskJson::new).collect();
+                serde_json::to_string_pretty(&tasks).unwrap_or_default()
+            }
+            (Format::Json, Output::Mutation { action, task }) => serde_json::to_string_pretty(
+                &serde_json::json!({ "ok": true, "action": action, "task": TaskJson::new(task) }),
+            )
+            .unwrap_or_default(),
+            (Format::Json, Output::Message(message)) => {
+                serde_json::to_string_pretty(&serde_json::json!({ "ok": true, "message": message }))
+                    .unwrap_or_default()
+            }
+        }
+    }
+}
+
+/// Render a failed subcommand
+pub fn render_error(error: &str, format: Format) -> String {
+    match format {
+        Format::Text => error.to_string(),
+        Format::Json => serde_json::json!({ "ok": false, "error": error }).to_string(),
+    }
+}
+
+/// Run a subcommand
+///
+/// Lists use the same Today/Week filters as the TUI, with a week of `week_days`.
+pub async fn run_command(
+    client: &dyn TaskBackend,
+    command: Command,
+    week_days: i64,
+) -> Result<Output, String> {
+    match command {
+        Command::Add {
+            title,
+            content,
+            date,
+            time,
+        } => {
+            let task =
+                tasks::create_task(client, title, None, content, None, None, date, time).await?;
+            Ok(Output::Mutation {
+                action: "created",
+                task,
+            })
+        }
+        Command::List { view } => {
+            let (today, week, inbox) = tasks::fetch_all_tasks(client, week_days).await?;
+            let tasks = match view {
+                View::Today => today,
+                View::Week => week,
+                View::Inbox => inbox,
+            };
+            Ok(Output::Tasks(tasks))
+        }
+        Command::Complete { id } => {
+            let task = find_task(client, &id).await?;
+            tasks::complete_task_with_client(client, &task.project_id, task.get_id()).await?;
+            Ok(Output::Mutation {
+                action: "completed",
+                task,
+            })
+        }
+        Command::Postpone { id, duration } => {
+            let target = utils::parse_duration(&duration)?;
+            let found = find_task(client, &id).await?;
+            // Get a fresh task instance to publish the change through
+            let mut task = client.get_task(&found.project_id, found.get_id()).await?;
+            let new_due = target.new_due_dates(&[task.due_date])[0];
+            let changes = tasks::TaskChanges::postponing(&task, new_due);
+            tasks::edit_task(client, &mut task, &changes).await?;
+            Ok(Output::Mutation {
+                action: "postponed",
+                task,
+            })
+        }
+        Command::Delete { id } => {
+            let task = find_task(client, &id).await?;
+            utils::delete_task(client, &task.project_id, task.get_id()).await?;
+            Ok(Output::Mutation {
+                action: "deleted",
+                task,
+            })
+        }
+        Command::Help => Ok(Output::Message(USAGE.to_string())),
+    }
+}
+
+/// Find a task in any project by its id or a unique prefix of it
+async fn find_task(client: &dyn TaskBackend, id: &str) -> Result<Task, String> {
+    let mut all_tasks = client.get_all_tasks().await?;
+    all_tasks.extend(client.get_inbox_tasks().await?);
+
+    if let Some(task) = all_tasks
+        .iter()
+        .find(|task| task_id_string(task.get_id()) == id)
+    {
+        return Ok(task.clone());
+    }
+
+    let mut matches: Vec<Task> = all_tasks
+        .into_iter()
+        .filter(|task| task_id_string(task.get_id()).starts_with(id))
+        .collect();
+    match matches.len() {
+        0 => Err(format!("No task with id {}", id)),
+        1 => Ok(matches.remove(0)),
+        count => Err(format!("Task id {} is ambiguous ({} matches)", id, count)),
+    }
+}
+
+fn format_due(due_date: &DateTime<Utc>, is_all_day: bool) -> String {
+    if due_date.timestamp() == 0 {
+        return String::new();
+    }
+    let local = due_date.with_timezone(&Local);
+    if is_all_day {
+        local.format("%m/%d/%Y").to_string()
+    } else {
+        local.format("%m/%d/%Y %I:%M %p").to_string()
+    }
+}
+
+/// One line per task: id, due date and title
+fn format_task_line(task: &Task) -> String {
+    let mut line = format!(
+        "{}  {:<19}  {}",
+        task_id_string(task.get_id()),
+        format_due(&task.due_date, task.is_all_day),
+        task.title
+    );
+    if let Some(repeat) = repeat_text(task) {
+        line.push_str(&format!("  ↻ {}", repeat));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::{task_fixture, MemoryBackend};
+
+    fn args(line: &[&str]) -> Vec<String> {
+        line.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(&[]).unwrap(), None);
+        assert_eq!(
+            parse_args(&args(&["add", "Buy", "milk", "--due", "5pm"])).unwrap(),
+            Some(Invocation {
+                command: Command::Add {
+                    title: "Buy milk".to_string(),
+                    content: None,
+                    date: Some(Local::now().date_naive()),
+                    time: NaiveTime::from_hms_opt(17, 0, 0),
+                },
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["add", "Trip", "--due=2030-01-02 9:30am"]))
+                .unwrap()
+                .map(|invocation| invocation.command),
+            Some(Command::Add {
+                title: "Trip".to_string(),
+                content: None,
+                date: NaiveDate::from_ymd_opt(2030, 1, 2),
+                time: NaiveTime::from_hms_opt(9, 30, 0),
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["list", "--view", "week", "--format", "json"])).unwrap(),
+            Some(Invocation {
+                command: Command::List { view: View::Week },
+                format: Format::Json,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["postpone", "abc", "now", "+", "1h"]))
+                .unwrap()
+                .map(|invocation| invocation.command),
+            Some(Command::Postpone {
+                id: "abc".to_string(),
+                duration: "now + 1h".to_string(),
+            })
+        );
+
+        assert!(parse_args(&args(&["add"])).is_err());
+        assert!(parse_args(&args(&["add", "x", "--due", "someday"])).is_err());
+        assert!(parse_args(&args(&["list", "--view", "month"])).is_err());
+        assert!(parse_args(&args(&["complete"])).is_err());
+        assert!(parse_args(&args(&["postpone", "abc", "soon"])).is_err());
+        assert!(parse_args(&args(&["frobnicate"])).is_err());
+        assert!(parse_args(&args(&["list", "--format", "yaml"])).is_err());
+    }
+
+    #[test]
+    fn test_requested_format() {
+        assert_eq!(requested_format(&args(&["list"])), Format::Text);
+        assert_eq!(
+            requested_format(&args(&["frobnicate", "--format", "json"])),
+            Format::Json
+        );
+        assert_eq!(
+            requested_format(&args(&["add", "--format=json", "--due"])),
+            Format::Json
+        );
+        assert_eq!(
+            requested_format(&args(&["list", "--format=json", "--format", "text"])),
+            Format::Text
+        );
+    }
+
+    #[test]
+    fn test_take_config_path() {
+        let mut line = args(&["list", "--config", "alt.toml", "--view", "week"]);
+        assert_eq!(
+            take_config_path(&mut line).unwrap(),
+            Some(PathBuf::from("alt.toml"))
+        );
+        assert_eq!(line, args(&["list", "--view", "week"]));
+
+        let mut line = args(&["--config=alt.toml"]);
+        assert_eq!(
+            take_config_path(&mut line).unwrap(),
+            Some(PathBuf::from("alt.toml"))
+        );
+        assert!(line.is_empty());
+
+        assert_eq!(take_config_path(&mut args(&["list"])).unwrap(), None);
+        assert!(take_config_path(&mut args(&["list", "--config"])).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_commands_by_id_prefix() {
+        let backend = MemoryBackend::new()
+            .with_task(task_fixture("abc123", "inbox", "Water plants"))
+            .with_task(task_fixture("abd456", "work", "Send report"));
+
+        let output = run_command(
+            &backend,
+            Command::Complete {
+                id: "abc".to_string(),
+            },
+            7,
+        )
+        .await
+        .unwrap();
+        assert!(output.render(Format::Text).starts_with("Completed abc123"));
+        assert_eq!(backend.tasks().len(), 1);
+
+        let missing = run_command(
+            &backend,
+            Command::Delete {
+                id: "zz".to_string(),
+            },
+            7,
+        )
+        .await;
+        assert_eq!(missing.unwrap_err(), "No task with id zz");
+    }
+
+    #[tokio::test]
+    async fn test_postpone_keeps_all_day() {
+        let day = NaiveDate::from_ymd_opt(2030, 12, 25).unwrap();
+        let mut task = task_fixture("abc123", "inbox", "Water plants");
+        let changes = tasks::TaskChanges {
+            date: Some(day),
+            ..Default::default()
+        };
+        tasks::apply_task_changes(&mut task, &changes).unwrap();
+        let backend = MemoryBackend::new().with_task(task);
+
+        run_command(
+            &backend,
+            Command::Postpone {
+                id: "abc".to_string(),
+                duration: "2days".to_string(),
+            },
+            7,
+        )
+        .await
+        .unwrap();
+        let task = &backend.tasks()[0];
+        assert!(task.is_all_day);
+        assert_eq!(
+            task.due_date.with_timezone(&Local).date_naive(),
+            day + chrono::Days::new(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_json_output() {
+        let backend = MemoryBackend::new().with_task(task_fixture("abc123", "work", "Send report"));
+
+        let output = run_command(
+            &backend,
+            Command::Delete {
+                id: "abc123".to_string(),
+            },
+            7,
+        )
+        .await
+        .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output.render(Format::Json)).unwrap();
+        assert_eq!(json["ok"], true);
+        assert_eq!(json["action"], "deleted");
+        assert_eq!(json["task"]["id"], "abc123");
+        assert_eq!(json["task"]["project_id"], "work");
+        assert_eq!(json["task"]["title"], "Send report");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_error("No task with id zz", Format::Json)).unwrap();
+        assert_eq!(json["ok"], false);
+        assert_eq!(json["error"], "No task with id zz");
+
+        // Messages are pretty printed like the other outputs
+        let message = Output::Message("Done".to_string()).render(Format::Json);
+        assert!(message.contains("\n  \"message\": \"Done\""));
+    }
+}
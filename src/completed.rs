@@ -0,0 +1,58 @@
+use chrono::{DateTime, Local, NaiveDate, Utc};
+
+use crate::dated_log::DatedLog;
+
+/// Tasks completed from this app, kept for two weeks for the Completed tab
+///
+/// The API only returns open tasks, so completions are remembered locally.
+pub type CompletedLog = DatedLog<14>;
+
+/// Heading for a day of completions, like `Today`, `Yesterday` or `Mon 10/12`
+pub fn day_heading(day: NaiveDate, today: NaiveDate) -> String {
+    match (today - day).num_days() {
+        0 => "Today".to_string(),
+        1 => "Yesterday".to_string(),
+        _ => day.format("%a %m/%d").to_string(),
+    }
+}
+
+/// Headings for a newest-first list of completion times, set where a new day starts
+pub fn day_headings(times: &[DateTime<Utc>], today: NaiveDate) -> Vec<Option<String>> {
+    let mut previous: Option<NaiveDate> = None;
+    times
+        .iter()
+        .map(|time| {
+            let day = time.with_timezone(&Local).date_naive();
+            let heading = (previous != Some(day)).then(|| day_heading(day, today));
+            previous = Some(day);
+            heading
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_day_headings() {
+        let today = NaiveDate::from_ymd_opt(2025, 10, 15).unwrap();
+        let at = |day: u32, hour: u32| {
+            Local
+                .with_ymd_and_hms(2025, 10, day, hour, 0, 0)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        let times = [at(15, 17), at(15, 9), at(14, 12), at(12, 8)];
+        assert_eq!(
+            day_headings(&times, today),
+            vec![
+                Some("Today".to_string()),
+                None,
+                Some("Yesterday".to_string()),
+                Some("Sun 10/12".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,525 @@
+use chrono::format::{Item, StrftimeItems};
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::auth;
+use crate::keymap::{parse_action, KeyMode, Keymap};
+use crate::query::Query;
+use crate::ui::{
+    colors::{Palette, Theme},
+    TabSlot, ViewTab,
+};
+
+const CONFIG_FILE: &str = "config.toml";
+
+/// What a project or query tab from the config lists
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TabFilter {
+    /// Tasks in the project with this name
+    Project(String),
+    /// Tasks matching a saved query, e.g. `priority:high due:<=today`
+    Query(String),
+}
+
+impl TabFilter {
+    /// The filter as query text
+    pub fn query(&self) -> String {
+        match self {
+            TabFilter::Project(name) => format!("project:\"{}\"", name),
+            TabFilter::Query(query) => query.clone(),
+        }
+    }
+}
+
+/// `strftime` formats for dates and times in the task list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateFormats {
+    pub date: String,
+    pub time: String,
+}
+
+impl Default for DateFormats {
+    fn default() -> Self {
+        Self {
+            date: "%m/%d/%Y".to_string(),
+            time: "%I:%M %p".to_string(),
+        }
+    }
+}
+
+/// Settings read from `~/.automatick/config.toml`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Ticks per second, which drive message timeouts and syncing
+    pub tick_rate: f64,
+    /// Frames drawn per second
+    pub frame_rate: f64,
+    /// Where TickTick sends the browser after signing in, served locally
+    pub redirect_uri: String,
+    /// Days ahead the Week tab looks
+    pub week_days: i64,
+    /// How long errors stay in the footer
+    pub error_seconds: f64,
+    pub dates: DateFormats,
+    /// The built-in themes followed by the config's own
+    pub themes: Vec<Theme>,
+    /// Index in `themes` of the theme in use
+    pub theme: usize,
+    /// Keys bound to actions, the built-in ones with the config's changes
+    pub keys: Keymap,
+    /// Order and labels of the tabs
+    pub tabs: Vec<TabSlot>,
+    /// Filters of the `ViewTab::Custom` tabs, by index
+    pub custom_tabs: Vec<TabFilter>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            tick_rate: 4.0,
+            frame_rate: 60.0,
+            redirect_uri: "http://localhost:8080/callback".to_string(),
+            week_days: 7,
+            error_seconds: 3.0,
+            dates: DateFormats::default(),
+            themes: Theme::built_in(),
+            theme: 0,
+            keys: Keymap::default(),
+            tabs: TabSlot::default_layout(),
+            custom_tabs: Vec::new(),
+        }
+    }
+}
+
+/// The file as written, before validation
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ConfigFile {
+    tick_rate: Option<f64>,
+    frame_rate: Option<f64>,
+    redirect_uri: Option<String>,
+    week_days: Option<i64>,
+    error_seconds: Option<f64>,
+    dates: Option<DatesEntry>,
+    /// Name of the theme to start with
+    theme: Option<String>,
+    /// Themes by name, each with an optional `base` theme and colors by name
+    themes: BTreeMap<String, BTreeMap<String, String>>,
+    /// Colors by name, like `normal_bg = "#131313"`, changed in every theme
+    colors: BTreeMap<String, String>,
+    /// Actions by key sequence for each mode, like `[keys.normal]` with `"g g" = "SelectFirst"`
+    keys: BTreeMap<String, BTreeMap<String, String>>,
+    tabs: Option<Vec<TabEntry>>,
+}
+
+/// The `[dates]` table
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DatesEntry {
+    date: Option<String>,
+    time: Option<String>,
+}
+
+/// One `[[tabs]]` table, showing a built-in view, a project or a query
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TabEntry {
+    label: Option<String>,
+    view: Option<String>,
+    project: Option<String>,
+    query: Option<String>,
+}
+
+impl Config {
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::home_dir().expect("Could not determine home directory");
+        path.push(".automatick");
+        path.push(CONFIG_FILE);
+        path
+    }
+
+    /// Read the config given with `--config`, or the default one
+    ///
+    /// Only the default file may be missing, in which case the defaults are used.
+    pub fn load(path: Option<&Path>) -> Result<Self, String> {
+        let default_path = Self::default_path();
+        let path = path.unwrap_or(&default_path);
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && path == default_path => {
+                Ok(Self::default())
+            }
+            Err(e) => Err(format!("Could not read {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Colors of the theme to start with
+    pub fn palette(&self) -> Palette {
+        self.themes[self.theme].colors
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let file: ConfigFile =
+            toml::from_str(text).map_err(|e| e.to_string().trim_end().to_string())?;
+        let mut config = Self::default();
+        if let Some(rate) = file.tick_rate {
+            config.tick_rate = in_range("tick_rate", rate, 1.0, 60.0)?;
+        }
+        if let Some(rate) = file.frame_rate {
+            config.frame_rate = in_range("frame_rate", rate, 1.0, 240.0)?;
+        }
+        if let Some(uri) = file.redirect_uri {
+            auth::callback_address(&uri).map_err(|e| format!("redirect_uri: {}", e))?;
+            config.redirect_uri = uri;
+        }
+        if let Some(days) = file.week_days {
+            config.week_days = in_range("week_days", days, 1, 365)?;
+        }
+        if let Some(seconds) = file.error_seconds {
+            config.error_seconds = in_range("error_seconds", seconds, 1.0, 60.0)?;
+        }
+        if let Some(dates) = file.dates {
+            if let Some(format) = dates.date {
+                config.dates.date = check_format("dates.date", format)?;
+            }
+            if let Some(format) = dates.time {
+                config.dates.time = check_format("dates.time", format)?;
+            }
+        }
+        for (name, mut colors) in file.themes {
+            if config.themes.iter().any(|theme| theme.name == name) {
+                return Err(format!("themes.{}: '{}' is a built-in theme", name, name));
+            }
+            let base = colors.remove("base").unwrap_or_else(|| "dark".to_string());
+            let mut palette = Theme::built_in()
+                .into_iter()
+                .find(|theme| theme.name == base)
+                .map(|theme| theme.colors)
+                .ok_or_else(|| {
+                    format!(
+                        "themes.{}: unknown base '{}'. Use dark, light or high-contrast",
+                        name, base
+                    )
+                })?;
+            set_colors(&mut palette, &format!("themes.{}", name), &colors)?;
+            config.themes.push(Theme::new(&name, palette));
+        }
+        for theme in &mut config.themes {
+            set_colors(&mut theme.colors, "colors", &file.colors)?;
+        }
+        if let Some(name) = file.theme {
+            config.theme = config
+                .themes
+                .iter()
+                .position(|theme| theme.name == name)
+                .ok_or_else(|| {
+                    let names: Vec<_> = config.themes.iter().map(|t| t.name.as_str()).collect();
+                    format!("theme: unknown theme '{}'. Use {}", name, names.join(", "))
+                })?;
+        }
+        for (mode_name, bindings) in file.keys {
+            let mode = KeyMode::ALL
+                .into_iter()
+                .find(|mode| mode.name() == mode_name)
+                .ok_or_else(|| {
+                    format!(
+                        "keys: unknown mode '{}'. Use normal, filtered, visual, search, help, confirm, editor, checklist or form",
+                        mode_name
+                    )
+                })?;
+            for (keys, action) in bindings {
+                parse_action(&action)
+                    .and_then(|action| config.keys.bind(mode, &keys, action))
+                    .map_err(|e| format!("keys.{}.\"{}\": {}", mode_name, keys, e))?;
+            }
+        }
+        config.keys.check_prefixes()?;
+        if let Some(entries) = file.tabs {
+            config.set_tabs(entries)?;
+        }
+        Ok(config)
+    }
+
+    fn set_tabs(&mut self, entries: Vec<TabEntry>) -> Result<(), String> {
+        let mut tabs = Vec::new();
+        let mut custom_tabs = Vec::new();
+        for (i, entry) in entries.into_iter().enumerate() {
+            let number = i + 1;
+            let label = entry.label.map(|label| label.trim().to_string());
+            if label.as_deref() == Some("") {
+                return Err(format!("Tab {} has an empty label", number));
+            }
+            let slot = match (entry.view, entry.project, entry.query) {
+                (Some(view), None, None) => match parse_view(&view) {
+                    Some(_) if view.eq_ignore_ascii_case("saved") && label.is_some() => {
+                        return Err(format!(
+                            "Tab {}: saved query tabs are labelled by their names",
+                            number
+                        ))
+                    }
+                    Some(Some(tab)) => TabSlot::Tab(tab, label),
+                    Some(None) => TabSlot::SavedQueries,
+                    None => {
+                        return Err(format!(
+                            "Tab {}: unknown view '{}'. Use today, week, inbox, saved, projects, tags, completed or trash",
+                            number, view
+                        ))
+                    }
+                },
+                (None, Some(project), None) => {
+                    let label = label.unwrap_or_else(|| project.clone());
+                    custom_tabs.push(TabFilter::Project(project));
+                    TabSlot::Tab(ViewTab::Custom(custom_tabs.len() - 1), Some(label))
+                }
+                (None, None, Some(query)) => {
+                    Query::parse(&query).map_err(|e| format!("Tab {}: {}", number, e))?;
+                    let Some(label) = label else {
+                        return Err(format!("Tab {}: a query tab needs a label", number));
+                    };
+                    custom_tabs.push(TabFilter::Query(query));
+                    TabSlot::Tab(ViewTab::Custom(custom_tabs.len() - 1), Some(label))
+                }
+                _ => {
+                    return Err(format!(
+                        "Tab {} needs exactly one of view, project or query",
+                        number
+                    ))
+                }
+            };
+            let repeated = match &slot {
+                TabSlot::Tab(ViewTab::Custom(_), _) => false,
+                TabSlot::Tab(tab, _) => tabs
+                    .iter()
+                    .any(|listed| matches!(listed, TabSlot::Tab(t, _) if t == tab)),
+                TabSlot::SavedQueries => tabs.contains(&TabSlot::SavedQueries),
+            };
+            if repeated {
+                return Err(format!("Tab {} repeats a view listed before it", number));
+            }
+            tabs.push(slot);
+        }
+        if !tabs.iter().any(|slot| matches!(slot, TabSlot::Tab(_, _))) {
+            return Err("At least one tab other than saved queries is needed".to_string());
+        }
+        self.tabs = tabs;
+        self.custom_tabs = custom_tabs;
+        Ok(())
+    }
+}
+
+/// Set colors by name, like `normal_bg = "#131313"`, from the `table` config table
+fn set_colors(
+    palette: &mut Palette,
+    table: &str,
+    colors: &BTreeMap<String, String>,
+) -> Result<(), String> {
+    for (name, value) in colors {
+        let color = palette
+            .color_mut(name)
+            .ok_or_else(|| format!("{}: unknown color '{}'", table, name))?;
+        *color = Color::from_str(value).map_err(|_| {
+            format!(
+                "{}.{}: invalid color '{}'. Use a name like \"red\", \"#rrggbb\" or 0-255",
+                table, name, value
+            )
+        })?;
+    }
+    Ok(())
+}
+
+fn in_range<T: PartialOrd + Display>(name: &str, value: T, min: T, max: T) -> Result<T, String> {
+    // Written this way round so NaN is out of range too
+    if !(value >= min && value <= max) {
+        return Err(format!(
+            "{} must be between {} and {}, not {}",
+            name, min, max, value
+        ));
+    }
+    Ok(value)
+}
+
+/// Make sure a `strftime` format only uses known specifiers
+fn check_format(name: &str, format: String) -> Result<String, String> {
+    if format.trim().is_empty() {
+        return Err(format!("{} can't be empty", name));
+    }
+    if StrftimeItems::new(&format).any(|item| item == Item::Error) {
+        return Err(format!("{}: invalid format '{}'", name, format));
+    }
+    Ok(format)
+}
+
+/// A built-in view by name, with `Some(None)` for the saved query tabs
+fn parse_view(name: &str) -> Option<Option<ViewTab>> {
+    if name.eq_ignore_ascii_case("saved") {
+        return Some(None);
+    }
+    ViewTab::ALL
+        .into_iter()
+        .find(|tab| tab.title().eq_ignore_ascii_case(name))
+        .map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tabs() {
+        let config = Config::parse(
+            r#"
+            [[tabs]]
+            view = "inbox"
+            label = "Capture"
+
+            [[tabs]]
+            project = "Work"
+
+            [[tabs]]
+            label = "Urgent"
+            query = "priority:high due:<=today"
+
+            [[tabs]]
+            view = "saved"
+
+            [[tabs]]
+            view = "Today"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.tabs,
+            vec![
+                TabSlot::Tab(ViewTab::Inbox, Some("Capture".to_string())),
+                TabSlot::Tab(ViewTab::Custom(0), Some("Work".to_string())),
+                TabSlot::Tab(ViewTab::Custom(1), Some("Urgent".to_string())),
+                TabSlot::SavedQueries,
+                TabSlot::Tab(ViewTab::Today, None),
+            ]
+        );
+        assert_eq!(config.custom_tabs[0].query(), "project:\"Work\"");
+
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_parse_settings() {
+        let config = Config::parse(
+            r##"
+            tick_rate = 10.0
+            week_days = 14
+            redirect_uri = "http://127.0.0.1:9000/auth"
+
+            [dates]
+            date = "%d.%m.%Y"
+
+            [colors]
+            normal_bg = "#1e1e2e"
+            accent_red = "light-red"
+            "##,
+        )
+        .unwrap();
+        assert_eq!(config.tick_rate, 10.0);
+        assert_eq!(config.frame_rate, 60.0);
+        assert_eq!(config.week_days, 14);
+        assert_eq!(config.redirect_uri, "http://127.0.0.1:9000/auth");
+        assert_eq!(config.dates.date, "%d.%m.%Y");
+        assert_eq!(config.dates.time, "%I:%M %p");
+        assert_eq!(config.palette().normal_bg, Color::Rgb(30, 30, 46));
+        assert_eq!(config.palette().accent_red, Color::LightRed);
+        assert_eq!(config.palette().accent_green, Palette::DARK.accent_green);
+    }
+
+    #[test]
+    fn test_invalid_settings() {
+        let error = |text: &str| Config::parse(text).unwrap_err();
+        assert_eq!(
+            error("week_days = 0"),
+            "week_days must be between 1 and 365, not 0"
+        );
+        assert!(error("frame_rate = 1000.0").contains("frame_rate"));
+        assert!(error("tick_rate = \"fast\"").contains("tick_rate"));
+        assert!(error("redirect_uri = \"https://example.com/cb\"").starts_with("redirect_uri"));
+        assert!(error("[dates]\ntime = \"%Q\"").contains("dates.time"));
+        assert!(error("[colors]\nnormal_bg = \"mauve\"").contains("colors.normal_bg"));
+        assert!(error("[colors]\nbackground = \"red\"").contains("unknown color 'background'"));
+        assert!(error("color = \"dark\"").contains("color"));
+    }
+
+    #[test]
+    fn test_parse_themes() {
+        let config = Config::parse(
+            r##"
+            theme = "paper"
+
+            [themes.paper]
+            base = "light"
+            tag_fg = "#336699"
+
+            [colors]
+            accent_red = "magenta"
+            "##,
+        )
+        .unwrap();
+        let names: Vec<_> = config.themes.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["dark", "light", "high-contrast", "paper"]);
+        assert_eq!(config.theme, 3);
+        assert_eq!(config.palette().tag_fg, Color::Rgb(51, 102, 153));
+        assert_eq!(config.palette().normal_bg, Palette::LIGHT.normal_bg);
+        // [colors] changes every theme
+        assert_eq!(config.themes[0].colors.accent_red, Color::Magenta);
+        assert_eq!(config.palette().accent_red, Color::Magenta);
+        assert_eq!(
+            Config::parse("theme = \"high-contrast\"")
+                .unwrap()
+                .palette(),
+            Palette::HIGH_CONTRAST
+        );
+
+        let error = |text: &str| Config::parse(text).unwrap_err();
+        assert_eq!(
+            error("theme = \"solarized\""),
+            "theme: unknown theme 'solarized'. Use dark, light, high-contrast"
+        );
+        assert!(error("[themes.light]\ntag_fg = \"red\"").contains("built-in theme"));
+        assert!(error("[themes.paper]\nbase = \"sepia\"").contains("unknown base 'sepia'"));
+        assert!(error("[themes.paper]\ntag_fg = \"beige\"").starts_with("themes.paper.tag_fg"));
+    }
+
+    #[test]
+    fn test_parse_keys() {
+        let config = Config::parse(
+            r#"
+            [keys.normal]
+            "g" = "none"
+            "g g" = "SelectFirst"
+            "ctrl+d" = "StartDeleteTask"
+            "#,
+        )
+        .unwrap();
+        assert_ne!(config.keys, Keymap::default());
+
+        let error = |text: &str| Config::parse(text).unwrap_err();
+        assert!(error("[keys.normal]\n\"g g\" = \"SelectFirst\"").contains("starts with 'g'"));
+        assert!(error("[keys.insert]\nx = \"Quit\"").contains("unknown mode 'insert'"));
+        assert!(error("[keys.normal]\nx = \"Fly\"").contains("keys.normal.\"x\": Unknown action"));
+        assert!(error("[keys.normal]\n\"hyper+x\" = \"Quit\"").contains("Unknown modifier"));
+    }
+
+    #[test]
+    fn test_invalid_tabs() {
+        let error = |text: &str| Config::parse(text).unwrap_err();
+        assert!(error("[[tabs]]\nview = \"later\"").contains("unknown view 'later'"));
+        assert!(error("[[tabs]]\nview = \"week\"\nproject = \"Work\"").contains("exactly one"));
+        assert!(error("[[tabs]]\nquery = \"tag:work\"").contains("needs a label"));
+        assert!(error("[[tabs]]\nlabel = \"Bad\"\nquery = \"owner:me\"").contains("Tab 1"));
+        assert!(error("[[tabs]]\nview = \"week\"\n[[tabs]]\nview = \"week\"").contains("Tab 2"));
+        assert!(error("[[tabs]]\nview = \"saved\"").contains("At least one tab"));
+        assert!(error("tabs = []").contains("At least one tab"));
+        assert!(error("[[tabs]]\nview = \"week\"\ncolor = \"red\"").contains("color"));
+    }
+}
@@ -0,0 +1,116 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use ticks::tasks::{Task, TaskID};
+
+use crate::backend::same_task_id;
+
+/// A task as it was when it was logged
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatedTask {
+    pub task: Task,
+    /// When the task was logged (older caches wrote `completed_at` or `deleted_at`)
+    #[serde(alias = "completed_at", alias = "deleted_at")]
+    pub at: DateTime<Utc>,
+}
+
+/// Snapshots of tasks kept for `KEEP_DAYS` days, newest first
+///
+/// Backs the Completed and Trash tabs, which list tasks the API no longer returns.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatedLog<const KEEP_DAYS: i64> {
+    entries: Vec<DatedTask>,
+}
+
+impl<const KEEP_DAYS: i64> DatedLog<KEEP_DAYS> {
+    pub fn entries(&self) -> &[DatedTask] {
+        &self.entries
+    }
+
+    /// Keep snapshots of tasks logged at `at`, dropping entries past the kept window
+    pub fn record(&mut self, tasks: &[Task], at: DateTime<Utc>) {
+        for task in tasks {
+            self.remove(task.get_id());
+            self.entries.insert(
+                0,
+                DatedTask {
+                    task: task.clone(),
+                    at,
+                },
+            );
+        }
+        self.entries.sort_by(|a, b| b.at.cmp(&a.at));
+        let cutoff = at - Duration::days(KEEP_DAYS);
+        self.entries.retain(|entry| entry.at >= cutoff);
+    }
+
+    /// Forget a task, returning whether it was in the log
+    pub fn remove(&mut self, task_id: &TaskID) -> bool {
+        let before = self.entries.len();
+        self.entries
+            .retain(|entry| !same_task_id(entry.task.get_id(), task_id));
+        self.entries.len() != before
+    }
+
+    /// When a task was logged, if it still is
+    pub fn logged_at(&self, task_id: &TaskID) -> Option<DateTime<Utc>> {
+        self.entries
+            .iter()
+            .find(|entry| same_task_id(entry.task.get_id(), task_id))
+            .map(|entry| entry.at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::task_fixture;
+
+    #[test]
+    fn test_record_and_remove() {
+        let now = Utc::now();
+        let mut log = DatedLog::<14>::default();
+        log.record(
+            &[task_fixture("t1", "inbox", "Old")],
+            now - Duration::days(30),
+        );
+        let report = task_fixture("t2", "work", "Send report");
+        log.record(&[report.clone()], now - Duration::days(2));
+        log.record(&[task_fixture("t3", "inbox", "Read a book")], now);
+
+        // Old entries are dropped and the newest come first
+        let titles: Vec<&str> = log
+            .entries()
+            .iter()
+            .map(|entry| entry.task.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Read a book", "Send report"]);
+        assert_eq!(log.entries()[1].task.project_id.0, "work");
+        assert_eq!(
+            log.logged_at(report.get_id()),
+            Some(now - Duration::days(2))
+        );
+
+        // Logging a task again moves it to the front
+        log.record(&[report.clone()], now + Duration::hours(1));
+        assert_eq!(log.entries()[0].task.title, "Send report");
+        assert_eq!(log.entries().len(), 2);
+
+        assert!(log.remove(report.get_id()));
+        assert!(!log.remove(report.get_id()));
+        assert_eq!(log.logged_at(report.get_id()), None);
+        assert_eq!(log.entries().len(), 1);
+    }
+
+    #[test]
+    fn test_reads_older_entries() {
+        let json = r#"{"entries":[{"task":TASK,"deleted_at":"2025-10-15T12:00:00Z"}]}"#.replace(
+            "TASK",
+            &serde_json::to_string(&task_fixture("t1", "inbox", "Old")).unwrap(),
+        );
+        let log: DatedLog<30> = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            log.entries()[0].at.to_rfc3339(),
+            "2025-10-15T12:00:00+00:00"
+        );
+    }
+}
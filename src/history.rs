@@ -0,0 +1,186 @@
+use ticks::tasks::{Task, TaskID};
+
+use crate::backend::same_task_id;
+
+/// Maximum number of changes that can be undone
+const MAX_HISTORY: usize = 50;
+
+/// A task change that can be undone and redone
+#[derive(Clone)]
+pub enum HistoryEntry {
+    /// Tasks that were completed, as they were before
+    Complete(Vec<Task>),
+    /// Tasks that were deleted, as they were before
+    Delete(Vec<Task>),
+    /// Tasks that were created
+    Create(Vec<Task>),
+    /// Completed tasks that were reopened
+    Reopen(Vec<Task>),
+    /// Tasks before and after an edit or postpone
+    Edit {
+        verb: String,
+        before: Vec<Task>,
+        after: Vec<Task>,
+    },
+}
+
+impl HistoryEntry {
+    /// Short description for the header, e.g. `complete "Water plants"`
+    pub fn describe(&self) -> String {
+        let (verb, tasks) = match self {
+            HistoryEntry::Complete(tasks) => ("complete", tasks),
+            HistoryEntry::Delete(tasks) => ("delete", tasks),
+            HistoryEntry::Create(tasks) => ("create", tasks),
+            HistoryEntry::Reopen(tasks) => ("reopen", tasks),
+            HistoryEntry::Edit { verb, after, .. } => (verb.as_str(), after),
+        };
+        match tasks.as_slice() {
+            [task] => format!("{} \"{}\"", verb, task.title),
+            tasks => format!("{} {} tasks", verb, tasks.len()),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            HistoryEntry::Complete(tasks)
+            | HistoryEntry::Delete(tasks)
+            | HistoryEntry::Create(tasks)
+            | HistoryEntry::Reopen(tasks) => tasks.is_empty(),
+            HistoryEntry::Edit { before, .. } => before.is_empty(),
+        }
+    }
+
+    fn task_lists_mut(&mut self) -> Vec<&mut Vec<Task>> {
+        match self {
+            HistoryEntry::Complete(tasks)
+            | HistoryEntry::Delete(tasks)
+            | HistoryEntry::Create(tasks)
+            | HistoryEntry::Reopen(tasks) => vec![tasks],
+            HistoryEntry::Edit { before, after, .. } => vec![before, after],
+        }
+    }
+
+    /// Remove a task from this entry, returning whether it was found
+    fn remove_task(&mut self, task_id: &TaskID) -> bool {
+        let mut found = false;
+        for tasks in self.task_lists_mut() {
+            if let Some(index) = tasks.iter().position(|t| same_task_id(t.get_id(), task_id)) {
+                tasks.remove(index);
+                found = true;
+            }
+        }
+        found
+    }
+}
+
+/// Undo and redo stacks for task changes
+#[derive(Default)]
+pub struct History {
+    undo: Vec<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Record a new change, clearing anything that could be redone
+    pub fn record(&mut self, entry: HistoryEntry) {
+        if entry.is_empty() {
+            return;
+        }
+        self.push_undo(entry);
+        self.redo.clear();
+    }
+
+    pub fn push_undo(&mut self, entry: HistoryEntry) {
+        self.undo.push(entry);
+        if self.undo.len() > MAX_HISTORY {
+            self.undo.remove(0);
+        }
+    }
+
+    pub fn pop_undo(&mut self) -> Option<HistoryEntry> {
+        self.undo.pop()
+    }
+
+    pub fn push_redo(&mut self, entry: HistoryEntry) {
+        self.redo.push(entry);
+    }
+
+    pub fn pop_redo(&mut self) -> Option<HistoryEntry> {
+        self.redo.pop()
+    }
+
+    /// Point entries at the real task once a placeholder has been created on the server
+    pub fn replace_task(&mut self, placeholder_id: &TaskID, task: &Task) {
+        for entry in self.undo.iter_mut().chain(self.redo.iter_mut()) {
+            for tasks in entry.task_lists_mut() {
+                for existing in tasks.iter_mut() {
+                    if same_task_id(existing.get_id(), placeholder_id) {
+                        *existing = task.clone();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Drop a task from the most recent entry that mentions it after its change was rejected
+    pub fn forget_task(&mut self, task_id: &TaskID) {
+        for stack in [&mut self.redo, &mut self.undo] {
+            if let Some(index) = stack
+                .iter_mut()
+                .rposition(|entry| entry.remove_task(task_id))
+            {
+                if stack[index].is_empty() {
+                    stack.remove(index);
+                }
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::task_fixture;
+
+    #[test]
+    fn test_record_clears_redo() {
+        let mut history = History::default();
+        history.record(HistoryEntry::Complete(vec![task_fixture(
+            "t1", "inbox", "A",
+        )]));
+        let entry = history.pop_undo().unwrap();
+        history.push_redo(entry);
+        assert_eq!(history.redo.len(), 1);
+
+        history.record(HistoryEntry::Delete(vec![task_fixture("t2", "inbox", "B")]));
+        assert!(history.pop_redo().is_none());
+        assert_eq!(history.pop_undo().unwrap().describe(), "delete \"B\"");
+    }
+
+    #[test]
+    fn test_replace_and_forget_task() {
+        let mut history = History::default();
+        let placeholder = task_fixture("pending-0", "inbox", "A");
+        history.record(HistoryEntry::Create(vec![
+            placeholder.clone(),
+            task_fixture("t2", "inbox", "B"),
+        ]));
+        assert_eq!(history.undo[0].describe(), "create 2 tasks");
+
+        let created = task_fixture("t1", "inbox", "A");
+        history.replace_task(placeholder.get_id(), &created);
+        history.forget_task(task_fixture("t2", "inbox", "B").get_id());
+        match history.pop_undo() {
+            Some(HistoryEntry::Create(tasks)) => {
+                assert_eq!(tasks.len(), 1);
+                assert!(same_task_id(tasks[0].get_id(), created.get_id()));
+            }
+            _ => panic!("expected a create entry"),
+        }
+
+        history.record(HistoryEntry::Complete(vec![created.clone()]));
+        history.forget_task(created.get_id());
+        assert!(history.pop_undo().is_none());
+    }
+}
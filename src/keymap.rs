@@ -0,0 +1,863 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::de::{value::StrDeserializer, IntoDeserializer};
+use serde::Deserialize;
+use std::fmt;
+use ticks::tasks::TaskPriority;
+
+use crate::action::Action;
+use crate::quick_add::{parse_priority, priority_name};
+
+/// Which bindings apply, named as in the `[keys.<mode>]` config tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyMode {
+    /// Browsing the task list
+    Normal,
+    /// Browsing the task list while a search filter is shown, before the normal bindings
+    Filtered,
+    Visual,
+    /// Typing at the `/` prompt, where unbound keys type text
+    Search,
+    Help,
+    /// Answering a yes/no confirmation
+    Confirm,
+    /// In the task editor pane, where text typed in insert mode goes to the field
+    Editor,
+    /// In the checklist of the task editor pane, for keys the checklist doesn't use
+    Checklist,
+    /// In a pop-up form, for keys the form passes on
+    Form,
+}
+
+impl KeyMode {
+    pub const ALL: [KeyMode; 9] = [
+        KeyMode::Normal,
+        KeyMode::Filtered,
+        KeyMode::Visual,
+        KeyMode::Search,
+        KeyMode::Help,
+        KeyMode::Confirm,
+        KeyMode::Editor,
+        KeyMode::Checklist,
+        KeyMode::Form,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyMode::Normal => "normal",
+            KeyMode::Filtered => "filtered",
+            KeyMode::Visual => "visual",
+            KeyMode::Search => "search",
+            KeyMode::Help => "help",
+            KeyMode::Confirm => "confirm",
+            KeyMode::Editor => "editor",
+            KeyMode::Checklist => "checklist",
+            KeyMode::Form => "form",
+        }
+    }
+}
+
+/// A key with its modifiers, written like `Ctrl+r`, `Alt+1` or `Enter`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Shift is already part of `G` or `BackTab`, so it's left out
+    pub fn from_event(key: KeyEvent) -> Self {
+        Self::new(
+            key.code,
+            key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER),
+        )
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid key '{}'", text);
+        // The key is after the last `+`, unless it is a `+` itself
+        let last = text.char_indices().last().ok_or_else(invalid)?.0;
+        let (prefix, key) = match text[..last].rfind('+') {
+            Some(split) => (&text[..split], &text[split + 1..]),
+            None => ("", text),
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        let mut shift = false;
+        let written_modifiers = prefix.split('+').filter(|modifier| !modifier.is_empty());
+        for modifier in written_modifiers {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+                "alt" | "meta" => modifiers |= KeyModifiers::ALT,
+                "super" | "cmd" => modifiers |= KeyModifiers::SUPER,
+                "shift" => shift = true,
+                _ => {
+                    return Err(format!(
+                        "Unknown modifier '{}' in '{}'. Use Ctrl, Alt, Super or Shift",
+                        modifier, text
+                    ))
+                }
+            }
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) if shift => KeyCode::Char(c.to_ascii_uppercase()),
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_lowercase().as_str() {
+                "tab" if shift => KeyCode::BackTab,
+                _ if shift => {
+                    return Err(format!("Shift only goes with letters or Tab in '{}'", text))
+                }
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "space" => KeyCode::Char(' '),
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                name => match name.strip_prefix('f').and_then(|n| n.parse().ok()) {
+                    Some(n @ 1..=12) => KeyCode::F(n),
+                    _ => return Err(invalid()),
+                },
+            },
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "Ctrl+"),
+            (KeyModifiers::ALT, "Alt+"),
+            (KeyModifiers::SUPER, "Super+"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                f.write_str(name)?;
+            }
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::BackTab => f.write_str("Shift+Tab"),
+            KeyCode::Up => f.write_str("↑"),
+            KeyCode::Down => f.write_str("↓"),
+            KeyCode::Left => f.write_str("←"),
+            KeyCode::Right => f.write_str("→"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+/// Parse keys pressed one after another, like `g g`
+fn parse_sequence(text: &str) -> Result<Vec<KeyChord>, String> {
+    let keys = text
+        .split_whitespace()
+        .map(KeyChord::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+    if keys.is_empty() {
+        return Err("Empty key".to_string());
+    }
+    Ok(keys)
+}
+
+fn sequence_text(keys: &[KeyChord]) -> String {
+    keys.iter()
+        .map(|key| key.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Where an action is listed in the help overlay
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HelpGroup {
+    Navigation,
+    Tasks,
+    General,
+}
+
+/// Help text of the actions keys can be bound to, or `None` for internal actions
+fn describe(action: &Action) -> Option<(HelpGroup, &'static str)> {
+    use HelpGroup::*;
+    let description = match action {
+        Action::SelectPrevious => (Navigation, "Move selection up"),
+        Action::SelectNext => (Navigation, "Move selection down"),
+        Action::SelectPreviousCycling => (Navigation, "Move selection up, wrapping around"),
+        Action::SelectNextCycling => (Navigation, "Move selection down, wrapping around"),
+        Action::SelectFirst => (Navigation, "Jump to first task"),
+        Action::SelectLast => (Navigation, "Jump to last task"),
+        Action::SelectNone => (Navigation, "Clear selection"),
+        Action::NextTab => (Navigation, "Next tab"),
+        Action::PreviousTab => (Navigation, "Previous tab"),
+        Action::SelectTab(_) => (Navigation, "Jump to a tab"),
+        Action::OpenProject | Action::OpenTag => {
+            (Navigation, "Open project or tag (Projects and Tags tabs)")
+        }
+        Action::CloseProject | Action::CloseTag => (Navigation, "Back to project or tag list"),
+        Action::EnterTaskEditor => (
+            Navigation,
+            "Focus task details (opens a project or tag when browsing)",
+        ),
+        Action::StartSearch => (
+            Navigation,
+            "Search titles and notes (Tab searches all projects)",
+        ),
+        Action::ToggleSearchScope => (Navigation, "Search this tab or all projects"),
+        Action::ConfirmSearch => (Navigation, "Keep the search and go back to the list"),
+        Action::ClearSearch => (Navigation, "Clear the search"),
+        Action::StartSaveQuery => (
+            Navigation,
+            "Save the search as a tab (edits the current saved tab)",
+        ),
+        Action::DeleteSavedQuery => (Navigation, "Remove the current saved tab"),
+        Action::EnterVisual => (Navigation, "Select several tasks (visual mode)"),
+        Action::EnterNormal => (Navigation, "Back to normal mode"),
+        Action::ExitTaskEditor => (Navigation, "Back to the task list"),
+        Action::EditorLeft => (
+            Navigation,
+            "Move left, back to the task list at the start of a line",
+        ),
+        Action::EditorDown => (Navigation, "Next line of the notes, or next field"),
+        Action::EditorUp => (Navigation, "Previous line of the notes, or previous field"),
+        Action::EditorTop => (Navigation, "First field, or first line of the notes"),
+        Action::EditorBottom => (Navigation, "Last field, or last line of the notes"),
+        Action::NextField => (Navigation, "Next field"),
+        Action::PreviousField => (Navigation, "Previous field"),
+        Action::StartCompleteTask => (Tasks, "Complete task (reopen it in the Completed tab)"),
+        Action::ReopenTasks => (Tasks, "Reopen completed tasks"),
+        Action::RestoreTasks => (Tasks, "Restore deleted tasks (Trash tab)"),
+        Action::StartDeleteTask => (Tasks, "Delete selected task (restore it in the Trash tab)"),
+        Action::StartEditTask => (Tasks, "Edit task (opens a project or tag when browsing)"),
+        Action::StartCreateTask => (Tasks, "Create new task (with date/time)"),
+        Action::StartQuickAdd => (
+            Tasks,
+            "Quick add, e.g. \"Call vendor fri 3pm !high #tag ^Work\"",
+        ),
+        Action::StartPostponeTask => (Tasks, "Postpone selected tasks"),
+        Action::StartMoveTasks => (Tasks, "Move selected tasks to a project"),
+        Action::SetPriority(_) => (Tasks, "Set priority (1 low, 2 medium, 3 high, 0 none)"),
+        Action::StartTagTasks => (Tasks, "Set tags of selected tasks"),
+        Action::StartTagFilter => (Tasks, "Filter by tag (empty clears the filter)"),
+        Action::ToggleHideUnstarted => (Tasks, "Hide or show tasks that haven't started yet"),
+        Action::NextTheme => (General, "Switch to the next color theme"),
+        Action::Undo => (Tasks, "Undo last change"),
+        Action::Redo => (Tasks, "Redo last undone change"),
+        Action::RefreshTasks => (Tasks, "Refresh task list"),
+        Action::ConfirmInput => (General, "Confirm"),
+        Action::CancelInput => (General, "Cancel"),
+        Action::ToggleHelp => (General, "Toggle this help screen"),
+        Action::Quit => (General, "Quit application"),
+        _ => return None,
+    };
+    Some(description)
+}
+
+/// Parse an action as written in the config, like `SelectNext`, `SetPriority(high)` or
+/// `SelectTab(2)`, with `None` for `none`, which unbinds a key
+pub fn parse_action(text: &str) -> Result<Option<Action>, String> {
+    let text = text.trim();
+    if text.eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+    let action = match text.strip_suffix(')').and_then(|call| call.split_once('(')) {
+        Some(("SetPriority", priority)) => {
+            Action::SetPriority(parse_priority(priority.trim()).ok_or_else(|| {
+                format!(
+                    "Unknown priority '{}'. Use none, low, medium or high",
+                    priority
+                )
+            })?)
+        }
+        // Tabs are counted from 1, like the keys that jump to them
+        Some(("SelectTab", number)) => match number.trim().parse::<usize>() {
+            Ok(number) if number >= 1 => Action::SelectTab(number - 1),
+            _ => return Err(format!("Invalid tab number '{}'", number)),
+        },
+        _ => {
+            let deserializer: StrDeserializer<'_, serde::de::value::Error> =
+                text.into_deserializer();
+            Action::deserialize(deserializer).map_err(|_| format!("Unknown action '{}'", text))?
+        }
+    };
+    if describe(&action).is_none() {
+        return Err(format!("Action '{}' can't be bound to a key", text));
+    }
+    Ok(Some(action))
+}
+
+/// How an action is written in the config
+fn action_text(action: &Action) -> String {
+    match action {
+        Action::SetPriority(priority) => format!("SetPriority({})", priority_name(priority)),
+        Action::SelectTab(position) => format!("SelectTab({})", position + 1),
+        action => action.to_string(),
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Binding {
+    mode: KeyMode,
+    keys: Vec<KeyChord>,
+    action: Action,
+}
+
+/// Lines of the help overlay under one heading
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpSection {
+    pub title: &'static str,
+    /// The keys and what they do
+    pub lines: Vec<(String, &'static str)>,
+}
+
+/// Key sequences bound to actions in each mode
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: Vec<Binding>,
+}
+
+impl Keymap {
+    /// Bind `keys` to `action` in `mode`, replacing what they were bound to
+    ///
+    /// Binding to `None` removes the keys.
+    pub fn bind(
+        &mut self,
+        mode: KeyMode,
+        keys: &str,
+        action: Option<Action>,
+    ) -> Result<(), String> {
+        let keys = parse_sequence(keys)?;
+        if mode == KeyMode::Search && keys.len() > 1 {
+            return Err(format!(
+                "'{}': the search prompt only takes single keys",
+                sequence_text(&keys)
+            ));
+        }
+        let existing = self
+            .bindings
+            .iter()
+            .position(|binding| binding.mode == mode && binding.keys == keys);
+        match (existing, action) {
+            (Some(index), Some(action)) => self.bindings[index].action = action,
+            (Some(index), None) => {
+                self.bindings.remove(index);
+            }
+            (None, Some(action)) => self.bindings.push(Binding { mode, keys, action }),
+            (None, None) => {}
+        }
+        Ok(())
+    }
+
+    /// Make sure no sequence in a mode starts with another one, which could never finish
+    pub fn check_prefixes(&self) -> Result<(), String> {
+        for long in &self.bindings {
+            let shadowed_by = self.bindings.iter().find(|short| {
+                short.mode == long.mode
+                    && short.keys.len() < long.keys.len()
+                    && long.keys.starts_with(&short.keys)
+            });
+            if let Some(short) = shadowed_by {
+                return Err(format!(
+                    "keys.{}: '{}' starts with '{}', which is bound to {}. Unbind it with \"{}\" = \"none\"",
+                    long.mode.name(),
+                    sequence_text(&long.keys),
+                    sequence_text(&short.keys),
+                    action_text(&short.action),
+                    sequence_text(&short.keys)
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The action for a key pressed after `pending`, the keys of an unfinished sequence
+    ///
+    /// Modes are tried in order. A key that can't continue the sequence starts a new one.
+    pub fn lookup(
+        &self,
+        modes: &[KeyMode],
+        pending: &mut Vec<KeyChord>,
+        key: KeyEvent,
+    ) -> Option<Action> {
+        pending.push(KeyChord::from_event(key));
+        let in_modes = || {
+            modes
+                .iter()
+                .flat_map(|mode| self.bindings.iter().filter(move |b| b.mode == *mode))
+        };
+        if let Some(binding) = in_modes().find(|binding| binding.keys == *pending) {
+            pending.clear();
+            return Some(binding.action.clone());
+        }
+        if in_modes().any(|binding| binding.keys.starts_with(pending.as_slice())) {
+            return None;
+        }
+        let restart = pending.len() > 1;
+        pending.clear();
+        if restart {
+            self.lookup(modes, pending, key)
+        } else {
+            None
+        }
+    }
+
+    /// Keys and descriptions for the help overlay, in binding order
+    pub fn help(&self) -> Vec<HelpSection> {
+        let lines = |modes: &[KeyMode], group: Option<HelpGroup>| {
+            // Actions like SetPriority(_) share one line, keyed by the variant name
+            let mut lines: Vec<(String, Vec<KeyChord>, Vec<Vec<KeyChord>>, &'static str)> =
+                Vec::new();
+            for mode in modes {
+                for binding in self.bindings.iter().filter(|b| b.mode == *mode) {
+                    let Some((action_group, description)) = describe(&binding.action) else {
+                        continue;
+                    };
+                    if group.is_some_and(|group| group != action_group) {
+                        continue;
+                    }
+                    let name = binding.action.to_string();
+                    let index = match lines.iter().position(|line| line.0 == name) {
+                        Some(index) => index,
+                        None => {
+                            lines.push((name, Vec::new(), Vec::new(), description));
+                            lines.len() - 1
+                        }
+                    };
+                    match binding.keys.as_slice() {
+                        [key] => lines[index].1.push(*key),
+                        keys => lines[index].2.push(keys.to_vec()),
+                    }
+                }
+            }
+            lines
+                .into_iter()
+                .map(|(_, keys, sequences, description)| {
+                    let keys: Vec<Vec<KeyChord>> = keys.iter().map(|key| vec![*key]).collect();
+                    let mut written = key_ranges(&keys);
+                    written.extend(key_ranges(&sequences));
+                    (written.join(" / "), description)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        [
+            (
+                "Navigation",
+                lines(&[KeyMode::Normal], Some(HelpGroup::Navigation)),
+            ),
+            (
+                "Task Actions",
+                lines(&[KeyMode::Normal], Some(HelpGroup::Tasks)),
+            ),
+            (
+                "Searching",
+                lines(&[KeyMode::Search, KeyMode::Filtered], None),
+            ),
+            ("Visual Mode", lines(&[KeyMode::Visual], None)),
+            ("Task Details", lines(&[KeyMode::Editor], None)),
+            ("Checklist", lines(&[KeyMode::Checklist], None)),
+            ("Forms", lines(&[KeyMode::Form], None)),
+            (
+                "General",
+                lines(&[KeyMode::Normal], Some(HelpGroup::General)),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, lines)| !lines.is_empty())
+        .map(|(title, lines)| HelpSection { title, lines })
+        .collect()
+    }
+}
+
+/// Write key sequences, folding runs like `1`, `2`, `3` into `1-3` and `p 0` to `p 3` into `p 0-3`
+fn key_ranges(sequences: &[Vec<KeyChord>]) -> Vec<String> {
+    // Whether `next` is `keys` with its last key one digit higher
+    let follows =
+        |keys: &[KeyChord], next: &[KeyChord]| match (keys.split_last(), next.split_last()) {
+            (Some((last, prefix)), Some((next_last, next_prefix))) => {
+                let (KeyCode::Char(c), KeyCode::Char(d)) = (last.code, next_last.code) else {
+                    return false;
+                };
+                prefix == next_prefix
+                    && last.modifiers == next_last.modifiers
+                    && c.is_ascii_digit()
+                    && d.is_ascii_digit()
+                    && d as u32 == c as u32 + 1
+            }
+            _ => false,
+        };
+    let mut written = Vec::new();
+    let mut i = 0;
+    while i < sequences.len() {
+        let mut end = i;
+        while end + 1 < sequences.len() && follows(&sequences[end], &sequences[end + 1]) {
+            end += 1;
+        }
+        match sequences[end].last() {
+            Some(last) if end > i + 1 => written.push(format!(
+                "{}-{}",
+                sequence_text(&sequences[i]),
+                last.code_text()
+            )),
+            _ => written.extend(sequences[i..=end].iter().map(|keys| sequence_text(keys))),
+        }
+        i = end + 1;
+    }
+    written
+}
+
+impl KeyChord {
+    /// The key without its modifiers
+    fn code_text(&self) -> String {
+        Self::new(self.code, KeyModifiers::NONE).to_string()
+    }
+}
+
+// Action can't derive PartialEq because TaskPriority doesn't, so compare as config text
+impl PartialEq for Keymap {
+    fn eq(&self, other: &Self) -> bool {
+        self.bindings.len() == other.bindings.len()
+            && self.bindings.iter().zip(&other.bindings).all(|(a, b)| {
+                a.mode == b.mode
+                    && a.keys == b.keys
+                    && action_text(&a.action) == action_text(&b.action)
+            })
+    }
+}
+
+impl Default for Keymap {
+    /// The built-in bindings
+    fn default() -> Self {
+        use Action::*;
+        use KeyMode::*;
+
+        let bindings: Vec<(KeyMode, &str, Action)> = vec![
+            (Normal, "j", SelectNext),
+            (Normal, "k", SelectPrevious),
+            (Normal, "Down", SelectNextCycling),
+            (Normal, "Up", SelectPreviousCycling),
+            (Normal, "g", SelectFirst),
+            (Normal, "Home", SelectFirst),
+            (Normal, "G", SelectLast),
+            (Normal, "End", SelectLast),
+            (Normal, "Tab", NextTab),
+            (Normal, "Shift+Tab", PreviousTab),
+            (Normal, "l", EnterTaskEditor),
+            (Normal, "Right", EnterTaskEditor),
+            (Normal, "Backspace", CloseProject),
+            (Normal, "Esc", SelectNone),
+            (Normal, "/", StartSearch),
+            (Normal, "S", StartSaveQuery),
+            (Normal, "X", DeleteSavedQuery),
+            (Normal, "v", EnterVisual),
+            (Normal, "e", StartCompleteTask),
+            (Normal, "R", RestoreTasks),
+            (Normal, "Enter", StartEditTask),
+            (Normal, "n", StartCreateTask),
+            (Normal, "a", StartQuickAdd),
+            (Normal, "d", StartDeleteTask),
+            (Normal, "Ctrl+p", StartPostponeTask),
+            (Normal, "Super+p", StartPostponeTask),
+            (Normal, "m", StartMoveTasks),
+            (Normal, "t", StartTagTasks),
+            (Normal, "#", StartTagFilter),
+            (Normal, "H", ToggleHideUnstarted),
+            (Normal, "u", Undo),
+            (Normal, "Ctrl+r", Redo),
+            (Normal, "r", RefreshTasks),
+            (Normal, "T", NextTheme),
+            (Normal, "?", ToggleHelp),
+            (Normal, "q", Quit),
+            (Filtered, "Esc", ClearSearch),
+            (Filtered, "n", SelectNextCycling),
+            (Filtered, "N", SelectPreviousCycling),
+            (Search, "Enter", ConfirmSearch),
+            (Search, "Esc", ClearSearch),
+            (Search, "Tab", ToggleSearchScope),
+            (Search, "Down", SelectNextCycling),
+            (Search, "Up", SelectPreviousCycling),
+            (Visual, "j", SelectNext),
+            (Visual, "k", SelectPrevious),
+            (Visual, "Down", SelectNextCycling),
+            (Visual, "Tab", SelectNextCycling),
+            (Visual, "Up", SelectPreviousCycling),
+            (Visual, "Shift+Tab", SelectPreviousCycling),
+            (Visual, "g", SelectFirst),
+            (Visual, "Home", SelectFirst),
+            (Visual, "G", SelectLast),
+            (Visual, "End", SelectLast),
+            (Visual, "e", StartCompleteTask),
+            (Visual, "R", RestoreTasks),
+            (Visual, "d", StartDeleteTask),
+            (Visual, "Ctrl+p", StartPostponeTask),
+            (Visual, "Super+p", StartPostponeTask),
+            (Visual, "m", StartMoveTasks),
+            (Visual, "t", StartTagTasks),
+            (Visual, "Esc", EnterNormal),
+            (Help, "?", ToggleHelp),
+            (Help, "Esc", ToggleHelp),
+            (Help, "q", ToggleHelp),
+            (Confirm, "y", ConfirmInput),
+            (Confirm, "Y", ConfirmInput),
+            (Confirm, "n", CancelInput),
+            (Confirm, "N", CancelInput),
+            (Confirm, "Esc", CancelInput),
+            (Editor, "q", Quit),
+            (Editor, "Esc", ExitTaskEditor),
+            (Editor, "h", EditorLeft),
+            (Editor, "Left", EditorLeft),
+            (Editor, "j", EditorDown),
+            (Editor, "Down", EditorDown),
+            (Editor, "k", EditorUp),
+            (Editor, "Up", EditorUp),
+            (Editor, "g", EditorTop),
+            (Editor, "G", EditorBottom),
+            (Editor, "Tab", NextField),
+            (Editor, "Shift+Tab", PreviousField),
+            (Editor, "Enter", ConfirmInput),
+            (Editor, "Ctrl+s", ConfirmInput),
+            (Checklist, "q", Quit),
+            (Checklist, "k", EditorUp),
+            (Checklist, "Up", EditorUp),
+            (Checklist, "Tab", NextField),
+            (Checklist, "Shift+Tab", PreviousField),
+            (Checklist, "Esc", ExitTaskEditor),
+            (Checklist, "h", ExitTaskEditor),
+            (Checklist, "Left", ExitTaskEditor),
+            (Checklist, "Enter", ConfirmInput),
+            (Form, "Enter", ConfirmInput),
+            (Form, "Ctrl+Enter", ConfirmInput),
+            (Form, "Super+Enter", ConfirmInput),
+            (Form, "Esc", CancelInput),
+        ];
+        let priorities = [
+            TaskPriority::None,
+            TaskPriority::Low,
+            TaskPriority::Medium,
+            TaskPriority::High,
+        ];
+        let mut keymap = Self {
+            bindings: Vec::new(),
+        };
+        for (mode, keys, action) in bindings {
+            keymap
+                .bind(mode, keys, Some(action))
+                .expect("built-in keys parse");
+        }
+        // Digits jump to tabs, so priority takes `p` and then a digit
+        for (digit, priority) in priorities.into_iter().enumerate() {
+            for mode in [Normal, Visual] {
+                keymap
+                    .bind(
+                        mode,
+                        &format!("p {}", digit),
+                        Some(SetPriority(priority.clone())),
+                    )
+                    .expect("built-in keys parse");
+            }
+        }
+        for position in 0..9 {
+            keymap
+                .bind(
+                    Normal,
+                    &(position + 1).to_string(),
+                    Some(SelectTab(position)),
+                )
+                .expect("built-in keys parse");
+        }
+        keymap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(
+        keymap: &Keymap,
+        modes: &[KeyMode],
+        pending: &mut Vec<KeyChord>,
+        key: &str,
+    ) -> Option<String> {
+        let chord = KeyChord::parse(key).unwrap();
+        keymap
+            .lookup(modes, pending, KeyEvent::new(chord.code, chord.modifiers))
+            .map(|action| action_text(&action))
+    }
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(
+            KeyChord::parse("Ctrl+r").unwrap(),
+            KeyChord::new(KeyCode::Char('r'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            KeyChord::parse("shift+g").unwrap(),
+            KeyChord::new(KeyCode::Char('G'), KeyModifiers::NONE)
+        );
+        assert_eq!(KeyChord::parse("shift+tab").unwrap().code, KeyCode::BackTab);
+        assert_eq!(KeyChord::parse("alt++").unwrap().code, KeyCode::Char('+'));
+        assert_eq!(KeyChord::parse("+").unwrap().code, KeyCode::Char('+'));
+        assert_eq!(KeyChord::parse("F5").unwrap().code, KeyCode::F(5));
+        assert!(KeyChord::parse("hyper+x").is_err());
+        assert!(KeyChord::parse("shift+enter").is_err());
+        assert!(KeyChord::parse("f13").is_err());
+        assert!(parse_sequence("  ").is_err());
+
+        assert_eq!(
+            KeyChord::parse("ctrl+alt+x").unwrap().to_string(),
+            "Ctrl+Alt+x"
+        );
+        assert_eq!(KeyChord::parse("up").unwrap().to_string(), "↑");
+        assert_eq!(sequence_text(&parse_sequence("g  g").unwrap()), "g g");
+    }
+
+    #[test]
+    fn test_parse_action() {
+        assert!(matches!(
+            parse_action("SelectNext"),
+            Ok(Some(Action::SelectNext))
+        ));
+        assert!(matches!(
+            parse_action("SetPriority(high)"),
+            Ok(Some(Action::SetPriority(TaskPriority::High)))
+        ));
+        assert!(matches!(
+            parse_action("SelectTab(1)"),
+            Ok(Some(Action::SelectTab(0)))
+        ));
+        assert!(matches!(parse_action("none"), Ok(None)));
+        assert!(parse_action("Jump").is_err());
+        assert!(parse_action("Tick").is_err());
+        assert!(parse_action("SelectTab(0)").is_err());
+        assert!(parse_action("SetPriority(urgent)").is_err());
+    }
+
+    #[test]
+    fn test_lookup_sequences() {
+        let mut keymap = Keymap::default();
+        let normal = [KeyMode::Normal];
+        let mut pending = Vec::new();
+        assert_eq!(
+            press(&keymap, &normal, &mut pending, "g"),
+            Some("SelectFirst".to_string())
+        );
+        assert_eq!(
+            press(&keymap, &normal, &mut pending, "2"),
+            Some("SelectTab(2)".to_string())
+        );
+        assert_eq!(press(&keymap, &normal, &mut pending, "p"), None);
+        assert_eq!(
+            press(&keymap, &normal, &mut pending, "3"),
+            Some("SetPriority(high)".to_string())
+        );
+        assert_eq!(press(&keymap, &normal, &mut pending, "ctrl+j"), None);
+
+        keymap
+            .bind(KeyMode::Normal, "g g", Some(Action::SelectFirst))
+            .unwrap();
+        assert!(keymap
+            .check_prefixes()
+            .unwrap_err()
+            .contains("Unbind it with \"g\" = \"none\""));
+        keymap.bind(KeyMode::Normal, "g", None).unwrap();
+        keymap.check_prefixes().unwrap();
+        assert_eq!(press(&keymap, &normal, &mut pending, "g"), None);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(
+            press(&keymap, &normal, &mut pending, "g"),
+            Some("SelectFirst".to_string())
+        );
+        // A key that can't finish the sequence starts over on its own
+        assert_eq!(press(&keymap, &normal, &mut pending, "g"), None);
+        assert_eq!(
+            press(&keymap, &normal, &mut pending, "j"),
+            Some("SelectNext".to_string())
+        );
+        assert!(pending.is_empty());
+
+        // Earlier modes win
+        let filtered = [KeyMode::Filtered, KeyMode::Normal];
+        assert_eq!(
+            press(&keymap, &filtered, &mut pending, "n"),
+            Some("SelectNextCycling".to_string())
+        );
+        assert_eq!(
+            press(&keymap, &filtered, &mut pending, "a"),
+            Some("StartQuickAdd".to_string())
+        );
+        assert!(keymap
+            .bind(KeyMode::Search, "a b", Some(Action::ClearSearch))
+            .is_err());
+    }
+
+    #[test]
+    fn test_help() {
+        let mut keymap = Keymap::default();
+        keymap
+            .bind(KeyMode::Normal, "ctrl+d", Some(Action::StartDeleteTask))
+            .unwrap();
+        let help = keymap.help();
+        let line = |title: &str, description: &str| {
+            help.iter()
+                .find(|section| section.title == title)
+                .and_then(|section| section.lines.iter().find(|line| line.1 == description))
+                .map(|line| line.0.clone())
+        };
+        assert_eq!(line("Navigation", "Jump to a tab").as_deref(), Some("1-9"));
+        assert_eq!(
+            line("Navigation", "Jump to first task").as_deref(),
+            Some("g / Home")
+        );
+        assert_eq!(
+            line(
+                "Task Actions",
+                "Set priority (1 low, 2 medium, 3 high, 0 none)"
+            )
+            .as_deref(),
+            Some("p 0-3")
+        );
+        assert_eq!(
+            line(
+                "Task Actions",
+                "Delete selected task (restore it in the Trash tab)"
+            )
+            .as_deref(),
+            Some("d / Ctrl+d")
+        );
+        assert_eq!(
+            line(
+                "Visual Mode",
+                "Set priority (1 low, 2 medium, 3 high, 0 none)"
+            )
+            .as_deref(),
+            Some("p 0-3")
+        );
+        assert_eq!(line("General", "Quit application").as_deref(), Some("q"));
+        assert_eq!(
+            line("Task Details", "Next line of the notes, or next field").as_deref(),
+            Some("j / ↓")
+        );
+        assert_eq!(
+            line("Checklist", "Back to the task list").as_deref(),
+            Some("Esc / h / ←")
+        );
+        assert_eq!(
+            line("Forms", "Confirm").as_deref(),
+            Some("Enter / Ctrl+Enter / Super+Enter")
+        );
+    }
+}
@@ -1,25 +1,138 @@
 mod action;
 mod app;
 mod auth;
+mod backend;
+mod cache;
+mod checklist;
+mod cli;
+mod completed;
+mod config;
+mod dated_log;
+mod history;
+mod keymap;
+mod query;
+mod quick_add;
+mod recurrence;
+mod reminders;
+mod search;
 mod tasks;
+mod trash;
 mod ui;
 mod utils;
 
 use std::sync::Arc;
 use ticks::{AccessToken, TickTick};
 
+use backend::offline::OfflineBackend;
+use cache::TaskCache;
+
 #[tokio::main]
 async fn main() {
-    if let Some((client_id, client_secret)) = auth::get_client_id() {
-        if let Some(access_token) = auth::get_access_token(client_id, client_secret).await {
-            let _ = run(access_token).await;
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let config_path = match cli::take_config_path(&mut args) {
+        Ok(path) => path,
+        Err(e) => usage_error(&e, &args),
+    };
+    let invocation = match cli::parse_args(&args) {
+        Ok(Some(cli::Invocation {
+            command: cli::Command::Help,
+            ..
+        })) => {
+            println!("{}", cli::USAGE);
+            return;
+        }
+        Ok(invocation) => invocation,
+        Err(e) => usage_error(&e, &args),
+    };
+
+    let format = invocation
+        .as_ref()
+        .map_or(cli::Format::Text, |invocation| invocation.format);
+
+    // A bad config should fail before signing in
+    let config = match config::Config::load(config_path.as_deref()) {
+        Ok(config) => config,
+        Err(e) => startup_error(&e, format),
+    };
+
+    let Some((client_id, client_secret)) = auth::get_client_id() else {
+        if invocation.is_some() {
+            startup_error(
+                "Set TICKTICK_CLIENT_ID and TICKTICK_CLIENT_SECRET to use automatick",
+                format,
+            );
+        }
+        return;
+    };
+    let Some(access_token) =
+        auth::get_access_token(client_id, client_secret, &config.redirect_uri).await
+    else {
+        if invocation.is_some() {
+            startup_error("Failed to sign in to TickTick", format);
+        }
+        return;
+    };
+
+    match invocation {
+        Some(invocation) => {
+            if let Err(e) = run_command(access_token, invocation, config.week_days).await {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        None => {
+            let _ = run(access_token, config).await;
+        }
+    }
+}
+
+/// Report a bad command line and exit, as JSON on stdout when `--format json` was asked for
+fn usage_error(error: &str, args: &[String]) -> ! {
+    match cli::requested_format(args) {
+        cli::Format::Json => println!("{}", cli::render_error(error, cli::Format::Json)),
+        cli::Format::Text => eprintln!("{}\n\n{}", error, cli::USAGE),
+    }
+    std::process::exit(2);
+}
+
+/// Report a failure before a subcommand could run and exit, as JSON on stdout like `usage_error`
+fn startup_error(error: &str, format: cli::Format) -> ! {
+    match format {
+        cli::Format::Json => println!("{}", cli::render_error(error, format)),
+        cli::Format::Text => eprintln!("{}", error),
+    }
+    std::process::exit(1);
+}
+
+/// Run a headless subcommand directly against the API
+async fn run_command(
+    access_token: AccessToken,
+    invocation: cli::Invocation,
+    week_days: i64,
+) -> anyhow::Result<()> {
+    let client = create_client(access_token)?;
+    match cli::run_command(&client, invocation.command, week_days).await {
+        Ok(output) => {
+            let output = output.render(invocation.format);
+            if !output.is_empty() {
+                println!("{}", output);
+            }
+            Ok(())
+        }
+        // JSON errors go to stdout so scripts can parse them like any other result
+        Err(e) if invocation.format == cli::Format::Json => {
+            println!("{}", cli::render_error(&e, invocation.format));
+            std::process::exit(1);
         }
+        Err(e) => Err(anyhow::anyhow!(e)),
     }
 }
 
-async fn run(access_token: AccessToken) -> anyhow::Result<()> {
+async fn run(access_token: AccessToken, config: config::Config) -> anyhow::Result<()> {
+    let cache = TaskCache::default_location();
     let client = Arc::new(create_client(access_token)?);
-    let mut app = app::App::new(client)?;
+    let backend = Arc::new(OfflineBackend::new(client, cache.clone()));
+    let mut app = app::App::new(backend, Some(cache), config)?;
     app.run().await?;
     Ok(())
 }
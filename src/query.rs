@@ -0,0 +1,331 @@
+use chrono::{Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use ticks::tasks::{Task, TaskPriority};
+
+use crate::quick_add::parse_priority;
+use crate::search;
+use crate::tasks::has_tag;
+use crate::utils::parse_date_us_format;
+
+/// A named query shown as its own tab
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedQuery {
+    pub name: String,
+    pub query: String,
+}
+
+/// How a task's value is compared with the one in a term
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Equal,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    /// Split a comparison off the front of a value, like `<=` in `<=+3d`
+    fn split(value: &str) -> (Self, &str) {
+        for (prefix, comparison) in [
+            ("<=", Comparison::LessOrEqual),
+            (">=", Comparison::GreaterOrEqual),
+            ("<", Comparison::Less),
+            (">", Comparison::Greater),
+            ("=", Comparison::Equal),
+        ] {
+            if let Some(rest) = value.strip_prefix(prefix) {
+                return (comparison, rest);
+            }
+        }
+        (Comparison::Equal, value)
+    }
+
+    fn holds<T: Ord>(self, left: T, right: T) -> bool {
+        match self {
+            Comparison::Equal => left == right,
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Greater => left > right,
+            Comparison::GreaterOrEqual => left >= right,
+        }
+    }
+}
+
+/// Day a due date is compared with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Day {
+    /// Days from today, so saved queries keep up with the calendar
+    FromToday(i64),
+    On(NaiveDate),
+}
+
+impl Day {
+    /// The day itself, or `None` when the offset runs off the calendar
+    fn resolve(self, today: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Day::FromToday(days) => today.checked_add_signed(Duration::try_days(days)?),
+            Day::On(date) => Some(date),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    /// The task has no due date
+    NoDue,
+    Due(Comparison, Day),
+    Priority(Comparison, u8),
+    Tag(String),
+    /// Project name, ignoring case
+    Project(String),
+    /// A word in the title or content
+    Text(String),
+}
+
+impl Condition {
+    fn matches(&self, task: &Task, project_name: Option<&str>, today: NaiveDate) -> bool {
+        match self {
+            Condition::NoDue => task.due_date.timestamp() <= 0,
+            Condition::Due(comparison, day) => {
+                task.due_date.timestamp() > 0
+                    && day.resolve(today).is_some_and(|day| {
+                        comparison.holds(task.due_date.with_timezone(&Local).date_naive(), day)
+                    })
+            }
+            Condition::Priority(comparison, rank) => {
+                comparison.holds(priority_rank(&task.priority), *rank)
+            }
+            Condition::Tag(tag) => has_tag(task, tag),
+            Condition::Project(name) => project_name.is_some_and(|p| p.eq_ignore_ascii_case(name)),
+            Condition::Text(word) => search::matches(task, word),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Term {
+    /// Written with a leading `-`, so matching tasks are left out
+    negated: bool,
+    condition: Condition,
+}
+
+/// A filter like `due:<=+3d priority:>=medium tag:work -project:Personal`
+///
+/// Every term has to match. Plain words search titles and notes, and values with
+/// spaces can be quoted, as in `project:"Side projects"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    terms: Vec<Term>,
+}
+
+impl Query {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let terms = split_terms(text)?
+            .iter()
+            .map(|term| parse_term(term))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { terms })
+    }
+
+    /// Whether a task passes every term, given the name of its project
+    pub fn matches(&self, task: &Task, project_name: Option<&str>, today: NaiveDate) -> bool {
+        self.terms
+            .iter()
+            .all(|term| term.condition.matches(task, project_name, today) != term.negated)
+    }
+}
+
+/// Split a query at spaces outside of double quotes, dropping the quotes
+fn split_terms(text: &str) -> Result<Vec<String>, String> {
+    let mut terms = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in text.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    terms.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if quoted {
+        return Err("Missing closing quote".to_string());
+    }
+    if !current.is_empty() {
+        terms.push(current);
+    }
+    Ok(terms)
+}
+
+fn parse_term(term: &str) -> Result<Term, String> {
+    let (negated, term) = match term.strip_prefix('-') {
+        Some(rest) if !rest.is_empty() => (true, rest),
+        _ => (false, term),
+    };
+    let Some((field, value)) = term.split_once(':') else {
+        return Ok(Term {
+            negated,
+            condition: Condition::Text(term.to_string()),
+        });
+    };
+    if value.is_empty() {
+        return Err(format!("Missing a value after '{}:'", field));
+    }
+
+    let condition = match field.to_lowercase().as_str() {
+        "due" => parse_due(value)?,
+        "priority" => {
+            let (comparison, name) = Comparison::split(value);
+            let priority = parse_priority(name).ok_or_else(|| {
+                format!("Unknown priority '{}'. Use none, low, medium or high", name)
+            })?;
+            Condition::Priority(comparison, priority_rank(&priority))
+        }
+        "tag" => Condition::Tag(value.trim_start_matches('#').to_string()),
+        "project" => Condition::Project(value.to_string()),
+        _ => {
+            return Err(format!(
+                "Unknown field '{}'. Use due, priority, tag or project",
+                field
+            ))
+        }
+    };
+    Ok(Term { negated, condition })
+}
+
+fn parse_due(value: &str) -> Result<Condition, String> {
+    let (comparison, day) = Comparison::split(value);
+    let day = day.to_lowercase();
+    if day == "none" {
+        return match comparison {
+            Comparison::Equal => Ok(Condition::NoDue),
+            _ => Err("due:none can't be compared".to_string()),
+        };
+    }
+
+    let parsed = match day.as_str() {
+        "today" => Some(Day::FromToday(0)),
+        "tomorrow" => Some(Day::FromToday(1)),
+        "yesterday" => Some(Day::FromToday(-1)),
+        _ => parse_offset(&day).or_else(|| parse_date_us_format(&day).ok().map(Day::On)),
+    };
+    parsed
+        .map(|day| Condition::Due(comparison, day))
+        .ok_or_else(|| {
+            format!(
+                "Invalid due date '{}'. Use today, +3d, -1w, 12/25 or none",
+                day
+            )
+        })
+}
+
+/// Furthest a day offset can reach, about a century either way
+const MAX_OFFSET_DAYS: i64 = 36_500;
+
+/// Parse a day offset such as `+3d`, `-1w` or `2w`
+fn parse_offset(text: &str) -> Option<Day> {
+    let (sign, text) = match text.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let days_per_unit = match text.chars().last()? {
+        'd' => 1,
+        'w' => 7,
+        _ => return None,
+    };
+    let count: i64 = text[..text.len() - 1].parse().ok()?;
+    let days = count.checked_mul(days_per_unit)?;
+    (days <= MAX_OFFSET_DAYS).then_some(Day::FromToday(sign * days))
+}
+
+fn priority_rank(priority: &TaskPriority) -> u8 {
+    match priority {
+        TaskPriority::None => 0,
+        TaskPriority::Low => 1,
+        TaskPriority::Medium => 2,
+        TaskPriority::High => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::task_fixture;
+    use chrono::{TimeZone, Utc};
+
+    fn due_on(task: &mut Task, date: NaiveDate) {
+        task.due_date = Local
+            .from_local_datetime(&date.and_hms_opt(12, 0, 0).unwrap())
+            .unwrap()
+            .with_timezone(&Utc);
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let query =
+            Query::parse("due:<=+3d priority:>=medium tag:#work -project:\"Side projects\"")
+                .unwrap();
+        assert_eq!(query.terms.len(), 4);
+        assert_eq!(
+            query.terms[0].condition,
+            Condition::Due(Comparison::LessOrEqual, Day::FromToday(3))
+        );
+        assert_eq!(
+            query.terms[1].condition,
+            Condition::Priority(Comparison::GreaterOrEqual, 2)
+        );
+        assert_eq!(query.terms[2].condition, Condition::Tag("work".to_string()));
+        assert!(query.terms[3].negated);
+        assert_eq!(
+            query.terms[3].condition,
+            Condition::Project("Side projects".to_string())
+        );
+
+        assert_eq!(parse_offset("-1w"), Some(Day::FromToday(-7)));
+        assert_eq!(parse_offset("2d"), Some(Day::FromToday(2)));
+        assert_eq!(parse_offset("+100000000d"), None);
+        assert_eq!(parse_offset("-2000000000000000000w"), None);
+        assert!(Query::parse("due:<=+100000000d").is_err());
+        assert!(Query::parse("").unwrap().terms.is_empty());
+        assert!(Query::parse("due:soonish").is_err());
+        assert!(Query::parse("priority:urgent").is_err());
+        assert!(Query::parse("owner:me").is_err());
+        assert!(Query::parse("tag:").is_err());
+        assert!(Query::parse("due:<none").is_err());
+        assert!(Query::parse("project:\"Side").is_err());
+    }
+
+    #[test]
+    fn test_query_matches() {
+        let today = NaiveDate::from_ymd_opt(2025, 10, 15).unwrap();
+        let mut report = task_fixture("t1", "work", "Send report");
+        due_on(&mut report, today + Duration::days(2));
+        report.priority = TaskPriority::High;
+        report.tags = vec!["Work".to_string()];
+        let mut book = task_fixture("t2", "inbox", "Read a book");
+        book.priority = TaskPriority::Low;
+
+        let query = Query::parse("due:<=+3d priority:>=medium tag:work -project:Personal").unwrap();
+        assert!(query.matches(&report, Some("Work"), today));
+        assert!(!query.matches(&report, Some("Personal"), today));
+        assert!(!query.matches(&book, Some("Inbox"), today));
+
+        let undated = Query::parse("due:none book").unwrap();
+        assert!(undated.matches(&book, Some("Inbox"), today));
+        assert!(!undated.matches(&report, Some("Work"), today));
+        assert!(Query::parse("due:>today")
+            .unwrap()
+            .matches(&report, None, today));
+        assert!(!Query::parse("due:10/16/2025")
+            .unwrap()
+            .matches(&report, None, today));
+
+        // An offset past the end of the calendar matches nothing rather than panicking
+        let far = Condition::Due(Comparison::LessOrEqual, Day::FromToday(i64::MAX));
+        assert!(!far.matches(&report, None, today));
+    }
+}
@@ -0,0 +1,295 @@
+use chrono::{NaiveDate, NaiveTime};
+use ticks::{projects::ProjectID, tasks::TaskPriority};
+
+use crate::{
+    backend::{NewTask, ProjectInfo},
+    tasks,
+    utils::{parse_date_us_format, parse_time_us_format},
+};
+
+/// Fields parsed from a single quick-add line
+///
+/// e.g. `Call vendor tomorrow 3pm !high #billing ^Work`
+#[derive(Debug, Clone, Default)]
+pub struct QuickAdd {
+    pub title: String,
+    pub date: Option<NaiveDate>,
+    pub time: Option<NaiveTime>,
+    pub priority: Option<TaskPriority>,
+    pub tags: Vec<String>,
+    /// Project name as typed after `^`
+    pub project: Option<String>,
+}
+
+impl QuickAdd {
+    /// The date to use, falling back to `default_date`, or today when only a time was given
+    pub fn due_date(&self, default_date: Option<NaiveDate>) -> Option<NaiveDate> {
+        self.date
+            .or(default_date)
+            .or_else(|| self.time.map(|_| chrono::Local::now().date_naive()))
+    }
+
+    /// Fields for creating the task in the given project (the inbox if `None`)
+    pub fn new_task(
+        &self,
+        project_id: Option<ProjectID>,
+        default_date: Option<NaiveDate>,
+    ) -> Result<NewTask, String> {
+        let mut new_task = tasks::new_task(
+            self.title.clone(),
+            project_id,
+            None,
+            None,
+            self.priority.clone(),
+            self.due_date(default_date),
+            self.time,
+        )?;
+        new_task.tags = self.tags.clone();
+        Ok(new_task)
+    }
+}
+
+/// Words that may introduce a date or time, e.g. "due fri" or "at 5pm"
+const DATE_PREFIXES: [&str; 3] = ["on", "by", "due"];
+const TIME_PREFIX: &str = "at";
+
+/// Parse a quick-add line
+///
+/// Recognizes `!priority`, `#tag`, `^project`, one date (anything accepted by
+/// `parse_date_us_format`, up to three words) and one time. Everything else is
+/// the title.
+pub fn parse_quick_add(input: &str) -> QuickAdd {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    let mut quick_add = QuickAdd::default();
+    let mut title_words = Vec::new();
+
+    let mut i = 0;
+    while i < words.len() {
+        let word = words[i];
+
+        if let Some(priority) = word.strip_prefix('!').and_then(parse_priority) {
+            quick_add.priority = Some(priority);
+            i += 1;
+            continue;
+        }
+        if let Some(tag) = word.strip_prefix('#').filter(|tag| !tag.is_empty()) {
+            if !quick_add.tags.iter().any(|t| t == tag) {
+                quick_add.tags.push(tag.to_string());
+            }
+            i += 1;
+            continue;
+        }
+        if let Some(project) = word.strip_prefix('^').filter(|p| !p.is_empty()) {
+            quick_add.project = Some(project.to_string());
+            i += 1;
+            continue;
+        }
+
+        if quick_add.date.is_none() {
+            let start = if DATE_PREFIXES.contains(&word.to_lowercase().as_str()) {
+                i + 1
+            } else {
+                i
+            };
+            if let Some((date, end)) = match_phrase(&words, start, 3, parse_date_us_format) {
+                quick_add.date = Some(date);
+                i = end;
+                continue;
+            }
+        }
+        if quick_add.time.is_none() {
+            let start = if word.eq_ignore_ascii_case(TIME_PREFIX) {
+                i + 1
+            } else {
+                i
+            };
+            if let Some((time, end)) = match_phrase(&words, start, 2, parse_time_us_format) {
+                quick_add.time = Some(time);
+                i = end;
+                continue;
+            }
+        }
+
+        title_words.push(word);
+        i += 1;
+    }
+
+    quick_add.title = title_words.join(" ");
+    quick_add
+}
+
+/// Try the longest phrase of up to `max_words` words starting at `start`
+///
+/// Returns the parsed value and the index after the phrase.
+fn match_phrase<T>(
+    words: &[&str],
+    start: usize,
+    max_words: usize,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Option<(T, usize)> {
+    (1..=max_words).rev().find_map(|len| {
+        let end = start + len;
+        if end > words.len() {
+            return None;
+        }
+        parse(&words[start..end].join(" "))
+            .ok()
+            .map(|value| (value, end))
+    })
+}
+
+/// Parse a priority name or TickTick's `!1`-`!3` shorthand
+pub fn parse_priority(name: &str) -> Option<TaskPriority> {
+    match name.to_lowercase().as_str() {
+        "high" | "h" | "3" => Some(TaskPriority::High),
+        "medium" | "med" | "m" | "2" => Some(TaskPriority::Medium),
+        "low" | "l" | "1" => Some(TaskPriority::Low),
+        "none" | "0" => Some(TaskPriority::None),
+        _ => None,
+    }
+}
+
+/// Parse a priority typed into an editor field, where blank means no priority
+pub fn parse_priority_field(text: &str) -> Result<TaskPriority, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(TaskPriority::None);
+    }
+    parse_priority(text)
+        .ok_or_else(|| "Invalid priority. Use high, medium, low or none".to_string())
+}
+
+/// Lowercase name of a priority, as accepted by [`parse_priority`]
+pub fn priority_name(priority: &TaskPriority) -> &'static str {
+    match priority {
+        TaskPriority::High => "high",
+        TaskPriority::Medium => "medium",
+        TaskPriority::Low => "low",
+        TaskPriority::None => "none",
+    }
+}
+
+/// Parse a tags field like `billing, #home work` into tags, dropping repeats
+pub fn parse_tags(text: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for word in text.split(|c: char| c == ',' || c.is_whitespace()) {
+        let tag = word.trim_start_matches('#');
+        if !tag.is_empty() && !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// Text for a tags field, as accepted by [`parse_tags`]
+pub fn format_tags(tags: &[String]) -> String {
+    tags.join(", ")
+}
+
+/// Find a project by name, ignoring case, spaces, `-` and `_`
+///
+/// Falls back to a unique prefix so `^side` finds "Side Projects".
+pub fn find_project<'a>(projects: &'a [ProjectInfo], name: &str) -> Option<&'a ProjectInfo> {
+    let normalize = |s: &str| -> String {
+        s.chars()
+            .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    let name = normalize(name);
+
+    if let Some(project) = projects.iter().find(|p| normalize(&p.name) == name) {
+        return Some(project);
+    }
+    let mut matches = projects
+        .iter()
+        .filter(|p| normalize(&p.name).starts_with(&name));
+    match (matches.next(), matches.next()) {
+        (Some(project), None) => Some(project),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Local};
+
+    #[test]
+    fn test_parse_quick_add() {
+        let parsed = parse_quick_add("Call vendor tomorrow 3pm !high #billing ^Work");
+        assert_eq!(parsed.title, "Call vendor");
+        assert_eq!(
+            parsed.date,
+            Some(Local::now().date_naive() + Duration::days(1))
+        );
+        assert_eq!(parsed.time, NaiveTime::from_hms_opt(15, 0, 0));
+        assert!(matches!(parsed.priority, Some(TaskPriority::High)));
+        assert_eq!(parsed.tags, vec!["billing"]);
+        assert_eq!(parsed.project.as_deref(), Some("Work"));
+
+        let parsed = parse_quick_add("Pay rent due 12/01/2030 at 9:30 am #home #home !1");
+        assert_eq!(parsed.title, "Pay rent");
+        assert_eq!(parsed.date, NaiveDate::from_ymd_opt(2030, 12, 1));
+        assert_eq!(parsed.time, NaiveTime::from_hms_opt(9, 30, 0));
+        assert!(matches!(parsed.priority, Some(TaskPriority::Low)));
+        assert_eq!(parsed.tags, vec!["home"]);
+
+        // Multi-word dates, and only the first date is taken
+        let parsed = parse_quick_add("Plan trip next friday then today");
+        assert_eq!(parsed.title, "Plan trip then today");
+        assert!(parsed.date.is_some());
+
+        // Markers without a value and unknown priorities stay in the title
+        let parsed = parse_quick_add("Fix # and ^ at !urgent");
+        assert_eq!(parsed.title, "Fix # and ^ at !urgent");
+        assert!(parsed.date.is_none() && parsed.time.is_none());
+        assert!(parsed.priority.is_none() && parsed.project.is_none());
+    }
+
+    #[test]
+    fn test_find_project() {
+        let project = |id: &str, name: &str| ProjectInfo {
+            id: ProjectID(id.to_string()),
+            name: name.to_string(),
+        };
+        let projects = vec![
+            project("p1", "Work"),
+            project("p2", "Side Projects"),
+            project("p3", "Workshop"),
+        ];
+
+        assert_eq!(find_project(&projects, "work").unwrap().id.0, "p1");
+        assert_eq!(find_project(&projects, "side_projects").unwrap().id.0, "p2");
+        assert_eq!(find_project(&projects, "side").unwrap().id.0, "p2");
+        assert_eq!(find_project(&projects, "works").unwrap().id.0, "p3");
+        assert!(find_project(&projects, "wor").is_none());
+        assert!(find_project(&projects, "home").is_none());
+    }
+
+    #[test]
+    fn test_parse_priority_field() {
+        assert!(matches!(
+            parse_priority_field(" High "),
+            Ok(TaskPriority::High)
+        ));
+        assert!(matches!(
+            parse_priority_field("2"),
+            Ok(TaskPriority::Medium)
+        ));
+        assert!(matches!(parse_priority_field(""), Ok(TaskPriority::None)));
+        assert!(parse_priority_field("urgent").is_err());
+        assert_eq!(priority_name(&TaskPriority::Low), "low");
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        assert_eq!(
+            parse_tags("billing, #home  work,,Home"),
+            vec!["billing", "home", "work"]
+        );
+        assert!(parse_tags(" , # ").is_empty());
+        let tags = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(parse_tags(&format_tags(&tags)), tags);
+    }
+}
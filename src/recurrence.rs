@@ -0,0 +1,472 @@
+use chrono::Weekday;
+use ticks::tasks::Task;
+
+use crate::{
+    backend::{set_task_field, task_field},
+    utils::parse_weekday,
+};
+
+const INVALID_REPEAT: &str = "Try \"every weekday\" or \"every 2 weeks on mon,thu\"";
+
+/// How often a task repeats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    fn rrule_name(self) -> &'static str {
+        match self {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        }
+    }
+
+    fn from_rrule_name(name: &str) -> Option<Self> {
+        match name {
+            "DAILY" => Some(Frequency::Daily),
+            "WEEKLY" => Some(Frequency::Weekly),
+            "MONTHLY" => Some(Frequency::Monthly),
+            "YEARLY" => Some(Frequency::Yearly),
+            _ => None,
+        }
+    }
+
+    fn unit(self) -> &'static str {
+        match self {
+            Frequency::Daily => "day",
+            Frequency::Weekly => "week",
+            Frequency::Monthly => "month",
+            Frequency::Yearly => "year",
+        }
+    }
+}
+
+/// A recurrence rule, limited to what friendly text can express
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Recurrence {
+    frequency: Frequency,
+    interval: u32,
+    /// Days of the week for weekly rules, Monday first
+    weekdays: Vec<Weekday>,
+    /// Day of the month for monthly rules
+    month_day: Option<u32>,
+}
+
+const WORKDAYS: [Weekday; 5] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+];
+const WEEKEND: [Weekday; 2] = [Weekday::Sat, Weekday::Sun];
+
+fn weekday_code(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn weekday_from_code(code: &str) -> Option<Weekday> {
+    [WORKDAYS.as_slice(), WEEKEND.as_slice()]
+        .concat()
+        .into_iter()
+        .find(|day| weekday_code(*day) == code)
+}
+
+/// Short lowercase name, e.g. `mon`
+fn weekday_name(day: Weekday) -> String {
+    day.to_string().to_lowercase()
+}
+
+/// `1st`, `2nd`, `3rd`, `4th`, ..., `21st`
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+fn parse_ordinal(word: &str) -> Option<u32> {
+    let digits = word
+        .strip_suffix("st")
+        .or_else(|| word.strip_suffix("nd"))
+        .or_else(|| word.strip_suffix("rd"))
+        .or_else(|| word.strip_suffix("th"))?;
+    digits.parse().ok()
+}
+
+impl Recurrence {
+    /// Parse friendly text like `every weekday` or `every 2 weeks on mon,thu`
+    fn parse(text: &str) -> Result<Self, String> {
+        let text = text.to_lowercase().replace(',', " ");
+        let mut frequency = None;
+        let mut interval = None;
+        let mut weekdays: Vec<Weekday> = Vec::new();
+        let mut month_day = None;
+
+        for word in text.split_whitespace() {
+            match word {
+                "every" | "each" | "on" | "and" | "the" => {}
+                "other" => interval = Some(2),
+                "daily" | "day" | "days" => frequency = Some(Frequency::Daily),
+                "weekly" | "week" | "weeks" => frequency = Some(Frequency::Weekly),
+                "monthly" | "month" | "months" => frequency = Some(Frequency::Monthly),
+                "yearly" | "annually" | "year" | "years" => frequency = Some(Frequency::Yearly),
+                "weekday" | "weekdays" => weekdays.extend(WORKDAYS),
+                "weekend" | "weekends" => weekdays.extend(WEEKEND),
+                _ => {
+                    if let Some(day) =
+                        parse_weekday(word).or_else(|| parse_weekday(word.trim_end_matches('s')))
+                    {
+                        weekdays.push(day);
+                    } else if let Ok(n) = word.parse::<u32>() {
+                        // A number after "every month" is the day, otherwise the interval
+                        if frequency == Some(Frequency::Monthly) {
+                            month_day = Some(n);
+                        } else {
+                            interval = Some(n);
+                        }
+                    } else if let Some(n) = parse_ordinal(word) {
+                        month_day = Some(n);
+                    } else {
+                        return Err(format!("Invalid repeat \"{}\". {}", word, INVALID_REPEAT));
+                    }
+                }
+            }
+        }
+
+        let frequency = match frequency {
+            Some(frequency) => frequency,
+            None if !weekdays.is_empty() => Frequency::Weekly,
+            None if month_day.is_some() => Frequency::Monthly,
+            None => return Err(format!("Invalid repeat. {}", INVALID_REPEAT)),
+        };
+        if !weekdays.is_empty() && frequency != Frequency::Weekly {
+            return Err("Days of the week only work with weekly repeats".to_string());
+        }
+        if month_day.is_some() && frequency != Frequency::Monthly {
+            return Err("A day of the month only works with monthly repeats".to_string());
+        }
+        if let Some(day) = month_day.filter(|day| !(1..=31).contains(day)) {
+            return Err(format!("Invalid day of the month: {}", day));
+        }
+        let interval = interval.unwrap_or(1);
+        if interval == 0 {
+            return Err(format!("Invalid repeat. {}", INVALID_REPEAT));
+        }
+
+        weekdays.sort_by_key(|day| day.num_days_from_monday());
+        weekdays.dedup();
+        Ok(Self {
+            frequency,
+            interval,
+            weekdays,
+            month_day,
+        })
+    }
+
+    /// Read an RRULE, or `None` if it uses parts friendly text can't express
+    fn from_rrule(rule: &str) -> Option<Self> {
+        let rule = rule.trim();
+        let body = rule
+            .get(..6)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("RRULE:"))
+            .map_or(rule, |_| &rule[6..]);
+
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut weekdays = Vec::new();
+        let mut month_day = None;
+        for part in body.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part.split_once('=')?;
+            let value = value.to_uppercase();
+            match key.to_uppercase().as_str() {
+                "FREQ" => frequency = Some(Frequency::from_rrule_name(&value)?),
+                "INTERVAL" => interval = value.parse().ok().filter(|n| *n > 0)?,
+                "BYDAY" => {
+                    for code in value.split(',') {
+                        weekdays.push(weekday_from_code(code)?);
+                    }
+                }
+                "BYMONTHDAY" => month_day = Some(value.parse().ok()?),
+                _ => return None,
+            }
+        }
+
+        let frequency = frequency?;
+        if (!weekdays.is_empty() && frequency != Frequency::Weekly)
+            || (month_day.is_some() && frequency != Frequency::Monthly)
+        {
+            return None;
+        }
+        weekdays.sort_by_key(|day: &Weekday| day.num_days_from_monday());
+        weekdays.dedup();
+        Some(Self {
+            frequency,
+            interval,
+            weekdays,
+            month_day,
+        })
+    }
+
+    fn to_rrule(&self) -> String {
+        let mut rule = format!(
+            "RRULE:FREQ={};INTERVAL={}",
+            self.frequency.rrule_name(),
+            self.interval
+        );
+        if !self.weekdays.is_empty() {
+            let codes: Vec<&str> = self.weekdays.iter().map(|day| weekday_code(*day)).collect();
+            rule.push_str(&format!(";BYDAY={}", codes.join(",")));
+        }
+        if let Some(day) = self.month_day {
+            rule.push_str(&format!(";BYMONTHDAY={}", day));
+        }
+        rule
+    }
+
+    /// Friendly text that [`Recurrence::parse`] reads back as the same rule
+    fn describe(&self) -> String {
+        if self.interval == 1 && self.frequency == Frequency::Weekly {
+            if self.weekdays == WORKDAYS {
+                return "every weekday".to_string();
+            }
+            if self.weekdays == WEEKEND {
+                return "every weekend".to_string();
+            }
+        }
+
+        let mut text = if self.interval == 1 {
+            format!("every {}", self.frequency.unit())
+        } else {
+            format!("every {} {}s", self.interval, self.frequency.unit())
+        };
+        if !self.weekdays.is_empty() {
+            let names: Vec<String> = self.weekdays.iter().map(|day| weekday_name(*day)).collect();
+            text.push_str(&format!(" on {}", names.join(",")));
+        }
+        if let Some(day) = self.month_day {
+            text.push_str(&format!(" on the {}", ordinal(day)));
+        }
+        text
+    }
+}
+
+/// Parse a repeat field into an RRULE string (blank or `never` means no repeat)
+///
+/// Accepts friendly text like `every weekday` or `every 2 weeks on mon,thu`, or a
+/// raw RRULE for rules the friendly text can't express.
+pub fn parse_recurrence(text: &str) -> Result<Option<String>, String> {
+    let text = text.trim();
+    if text.is_empty() || text.eq_ignore_ascii_case("never") || text.eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+    if text.to_uppercase().contains("FREQ=") {
+        let body = text
+            .trim_start_matches("RRULE:")
+            .trim_start_matches("rrule:");
+        if !body.to_uppercase().split(';').any(|part| {
+            part.strip_prefix("FREQ=")
+                .and_then(Frequency::from_rrule_name)
+                .is_some()
+        }) {
+            return Err("Invalid RRULE: FREQ must be DAILY, WEEKLY, MONTHLY or YEARLY".to_string());
+        }
+        return Ok(Some(format!("RRULE:{}", body.to_uppercase())));
+    }
+    Recurrence::parse(text).map(|recurrence| Some(recurrence.to_rrule()))
+}
+
+/// Friendly text for an RRULE, or the rule itself if there's no friendly form
+pub fn describe_rrule(rule: &str) -> String {
+    match Recurrence::from_rrule(rule) {
+        Some(recurrence) => recurrence.describe(),
+        None => rule.trim().trim_start_matches("RRULE:").to_string(),
+    }
+}
+
+/// The task's recurrence as an RRULE string, if it repeats
+pub fn repeat_rule(task: &Task) -> Option<String> {
+    task_field(task, "repeatFlag")
+        .and_then(|value| value.as_str().map(str::to_string))
+        .filter(|rule| !rule.is_empty())
+}
+
+/// Set or clear the task's recurrence
+pub fn set_repeat_rule(task: &mut Task, rule: Option<&str>) {
+    set_task_field(
+        task,
+        "repeatFlag",
+        serde_json::Value::String(rule.unwrap_or_default().to_string()),
+    );
+}
+
+/// Friendly text of the task's recurrence, if it repeats
+pub fn repeat_text(task: &Task) -> Option<String> {
+    repeat_rule(task).map(|rule| describe_rrule(&rule))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rrule(text: &str) -> String {
+        parse_recurrence(text).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_parse_recurrence() {
+        assert_eq!(rrule("daily"), "RRULE:FREQ=DAILY;INTERVAL=1");
+        assert_eq!(rrule("every day"), "RRULE:FREQ=DAILY;INTERVAL=1");
+        assert_eq!(rrule("Every 3 days"), "RRULE:FREQ=DAILY;INTERVAL=3");
+        assert_eq!(rrule("every other week"), "RRULE:FREQ=WEEKLY;INTERVAL=2");
+        assert_eq!(
+            rrule("every weekday"),
+            "RRULE:FREQ=WEEKLY;INTERVAL=1;BYDAY=MO,TU,WE,TH,FR"
+        );
+        assert_eq!(
+            rrule("every weekend"),
+            "RRULE:FREQ=WEEKLY;INTERVAL=1;BYDAY=SA,SU"
+        );
+        assert_eq!(
+            rrule("every 2 weeks on mon,thu"),
+            "RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH"
+        );
+        assert_eq!(
+            rrule("every thursday and monday"),
+            "RRULE:FREQ=WEEKLY;INTERVAL=1;BYDAY=MO,TH"
+        );
+        assert_eq!(
+            rrule("every tue, tues"),
+            "RRULE:FREQ=WEEKLY;INTERVAL=1;BYDAY=TU"
+        );
+        assert_eq!(rrule("mondays"), "RRULE:FREQ=WEEKLY;INTERVAL=1;BYDAY=MO");
+        assert_eq!(rrule("monthly"), "RRULE:FREQ=MONTHLY;INTERVAL=1");
+        assert_eq!(
+            rrule("every month on the 15th"),
+            "RRULE:FREQ=MONTHLY;INTERVAL=1;BYMONTHDAY=15"
+        );
+        assert_eq!(
+            rrule("every 3 months on 1"),
+            "RRULE:FREQ=MONTHLY;INTERVAL=3;BYMONTHDAY=1"
+        );
+        assert_eq!(rrule("annually"), "RRULE:FREQ=YEARLY;INTERVAL=1");
+        assert_eq!(rrule("every 2 years"), "RRULE:FREQ=YEARLY;INTERVAL=2");
+    }
+
+    #[test]
+    fn test_parse_recurrence_blank_and_raw() {
+        assert_eq!(parse_recurrence("").unwrap(), None);
+        assert_eq!(parse_recurrence("  never ").unwrap(), None);
+        assert_eq!(parse_recurrence("None").unwrap(), None);
+
+        // Raw rules are kept, even with parts the friendly text can't express
+        assert_eq!(rrule("FREQ=DAILY;COUNT=5"), "RRULE:FREQ=DAILY;COUNT=5");
+        assert_eq!(
+            rrule("rrule:freq=monthly;bysetpos=-1;byday=fr"),
+            "RRULE:FREQ=MONTHLY;BYSETPOS=-1;BYDAY=FR"
+        );
+        assert!(parse_recurrence("FREQ=HOURLY").is_err());
+    }
+
+    #[test]
+    fn test_parse_recurrence_errors() {
+        assert!(parse_recurrence("sometimes").is_err());
+        assert!(parse_recurrence("every").is_err());
+        assert!(parse_recurrence("every 0 days").is_err());
+        assert!(parse_recurrence("every day on mon").is_err());
+        assert!(parse_recurrence("every week on the 3rd").is_err());
+        assert!(parse_recurrence("every month on the 32nd").is_err());
+        let error = parse_recurrence("every fortnight").unwrap_err();
+        assert!(error.contains("\"fortnight\""));
+    }
+
+    #[test]
+    fn test_describe_rrule() {
+        assert_eq!(describe_rrule("RRULE:FREQ=DAILY;INTERVAL=1"), "every day");
+        assert_eq!(describe_rrule("FREQ=DAILY"), "every day");
+        assert_eq!(
+            describe_rrule("RRULE:FREQ=DAILY;INTERVAL=3"),
+            "every 3 days"
+        );
+        assert_eq!(
+            describe_rrule("RRULE:FREQ=WEEKLY;INTERVAL=1;BYDAY=FR,MO,TU,WE,TH"),
+            "every weekday"
+        );
+        assert_eq!(
+            describe_rrule("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH"),
+            "every 2 weeks on mon,thu"
+        );
+        assert_eq!(
+            describe_rrule("RRULE:FREQ=MONTHLY;INTERVAL=1;BYMONTHDAY=22"),
+            "every month on the 22nd"
+        );
+        assert_eq!(
+            describe_rrule("RRULE:FREQ=MONTHLY;INTERVAL=1;BYMONTHDAY=11"),
+            "every month on the 11th"
+        );
+        assert_eq!(describe_rrule("RRULE:FREQ=YEARLY;INTERVAL=1"), "every year");
+
+        // Rules without a friendly form are shown as they are
+        assert_eq!(
+            describe_rrule("RRULE:FREQ=DAILY;COUNT=5"),
+            "FREQ=DAILY;COUNT=5"
+        );
+        assert_eq!(
+            describe_rrule("RRULE:FREQ=MONTHLY;BYDAY=1MO"),
+            "FREQ=MONTHLY;BYDAY=1MO"
+        );
+    }
+
+    #[test]
+    fn test_friendly_text_round_trips() {
+        for text in [
+            "every day",
+            "every 4 days",
+            "every weekday",
+            "every weekend",
+            "every week on tue",
+            "every 2 weeks on mon,thu",
+            "every month on the 1st",
+            "every 6 months",
+            "every year",
+        ] {
+            assert_eq!(describe_rrule(&rrule(text)), text);
+        }
+    }
+
+    #[test]
+    fn test_repeat_rule_on_task() {
+        use crate::backend::memory::task_fixture;
+
+        let mut task = task_fixture("t1", "inbox", "Standup");
+        assert_eq!(repeat_rule(&task), None);
+
+        set_repeat_rule(
+            &mut task,
+            Some("RRULE:FREQ=WEEKLY;INTERVAL=1;BYDAY=MO,TU,WE,TH,FR"),
+        );
+        assert_eq!(repeat_text(&task).as_deref(), Some("every weekday"));
+
+        set_repeat_rule(&mut task, None);
+        assert_eq!(repeat_rule(&task), None);
+        assert_eq!(task.title, "Standup");
+    }
+}
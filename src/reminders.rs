@@ -0,0 +1,415 @@
+use chrono::Timelike;
+use ticks::tasks::Task;
+
+use crate::{
+    backend::{set_task_field, task_field},
+    utils::parse_time_us_format,
+};
+
+const MINUTES_PER_HOUR: i64 = 60;
+const MINUTES_PER_DAY: i64 = 24 * MINUTES_PER_HOUR;
+const MINUTES_PER_WEEK: i64 = 7 * MINUTES_PER_DAY;
+
+const INVALID_REMINDER: &str = "Try \"15 minutes before\" or \"1 day before at 9am\"";
+
+/// Read a `TRIGGER:` duration (RFC 5545) as signed minutes from the due time
+///
+/// Accepts both compact forms (`-PT15M`) and TickTick's padded ones (`-P0DT9H0M0S`).
+fn parse_trigger(trigger: &str) -> Option<i64> {
+    let trigger = trigger.trim();
+    let body = trigger
+        .get(..8)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("TRIGGER:"))
+        .map_or(trigger, |_| &trigger[8..])
+        .to_uppercase();
+    let (sign, body) = match body.strip_prefix('-') {
+        Some(rest) => (-1, rest.to_string()),
+        None => (1, body.trim_start_matches('+').to_string()),
+    };
+    let body = body.strip_prefix('P')?;
+
+    let mut minutes: i64 = 0;
+    let mut seconds: i64 = 0;
+    let mut number = String::new();
+    let mut in_time = false;
+    let mut has_part = false;
+    for c in body.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' if !in_time && number.is_empty() => in_time = true,
+            _ => {
+                let value: i64 = number.parse().ok()?;
+                number.clear();
+                has_part = true;
+                let part = match (c, in_time) {
+                    ('W', false) => value.checked_mul(MINUTES_PER_WEEK)?,
+                    ('D', false) => value.checked_mul(MINUTES_PER_DAY)?,
+                    ('H', true) => value.checked_mul(MINUTES_PER_HOUR)?,
+                    ('M', true) => value,
+                    ('S', true) => {
+                        seconds = seconds.checked_add(value)?;
+                        continue;
+                    }
+                    _ => return None,
+                };
+                minutes = part.checked_add(minutes)?;
+            }
+        }
+    }
+    if !number.is_empty() || !has_part {
+        return None;
+    }
+    Some(sign * minutes.checked_add(seconds / 60)?)
+}
+
+/// Write signed minutes from the due time as a `TRIGGER:` duration
+fn format_trigger(minutes: i64) -> String {
+    if minutes == 0 {
+        return "TRIGGER:PT0S".to_string();
+    }
+    let sign = if minutes < 0 { "-" } else { "" };
+    let total = minutes.abs();
+    let days = total / MINUTES_PER_DAY;
+    let hours = total % MINUTES_PER_DAY / MINUTES_PER_HOUR;
+    let mins = total % MINUTES_PER_HOUR;
+
+    let mut trigger = format!("TRIGGER:{}P", sign);
+    if days > 0 {
+        trigger.push_str(&format!("{}D", days));
+    }
+    if hours > 0 || mins > 0 {
+        trigger.push('T');
+        if hours > 0 {
+            trigger.push_str(&format!("{}H", hours));
+        }
+        if mins > 0 {
+            trigger.push_str(&format!("{}M", mins));
+        }
+    }
+    trigger
+}
+
+/// Minutes in one of a unit (`15m`, `2 hours`, `1 day`, `1 week`)
+fn unit_minutes(unit: &str) -> Option<i64> {
+    match unit {
+        "m" | "min" | "mins" | "minute" | "minutes" => Some(1),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Some(MINUTES_PER_HOUR),
+        "d" | "day" | "days" => Some(MINUTES_PER_DAY),
+        "w" | "wk" | "week" | "weeks" => Some(MINUTES_PER_WEEK),
+        _ => None,
+    }
+}
+
+/// Read an offset like `15 minutes before` or `2d after` as signed minutes
+fn parse_offset(text: &str) -> Option<i64> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let (amount, direction) = match words.as_slice() {
+        [amount @ .., direction] => (amount, *direction),
+        [] => return None,
+    };
+    let sign = match direction {
+        "before" | "early" => -1,
+        "after" | "later" => 1,
+        _ => return None,
+    };
+
+    // "15 minutes", "15m" or "an hour"
+    let (count, unit) = match amount {
+        [count, unit] => (*count, *unit),
+        [compact] => {
+            let split = compact.find(|c: char| !c.is_ascii_digit())?;
+            compact.split_at(split)
+        }
+        _ => return None,
+    };
+    let count: i64 = match count {
+        "a" | "an" | "one" => 1,
+        count => count.parse().ok()?,
+    };
+    Some(sign * count.checked_mul(unit_minutes(unit)?)?)
+}
+
+/// Parse one reminder as signed minutes from the due time
+fn parse_reminder(text: &str) -> Result<i64, String> {
+    let text = text.trim().to_lowercase();
+    let invalid = || format!("Invalid reminder \"{}\". {}", text, INVALID_REMINDER);
+
+    if text.starts_with("trigger:") {
+        return parse_trigger(&text).ok_or_else(invalid);
+    }
+    if matches!(
+        text.as_str(),
+        "at due time" | "due time" | "on time" | "at time" | "when due"
+    ) {
+        return Ok(0);
+    }
+
+    // "1 day before at 9am" and "on the day at 9am" count from the start of the due day
+    let (offset, time) = match text.strip_prefix("at ") {
+        Some(time) => ("", Some(time)),
+        None => match text.rsplit_once(" at ") {
+            Some((offset, time)) => (offset, Some(time)),
+            None => (text.as_str(), None),
+        },
+    };
+    let Some(time) = time else {
+        return parse_offset(offset).ok_or_else(invalid);
+    };
+
+    let time = parse_time_us_format(time).map_err(|_| invalid())?;
+    let time_minutes = (time.hour() * 60 + time.minute()) as i64;
+    let day_offset = match offset.trim() {
+        "" | "on the day" | "the day" | "same day" | "that day" | "on the day of" => 0,
+        offset => parse_offset(offset)
+            .filter(|minutes| minutes % MINUTES_PER_DAY == 0)
+            .ok_or_else(invalid)?,
+    };
+    day_offset.checked_add(time_minutes).ok_or_else(invalid)
+}
+
+/// Parse a comma separated list of reminders into `TRIGGER:` strings
+///
+/// Blank or `none` means no reminders. Accepts `at due time`, offsets like
+/// `15 minutes before` and day offsets with a time like `1 day before at 9am` (the
+/// latter for all-day tasks, which TickTick reminds relative to the start of the day).
+pub fn parse_reminders(text: &str) -> Result<Vec<String>, String> {
+    let text = text.trim();
+    if text.is_empty() || text.eq_ignore_ascii_case("none") {
+        return Ok(Vec::new());
+    }
+    let mut triggers: Vec<String> = Vec::new();
+    for reminder in text.split([',', ';']).filter(|r| !r.trim().is_empty()) {
+        let trigger = format_trigger(parse_reminder(reminder)?);
+        if !triggers.contains(&trigger) {
+            triggers.push(trigger);
+        }
+    }
+    Ok(triggers)
+}
+
+/// Check a reminders field against the due date field they count from
+pub fn validate_reminders(text: &str, date: &str) -> Result<(), String> {
+    let triggers = parse_reminders(text)?;
+    if !triggers.is_empty() && date.trim().is_empty() {
+        return Err("Reminders need a due date".to_string());
+    }
+    Ok(())
+}
+
+fn plural(count: i64, unit: &str) -> String {
+    if count == 1 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", count, unit)
+    }
+}
+
+/// `9am`, `9:30am`, `12pm`
+fn format_time_of_day(minutes: i64) -> String {
+    let hour = minutes / MINUTES_PER_HOUR;
+    let minute = minutes % MINUTES_PER_HOUR;
+    let suffix = if hour < 12 { "am" } else { "pm" };
+    let hour_12 = match hour % 12 {
+        0 => 12,
+        hour => hour,
+    };
+    if minute == 0 {
+        format!("{}{}", hour_12, suffix)
+    } else {
+        format!("{}:{:02}{}", hour_12, minute, suffix)
+    }
+}
+
+/// Friendly text for one trigger, or the trigger itself if it can't be read
+///
+/// All-day tasks are reminded relative to the start of the due day, so their
+/// triggers read as a day and a time.
+pub fn describe_trigger(trigger: &str, is_all_day: bool) -> String {
+    let Some(minutes) = parse_trigger(trigger) else {
+        return trigger.trim().to_string();
+    };
+    if minutes == 0 {
+        return "at due time".to_string();
+    }
+
+    if is_all_day {
+        let days = minutes.div_euclid(MINUTES_PER_DAY);
+        let time = format_time_of_day(minutes.rem_euclid(MINUTES_PER_DAY));
+        return match days {
+            0 => format!("on the day at {}", time),
+            days if days < 0 => format!("{} before at {}", plural(-days, "day"), time),
+            days => format!("{} after at {}", plural(days, "day"), time),
+        };
+    }
+
+    let direction = if minutes < 0 { "before" } else { "after" };
+    let total = minutes.abs();
+    let amount = if total % MINUTES_PER_WEEK == 0 {
+        plural(total / MINUTES_PER_WEEK, "week")
+    } else if total % MINUTES_PER_DAY == 0 {
+        plural(total / MINUTES_PER_DAY, "day")
+    } else if total % MINUTES_PER_HOUR == 0 {
+        plural(total / MINUTES_PER_HOUR, "hour")
+    } else {
+        plural(total, "minute")
+    };
+    format!("{} {}", amount, direction)
+}
+
+/// Friendly text for a list of triggers, as accepted by [`parse_reminders`]
+pub fn describe_reminders(triggers: &[String], is_all_day: bool) -> String {
+    triggers
+        .iter()
+        .map(|trigger| describe_trigger(trigger, is_all_day))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The task's reminders as `TRIGGER:` strings
+pub fn reminders(task: &Task) -> Vec<String> {
+    task_field(task, "reminders")
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+/// Replace the task's reminders
+pub fn set_reminders(task: &mut Task, triggers: &[String]) {
+    set_task_field(task, "reminders", serde_json::json!(triggers));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triggers(text: &str) -> Vec<String> {
+        parse_reminders(text).unwrap()
+    }
+
+    #[test]
+    fn test_parse_reminders() {
+        assert_eq!(triggers("at due time"), vec!["TRIGGER:PT0S"]);
+        assert_eq!(triggers("15 minutes before"), vec!["TRIGGER:-PT15M"]);
+        assert_eq!(triggers("15m before"), vec!["TRIGGER:-PT15M"]);
+        assert_eq!(triggers("an hour before"), vec!["TRIGGER:-PT1H"]);
+        assert_eq!(triggers("90 min before"), vec!["TRIGGER:-PT1H30M"]);
+        assert_eq!(triggers("2 days before"), vec!["TRIGGER:-P2D"]);
+        assert_eq!(triggers("1 week before"), vec!["TRIGGER:-P7D"]);
+        assert_eq!(triggers("10 minutes after"), vec!["TRIGGER:PT10M"]);
+        assert_eq!(
+            triggers("At due time, 1 Hour Before"),
+            vec!["TRIGGER:PT0S", "TRIGGER:-PT1H"]
+        );
+        // Duplicates are dropped
+        assert_eq!(
+            triggers("60 minutes before; 1h before"),
+            vec!["TRIGGER:-PT1H"]
+        );
+    }
+
+    #[test]
+    fn test_parse_day_reminders() {
+        assert_eq!(triggers("on the day at 9am"), vec!["TRIGGER:PT9H"]);
+        assert_eq!(triggers("at 9:30am"), vec!["TRIGGER:PT9H30M"]);
+        assert_eq!(triggers("1 day before at 9am"), vec!["TRIGGER:-PT15H"]);
+        assert_eq!(triggers("2 days before at 6pm"), vec!["TRIGGER:-P1DT6H"]);
+        assert_eq!(triggers("1 day before at 12am"), vec!["TRIGGER:-P1D"]);
+    }
+
+    #[test]
+    fn test_parse_reminders_blank_and_raw() {
+        assert!(triggers("").is_empty());
+        assert!(triggers(" none ").is_empty());
+        assert_eq!(triggers("TRIGGER:-P0DT9H0M0S"), vec!["TRIGGER:-PT9H"]);
+        assert_eq!(triggers("trigger:P0DT9H0M0S"), vec!["TRIGGER:PT9H"]);
+    }
+
+    #[test]
+    fn test_parse_reminders_errors() {
+        assert!(parse_reminders("soon").is_err());
+        assert!(parse_reminders("15 minutes").is_err());
+        assert!(parse_reminders("15 parsecs before").is_err());
+        assert!(parse_reminders("2 hours before at 9am").is_err());
+        assert!(parse_reminders("1 day before at noonish").is_err());
+        assert!(parse_reminders("TRIGGER:-PT15X").is_err());
+        assert!(parse_reminders("9223372036854775807 weeks before").is_err());
+        assert!(parse_reminders("6405119470038038 days after at 11:59pm").is_err());
+        assert!(parse_reminders("TRIGGER:-P9223372036854775807W").is_err());
+        assert!(parse_reminders("TRIGGER:P6405119470038038DT9223372036854775807M").is_err());
+        let error = parse_reminders("at due time, whenever").unwrap_err();
+        assert!(error.contains("\"whenever\""));
+    }
+
+    #[test]
+    fn test_validate_reminders() {
+        assert!(validate_reminders("15 minutes before", "tomorrow").is_ok());
+        assert!(validate_reminders("", "").is_ok());
+        assert_eq!(
+            validate_reminders("15 minutes before", " "),
+            Err("Reminders need a due date".to_string())
+        );
+        assert!(validate_reminders("whenever", "tomorrow").is_err());
+    }
+
+    #[test]
+    fn test_describe_trigger() {
+        assert_eq!(describe_trigger("TRIGGER:PT0S", false), "at due time");
+        assert_eq!(
+            describe_trigger("TRIGGER:-PT15M", false),
+            "15 minutes before"
+        );
+        assert_eq!(describe_trigger("TRIGGER:-PT1H", false), "1 hour before");
+        assert_eq!(
+            describe_trigger("TRIGGER:-P0DT2H0M0S", false),
+            "2 hours before"
+        );
+        assert_eq!(describe_trigger("TRIGGER:-P1D", false), "1 day before");
+        assert_eq!(describe_trigger("TRIGGER:-P1W", false), "1 week before");
+        assert_eq!(describe_trigger("TRIGGER:PT5M", false), "5 minutes after");
+
+        assert_eq!(
+            describe_trigger("TRIGGER:P0DT9H0M0S", true),
+            "on the day at 9am"
+        );
+        assert_eq!(
+            describe_trigger("TRIGGER:-P0DT15H0M0S", true),
+            "1 day before at 9am"
+        );
+        assert_eq!(
+            describe_trigger("TRIGGER:-P1DT5H30M0S", true),
+            "2 days before at 6:30pm"
+        );
+        assert_eq!(
+            describe_trigger("TRIGGER:nonsense", true),
+            "TRIGGER:nonsense"
+        );
+    }
+
+    #[test]
+    fn test_friendly_text_round_trips() {
+        for (text, is_all_day) in [
+            ("at due time", false),
+            ("15 minutes before", false),
+            ("1 hour before, 2 days before", false),
+            ("3 weeks before", false),
+            ("on the day at 9am", true),
+            ("1 day before at 9am, 3 days before at 12:15pm", true),
+        ] {
+            assert_eq!(describe_reminders(&triggers(text), is_all_day), text);
+        }
+    }
+
+    #[test]
+    fn test_reminders_on_task() {
+        use crate::backend::memory::task_fixture;
+
+        let mut task = task_fixture("t1", "inbox", "Call vendor");
+        assert!(reminders(&task).is_empty());
+
+        set_reminders(&mut task, &triggers("15 minutes before, at due time"));
+        assert_eq!(reminders(&task), vec!["TRIGGER:-PT15M", "TRIGGER:PT0S"]);
+
+        set_reminders(&mut task, &[]);
+        assert!(reminders(&task).is_empty());
+        assert_eq!(task.title, "Call vendor");
+    }
+}
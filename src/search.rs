@@ -0,0 +1,84 @@
+use std::ops::Range;
+use ticks::tasks::Task;
+
+/// Which tasks the `/` prompt searches
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SearchScope {
+    /// The tasks of the current tab
+    #[default]
+    View,
+    /// Every task in every project
+    All,
+}
+
+/// Text typed at the search prompt and where it looks
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Search {
+    pub query: String,
+    pub scope: SearchScope,
+}
+
+/// Whether every word of `query` appears in the task's title or content, ignoring case
+pub fn matches(task: &Task, query: &str) -> bool {
+    let title = task.title.to_lowercase();
+    let content = task.content.to_lowercase();
+    query
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .all(|word| title.contains(&word) || content.contains(&word))
+}
+
+/// Byte ranges of `text` matching any word of `query`, ignoring case, for highlighting
+pub fn match_ranges(text: &str, query: &str) -> Vec<Range<usize>> {
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (i, _) in text.char_indices() {
+        if ranges.last().is_some_and(|range| i < range.end) {
+            continue;
+        }
+        if let Some(len) = words.iter().filter_map(|w| match_len(&text[i..], w)).max() {
+            ranges.push(i..i + len);
+        }
+    }
+    ranges
+}
+
+/// Bytes of `text` taken by `word` if `text` starts with it, ignoring case
+fn match_len(text: &str, word: &str) -> Option<usize> {
+    let mut wanted = word.chars().peekable();
+    for (i, c) in text.char_indices() {
+        for lower in c.to_lowercase() {
+            if wanted.next() != Some(lower) {
+                return None;
+            }
+        }
+        if wanted.peek().is_none() {
+            return Some(i + c.len_utf8());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::task_fixture;
+
+    #[test]
+    fn test_matches_title_and_content() {
+        let mut task = task_fixture("t1", "inbox", "Send quarterly report");
+        task.content = "Ask Dana for the numbers".to_string();
+        assert!(matches(&task, "REPORT"));
+        assert!(matches(&task, "report dana"));
+        assert!(matches(&task, ""));
+        assert!(!matches(&task, "report budget"));
+    }
+
+    #[test]
+    fn test_match_ranges() {
+        assert_eq!(match_ranges("Water plants", "PLANT"), vec![6..11]);
+        assert_eq!(match_ranges("Read a book", "a"), vec![2..3, 5..6]);
+        assert_eq!(match_ranges("Café au lait", "é au"), vec![3..5, 6..8]);
+        assert!(match_ranges("Dentist", "").is_empty());
+    }
+}
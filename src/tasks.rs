@@ -1,18 +1,24 @@
-use chrono::TimeZone;
-use chrono::{NaiveDate, NaiveTime};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono::{LocalResult, TimeZone};
 use ticks::{
     projects::ProjectID,
     tasks::{Task, TaskID, TaskPriority},
-    TickTick,
 };
 
-/// Fetch all tasks (today, week, and inbox) at once
+use crate::backend::{copy_task_fields, NewTask, TaskBackend};
+use crate::checklist::{set_checklist_items, ChecklistItem};
+use crate::recurrence::set_repeat_rule;
+use crate::reminders::set_reminders;
+use crate::utils::{parse_date_and_time, parse_duration, PostponeTarget};
+
+/// Fetch all tasks (today, week, and inbox) at once, with the week spanning `week_days`
 pub async fn fetch_all_tasks(
-    client: &TickTick,
+    client: &dyn TaskBackend,
+    week_days: i64,
 ) -> Result<(Vec<Task>, Vec<Task>, Vec<Task>), String> {
     // Fetch all views concurrently
     let today_future = fetch_today_tasks(client);
-    let week_future = fetch_week_tasks(client);
+    let week_future = fetch_week_tasks(client, week_days);
     let inbox_future = fetch_inbox_tasks(client);
 
     let (today_result, week_result, inbox_result) =
@@ -31,87 +37,69 @@ pub async fn fetch_all_tasks(
 }
 
 /// Fetch all tasks from a specific project
-#[allow(dead_code)]
 pub async fn fetch_project_tasks(
-    client: &TickTick,
+    client: &dyn TaskBackend,
     project_id: &ProjectID,
 ) -> Result<Vec<Task>, String> {
-    match client.get_project(project_id).await {
-        Ok(project) => match project.get_tasks().await {
-            Ok(tasks) => Ok(tasks),
-            Err(e) => Err(format!("Failed to fetch tasks from project: {:?}", e)),
-        },
-        Err(e) => Err(format!("Failed to get project: {:?}", e)),
-    }
+    client.get_project_tasks(project_id).await
 }
 
-/// Fetch all tasks from the inbox
-pub async fn fetch_inbox_tasks(client: &TickTick) -> Result<Vec<Task>, String> {
-    // First get the project data with "inbox" to retrieve the actual inbox project ID
-    let inbox_id = ProjectID("inbox".to_string());
-    match client.get_project_data(&inbox_id).await {
-        Ok(project_data) => Ok(project_data.tasks),
-        Err(e) => Err(format!("Failed to fetch inbox tasks: {:?}", e)),
-    }
+/// Fetch the tasks in every project and the inbox that carry a tag
+pub async fn fetch_tagged_tasks(client: &dyn TaskBackend, tag: &str) -> Result<Vec<Task>, String> {
+    let all_tasks = client.get_all_tasks().await?;
+    let inbox_tasks = client.get_inbox_tasks().await?;
+    let mut tasks: Vec<Task> = all_tasks
+        .into_iter()
+        .chain(inbox_tasks)
+        .filter(|task| has_tag(task, tag))
+        .collect();
+    sort_tasks(&mut tasks);
+    Ok(tasks)
 }
 
-/// Fetch all tasks due today across all projects (including inbox)
-pub async fn fetch_today_tasks(client: &TickTick) -> Result<Vec<Task>, String> {
-    use chrono::Local;
+/// Fetch the tasks in every project and the inbox
+pub async fn fetch_every_task(client: &dyn TaskBackend) -> Result<Vec<Task>, String> {
+    let all_tasks = client.get_all_tasks().await?;
+    let inbox_tasks = client.get_inbox_tasks().await?;
+    let mut tasks: Vec<Task> = all_tasks.into_iter().chain(inbox_tasks).collect();
+    sort_tasks(&mut tasks);
+    Ok(tasks)
+}
 
-    // Get today's end time (end of day in local time)
-    let now = Local::now();
-    let today_end = now
+/// Whether a task carries a tag, ignoring case
+pub fn has_tag(task: &Task, tag: &str) -> bool {
+    task.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+}
+
+/// Fetch all tasks from the inbox
+pub async fn fetch_inbox_tasks(client: &dyn TaskBackend) -> Result<Vec<Task>, String> {
+    client.get_inbox_tasks().await
+}
+
+/// End of the current local day in UTC
+fn today_end() -> chrono::DateTime<chrono::Utc> {
+    chrono::Local::now()
         .date_naive()
         .and_hms_opt(23, 59, 59)
         .unwrap()
-        .and_local_timezone(Local)
+        .and_local_timezone(chrono::Local)
         .unwrap()
-        .with_timezone(&chrono::Utc);
-
-    // Fetch all tasks from all projects
-    let all_tasks = match client.get_all_tasks_in_projects().await {
-        Ok(tasks) => tasks,
-        Err(e) => return Err(format!("Failed to fetch tasks: {:?}", e)),
-    };
-
-    // Fetch inbox tasks
-    let inbox_id = ProjectID("inbox".to_string());
-    let inbox_tasks = match client.get_project_data(&inbox_id).await {
-        Ok(project_data) => project_data.tasks,
-        Err(e) => return Err(format!("Failed to fetch inbox tasks: {:?}", e)),
-    };
-
-    let mut today_tasks = Vec::new();
-
-    // Filter tasks from all projects that are due today or overdue
-    for task in all_tasks {
-        let task_due = task.due_date;
-        // Check if due_date is set (not epoch) and is today or earlier (overdue)
-        if task_due.timestamp() > 0 && task_due <= today_end {
-            today_tasks.push(task);
-        }
-    }
-
-    // Filter inbox tasks that are due today or overdue
-    for task in inbox_tasks {
-        let task_due = task.due_date;
-        // Check if due_date is set (not epoch) and is today or earlier (overdue)
-        if task_due.timestamp() > 0 && task_due <= today_end {
-            today_tasks.push(task);
-        }
-    }
+        .with_timezone(&chrono::Utc)
+}
 
-    Ok(today_tasks)
+/// Whether a task belongs in the Today view (due today or overdue)
+pub fn is_today_task(task: &Task) -> bool {
+    let task_due = task.due_date;
+    // Check if due_date is set (not epoch) and is today or earlier (overdue)
+    task_due.timestamp() > 0 && task_due <= today_end()
 }
 
-/// Fetch all tasks due in the next 7 days across all projects (including inbox)
-pub async fn fetch_week_tasks(client: &TickTick) -> Result<Vec<Task>, String> {
+/// Whether a task belongs in the Week view (due after today, within the next `week_days` days)
+pub fn is_week_task(task: &Task, week_days: i64) -> bool {
     use chrono::Local;
 
-    // Get today's start time (beginning of day in local time)
-    let now = Local::now();
-    let today_end = now
+    // Get `week_days` days from now (end of day)
+    let week_end = (Local::now() + chrono::Duration::days(week_days))
         .date_naive()
         .and_hms_opt(23, 59, 59)
         .unwrap()
@@ -119,183 +107,365 @@ pub async fn fetch_week_tasks(client: &TickTick) -> Result<Vec<Task>, String> {
         .unwrap()
         .with_timezone(&chrono::Utc);
 
-    // Get 7 days from now (end of day)
-    let week_end = (now + chrono::Duration::days(7))
-        .date_naive()
-        .and_hms_opt(23, 59, 59)
-        .unwrap()
-        .and_local_timezone(Local)
-        .unwrap()
-        .with_timezone(&chrono::Utc);
+    let task_due = task.due_date;
+    // Check if due_date is set (not epoch) and is within the window
+    task_due.timestamp() > 0 && task_due >= today_end() && task_due <= week_end
+}
 
+/// Fetch all tasks due today across all projects (including inbox)
+pub async fn fetch_today_tasks(client: &dyn TaskBackend) -> Result<Vec<Task>, String> {
     // Fetch all tasks from all projects
-    let all_tasks = match client.get_all_tasks_in_projects().await {
-        Ok(tasks) => tasks,
-        Err(e) => return Err(format!("Failed to fetch tasks: {:?}", e)),
-    };
+    let all_tasks = client.get_all_tasks().await?;
 
     // Fetch inbox tasks
-    let inbox_id = ProjectID("inbox".to_string());
-    let inbox_tasks = match client.get_project_data(&inbox_id).await {
-        Ok(project_data) => project_data.tasks,
-        Err(e) => return Err(format!("Failed to fetch inbox tasks: {:?}", e)),
-    };
-
-    let mut week_tasks = Vec::new();
-
-    // Filter tasks from all projects that are due within the next 7 days
-    for task in all_tasks {
-        let task_due = task.due_date;
-        // Check if due_date is set (not epoch) and is within the next 7 days
-        if task_due.timestamp() > 0 && task_due >= today_end && task_due <= week_end {
-            week_tasks.push(task);
-        }
-    }
+    let inbox_tasks = client.get_inbox_tasks().await?;
+
+    // Filter tasks from all projects and the inbox that are due today or overdue
+    Ok(all_tasks
+        .into_iter()
+        .chain(inbox_tasks)
+        .filter(is_today_task)
+        .collect())
+}
 
-    // Filter inbox tasks that are due within the next 7 days
-    for task in inbox_tasks {
-        let task_due = task.due_date;
-        // Check if due_date is set (not epoch) and is within the next 7 days
-        if task_due.timestamp() > 0 && task_due >= today_end && task_due <= week_end {
-            week_tasks.push(task);
-        }
-    }
+/// Fetch all tasks due in the next `week_days` days across all projects (including inbox)
+pub async fn fetch_week_tasks(
+    client: &dyn TaskBackend,
+    week_days: i64,
+) -> Result<Vec<Task>, String> {
+    // Fetch all tasks from all projects
+    let all_tasks = client.get_all_tasks().await?;
 
-    Ok(week_tasks)
+    // Fetch inbox tasks
+    let inbox_tasks = client.get_inbox_tasks().await?;
+
+    // Filter tasks from all projects and the inbox that are due within the window
+    Ok(all_tasks
+        .into_iter()
+        .chain(inbox_tasks)
+        .filter(|task| is_week_task(task, week_days))
+        .collect())
 }
 
-pub async fn create_task(
-    client: &TickTick,
+/// Build the fields for a new task from user input
+pub fn new_task(
     title: String,
     project: Option<ProjectID>,
     content: Option<String>,
     _description: Option<String>,
-    _priority: Option<TaskPriority>,
+    priority: Option<TaskPriority>,
     date: Option<NaiveDate>,
     time: Option<NaiveTime>,
-) -> Result<(), String> {
-    let mut builder = ticks::tasks::Task::builder(client, &title);
+) -> Result<NewTask, String> {
     let project_id = project.unwrap_or(ProjectID("inbox".to_string()));
-    builder = builder.project_id(project_id);
-
-    if let Some(c) = content {
-        builder = builder.content(&c);
-    }
+    let mut new_task = NewTask {
+        title,
+        project_id,
+        content,
+        due_date: None,
+        is_all_day: false,
+        priority,
+        tags: Vec::new(),
+        items: Vec::new(),
+        repeat: None,
+        reminders: Vec::new(),
+        start_date: None,
+    };
 
     if let Some(d) = date {
-        let datetime = if let Some(t) = time {
-            d.and_time(t)
-        } else {
-            builder = builder.is_all_day(true);
-            d.and_hms_opt(0, 0, 0).unwrap()
-        };
-        let utc_datetime = chrono::Local
-            .from_local_datetime(&datetime)
-            .unwrap()
-            .to_utc();
-        builder = builder.due_date(utc_datetime);
+        new_task.is_all_day = time.is_none();
+        new_task.due_date = Some(due_at(d, time)?);
     }
-    match builder.build_and_publish().await {
-        Ok(_) => Ok(()),
-        Err(e) => Err(format!("Failed to create task: {:?}", e)),
+    Ok(new_task)
+}
+
+/// The UTC time of a local date and time
+///
+/// A time repeated when clocks go back is the earlier one, and a time skipped when
+/// they go forward is an error.
+fn local_to_utc(datetime: NaiveDateTime) -> Result<DateTime<Utc>, String> {
+    resolve_local(Local.from_local_datetime(&datetime), datetime)
+}
+
+/// Pick the UTC time for `datetime` from its possible local times
+fn resolve_local(
+    result: LocalResult<DateTime<Local>>,
+    datetime: NaiveDateTime,
+) -> Result<DateTime<Utc>, String> {
+    result
+        .earliest()
+        .map(|local| local.to_utc())
+        .ok_or_else(|| {
+            format!(
+                "{} doesn't exist in the local time zone",
+                datetime.format("%Y-%m-%d %H:%M")
+            )
+        })
+}
+
+/// The due date of a task due on `date`, at `time` or all day
+fn due_at(date: NaiveDate, time: Option<NaiveTime>) -> Result<DateTime<Utc>, String> {
+    match time {
+        Some(time) => local_to_utc(date.and_time(time)),
+        // Where clocks go forward at midnight the day starts an hour later
+        None => local_to_utc(date.and_hms_opt(0, 0, 0).unwrap())
+            .or_else(|_| local_to_utc(date.and_hms_opt(1, 0, 0).unwrap())),
     }
 }
 
-pub async fn edit_task(
-    task: &mut Task,
-    title: Option<String>,
+pub async fn create_task(
+    client: &dyn TaskBackend,
+    title: String,
     project: Option<ProjectID>,
     content: Option<String>,
-    _description: Option<String>,
-    _priority: Option<TaskPriority>,
+    description: Option<String>,
+    priority: Option<TaskPriority>,
     date: Option<NaiveDate>,
     time: Option<NaiveTime>,
+) -> Result<Task, String> {
+    let new_task = new_task(title, project, content, description, priority, date, time)?;
+    client.create_task(new_task).await
+}
+
+/// Apply `changes` to a task and send it
+pub async fn edit_task(
+    client: &dyn TaskBackend,
+    task: &mut Task,
+    changes: &TaskChanges,
 ) -> Result<(), String> {
-    if let Some(t) = title {
-        task.title = t;
+    apply_task_changes(task, changes)?;
+    client.update_task(task).await
+}
+
+/// Field changes made by an edit or postpone
+#[derive(Clone, Default)]
+pub struct TaskChanges {
+    /// Copy every editable field from this task first (used by undo and redo)
+    pub restore: Option<Task>,
+    pub title: Option<String>,
+    pub project: Option<ProjectID>,
+    pub content: Option<String>,
+    pub priority: Option<TaskPriority>,
+    pub tags: Option<Vec<String>>,
+    pub items: Option<Vec<ChecklistItem>>,
+    /// New RRULE, or `Some(None)` to stop repeating
+    pub repeat: Option<Option<String>>,
+    /// Reminders as `TRIGGER:` strings
+    pub reminders: Option<Vec<String>>,
+    pub start: Option<StartChange>,
+    pub date: Option<NaiveDate>,
+    pub time: Option<NaiveTime>,
+}
+
+impl TaskChanges {
+    /// Changes that put back every editable field of `task`
+    pub fn restoring(task: &Task) -> Self {
+        Self {
+            restore: Some(task.clone()),
+            ..Default::default()
+        }
     }
-    if let Some(p) = project {
-        task.project_id = p;
+
+    /// Changes that move `task` to a new due date, keeping an all-day task all-day
+    pub fn postponing(task: &Task, due: DateTime<Utc>) -> Self {
+        let due = due.with_timezone(&Local);
+        Self {
+            date: Some(due.date_naive()),
+            time: (!task.is_all_day).then(|| due.time()),
+            ..Default::default()
+        }
     }
-    if let Some(c) = content {
-        task.content = c;
+}
+
+/// Apply edited fields to a task without sending them anywhere
+///
+/// Leaves the task unchanged when a date or time doesn't exist locally.
+pub fn apply_task_changes(task: &mut Task, changes: &TaskChanges) -> Result<(), String> {
+    let mut edited = task.clone();
+    if let Some(fields) = &changes.restore {
+        copy_task_fields(fields, &mut edited);
     }
-    if let Some(d) = date {
-        let datetime = if let Some(t) = time {
-            // When time is provided, ensure the task is not all-day
-            task.is_all_day = false;
-            d.and_time(t)
-        } else {
-            task.is_all_day = true;
-            d.and_hms_opt(0, 0, 0).unwrap()
-        };
-        let utc_datetime = chrono::Local
-            .from_local_datetime(&datetime)
-            .unwrap()
-            .to_utc();
-        task.due_date = utc_datetime;
-        task.start_date = utc_datetime;
-    } else if time.is_some() {
-        // Handle case where only time is being updated without changing the date
-        if let Some(t) = time {
-            // If we have a valid due_date and we're setting a time, update to non-all-day
-            if task.due_date.timestamp() > 0 {
-                task.is_all_day = false;
-                let current_date = task.due_date.with_timezone(&chrono::Local).date_naive();
-                let datetime = current_date.and_time(t);
-                let utc_datetime = chrono::Local
-                    .from_local_datetime(&datetime)
-                    .unwrap()
-                    .to_utc();
-                task.due_date = utc_datetime;
-                task.start_date = utc_datetime;
+    if let Some(t) = &changes.title {
+        edited.title = t.clone();
+    }
+    if let Some(p) = &changes.priority {
+        edited.priority = p.clone();
+    }
+    if let Some(t) = &changes.tags {
+        edited.tags = t.clone();
+    }
+    if let Some(i) = &changes.items {
+        set_checklist_items(&mut edited, i);
+    }
+    if let Some(r) = &changes.repeat {
+        set_repeat_rule(&mut edited, r.as_deref());
+    }
+    if let Some(r) = &changes.reminders {
+        set_reminders(&mut edited, r);
+    }
+    if let Some(p) = &changes.project {
+        edited.project_id = p.clone();
+    }
+    if let Some(c) = &changes.content {
+        edited.content = c.clone();
+    }
+
+    // A separate start date keeps its distance from the due date when that moves
+    let start_offset = if edited.due_date.timestamp() > 0 && edited.start_date.timestamp() > 0 {
+        edited.due_date - edited.start_date
+    } else {
+        chrono::Duration::zero()
+    };
+    let old_due = edited.due_date;
+    let time = changes.time;
+    if let Some(d) = changes.date {
+        // A date without a time is all-day
+        edited.is_all_day = time.is_none();
+        edited.due_date = due_at(d, time)?;
+    } else if let Some(t) = time {
+        // A time alone moves the current due date, making it no longer all-day
+        if edited.due_date.timestamp() > 0 {
+            edited.is_all_day = false;
+            let current_date = edited.due_date.with_timezone(&chrono::Local).date_naive();
+            edited.due_date = local_to_utc(current_date.and_time(t))?;
+        }
+    }
+    if edited.due_date != old_due {
+        edited.start_date = edited.due_date - start_offset;
+    }
+
+    if let Some(start) = &changes.start {
+        if let Some(start_date) = start.start_date(edited.due_date)? {
+            edited.start_date = start_date;
+        }
+    }
+    *task = edited;
+    Ok(())
+}
+
+/// A change to when a task starts
+#[derive(Debug, Clone, PartialEq)]
+pub enum StartChange {
+    /// Start with the due date again
+    Clear,
+    /// Start on a date, at a time or both (a time alone is on the due day)
+    At(Option<NaiveDate>, Option<NaiveTime>),
+    /// Start a length of time before the due date
+    BeforeDue(chrono::Duration),
+}
+
+impl StartChange {
+    /// The start date this gives a task due at `due` (the epoch when unset)
+    ///
+    /// Returns `None` when the start depends on a due date the task doesn't have.
+    pub fn start_date(&self, due: DateTime<Utc>) -> Result<Option<DateTime<Utc>>, String> {
+        let has_due = due.timestamp() > 0;
+        match self {
+            StartChange::Clear => Ok(Some(due)),
+            StartChange::BeforeDue(length) => Ok(has_due.then(|| due - *length)),
+            StartChange::At(date, time) => {
+                // A time alone starts on the due day
+                let date = date.unwrap_or_else(|| {
+                    if has_due {
+                        due.with_timezone(&Local).date_naive()
+                    } else {
+                        Local::now().date_naive()
+                    }
+                });
+                let datetime = date.and_time(time.unwrap_or_default());
+                local_to_utc(datetime).map(Some)
             }
         }
     }
-    task.publish_changes()
-        .await
-        .map_err(|e| format!("Failed to edit task: {:?}", e))
+}
+
+/// Parse the start field: a date and time, a length before the due date like
+/// `2 hours before due`, or `none`
+pub fn parse_start(text: &str) -> Result<StartChange, String> {
+    let text = text.trim().to_lowercase();
+    if text.is_empty() || text == "none" {
+        return Ok(StartChange::Clear);
+    }
+    let length = ["before due", "before"]
+        .iter()
+        .find_map(|suffix| text.strip_suffix(suffix))
+        .unwrap_or(&text);
+    match parse_duration(length) {
+        Ok(PostponeTarget::RelativeToDueDate(length)) => Ok(StartChange::BeforeDue(length)),
+        Ok(PostponeTarget::AbsoluteTime(time)) => {
+            Ok(StartChange::At(Some(time.date_naive()), Some(time.time())))
+        }
+        Err(_) => match parse_date_and_time(&text) {
+            Ok((date, time)) => Ok(StartChange::At(date, time)),
+            Err(_) => Err("Use a date, a time or a length before due like 2 hours".to_string()),
+        },
+    }
+}
+
+/// Check the start field against the due date field
+pub fn validate_start(text: &str, date: &str) -> Result<(), String> {
+    if let StartChange::BeforeDue(_) = parse_start(text)? {
+        if date.trim().is_empty() {
+            return Err("Needs a due date to count from".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// The task's start date, if it's set apart from the due date
+pub fn separate_start(task: &Task) -> Option<DateTime<Utc>> {
+    (task.start_date.timestamp() > 0 && task.start_date != task.due_date).then_some(task.start_date)
+}
+
+/// Whether a task has reached its start date (tasks without one always have)
+pub fn has_started(task: &Task) -> bool {
+    let Some(start) = separate_start(task) else {
+        return true;
+    };
+    let now = Local::now();
+    if task.is_all_day {
+        start.with_timezone(&Local).date_naive() <= now.date_naive()
+    } else {
+        start <= now
+    }
+}
+
+/// The start date as the start field shows it, or an empty string
+pub fn format_start(task: &Task) -> String {
+    separate_start(task)
+        .map(|start| {
+            let local = start.with_timezone(&Local);
+            if local.time() == NaiveTime::MIN {
+                local.format("%m/%d/%Y").to_string()
+            } else {
+                local.format("%m/%d/%Y %I:%M %p").to_string()
+            }
+        })
+        .unwrap_or_default()
 }
 
 /// Mark a task as completed using client directly
 pub async fn complete_task_with_client(
-    client: &TickTick,
+    client: &dyn TaskBackend,
     project_id: &ProjectID,
     task_id: &TaskID,
 ) -> Result<(), String> {
-    // Get a fresh task instance from the API with proper client context
-    match client.get_project_data(project_id).await {
-        Ok(project_data) => {
-            // Find the task in the project data
-            let task_id_str = format!("{:?}", task_id);
-            if let Some(mut task) = project_data.tasks.into_iter().find(|t| {
-                let t_id_str = format!("{:?}", t.get_id());
-                t_id_str == task_id_str
-            }) {
-                match task.complete().await {
-                    Ok(_) => Ok(()),
-                    Err(e) => Err(format!("Failed to complete task: {:?}", e)),
-                }
-            } else {
-                Err("Task not found in project".to_string())
-            }
-        }
-        Err(e) => Err(format!("Failed to get project data: {:?}", e)),
-    }
+    client.complete_task(project_id, task_id).await
 }
 
 /// Delete a task
-pub async fn delete_task(task: Task) -> Result<(), String> {
-    match task.delete().await {
-        Ok(_) => Ok(()),
-        Err(e) => Err(format!("Failed to delete task: {:?}", e)),
-    }
+pub async fn delete_task(
+    client: &dyn TaskBackend,
+    project_id: &ProjectID,
+    task_id: &TaskID,
+) -> Result<(), String> {
+    client.delete_task(project_id, task_id).await
 }
 
 /// Sort tasks by due_date, then start_date, then sort_order
 pub fn sort_tasks(tasks: &mut Vec<Task>) {
     tasks.sort_by(|a, b| {
-        use chrono::{DateTime, Datelike, Utc};
+        use chrono::Datelike;
 
         // Helper to check if a datetime is the epoch (unset)
         let is_unset = |dt: &DateTime<Utc>| dt.timestamp() == 0;
@@ -373,3 +543,101 @@ pub fn sort_tasks(tasks: &mut Vec<Task>) {
         a.sort_order.cmp(&b.sort_order)
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::task_fixture;
+
+    fn set_due(task: &mut Task, date: Option<NaiveDate>, time: Option<NaiveTime>) {
+        let changes = TaskChanges {
+            date,
+            time,
+            ..Default::default()
+        };
+        apply_task_changes(task, &changes).unwrap();
+    }
+
+    fn set_start(task: &mut Task, start: StartChange) {
+        let changes = TaskChanges {
+            start: Some(start),
+            ..Default::default()
+        };
+        apply_task_changes(task, &changes).unwrap();
+    }
+
+    #[test]
+    fn test_parse_start() {
+        assert_eq!(parse_start(" "), Ok(StartChange::Clear));
+        assert_eq!(parse_start("None"), Ok(StartChange::Clear));
+        assert_eq!(
+            parse_start("2 hours"),
+            Ok(StartChange::BeforeDue(chrono::Duration::hours(2)))
+        );
+        assert_eq!(
+            parse_start("90min before due"),
+            Ok(StartChange::BeforeDue(chrono::Duration::minutes(90)))
+        );
+        assert_eq!(
+            parse_start("9am"),
+            Ok(StartChange::At(None, NaiveTime::from_hms_opt(9, 0, 0)))
+        );
+        assert_eq!(
+            parse_start("12/20/2030"),
+            Ok(StartChange::At(NaiveDate::from_ymd_opt(2030, 12, 20), None))
+        );
+        assert!(parse_start("whenever").is_err());
+    }
+
+    #[test]
+    fn test_start_is_separate_from_due() {
+        let day = NaiveDate::from_ymd_opt(2030, 12, 25).unwrap();
+        let mut task = task_fixture("t1", "inbox", "Workshop");
+        set_due(&mut task, Some(day), NaiveTime::from_hms_opt(17, 0, 0));
+        assert_eq!(separate_start(&task), None);
+
+        // A time alone starts on the due day
+        set_start(
+            &mut task,
+            StartChange::At(None, NaiveTime::from_hms_opt(9, 0, 0)),
+        );
+        assert_eq!(format_start(&task), "12/25/2030 09:00 AM");
+        assert!(!has_started(&task));
+
+        // Moving the due date keeps the span
+        set_due(&mut task, day.succ_opt(), NaiveTime::from_hms_opt(17, 0, 0));
+        assert_eq!(format_start(&task), "12/26/2030 09:00 AM");
+
+        set_start(
+            &mut task,
+            StartChange::BeforeDue(chrono::Duration::hours(2)),
+        );
+        assert_eq!(format_start(&task), "12/26/2030 03:00 PM");
+
+        set_start(&mut task, StartChange::Clear);
+        assert_eq!(separate_start(&task), None);
+        assert!(has_started(&task));
+    }
+
+    #[test]
+    fn test_resolve_local() {
+        let datetime = NaiveDate::from_ymd_opt(2030, 3, 10)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+
+        // Skipped when clocks go forward
+        assert_eq!(
+            resolve_local(LocalResult::None, datetime),
+            Err("2030-03-10 02:30 doesn't exist in the local time zone".to_string())
+        );
+
+        // Repeated when clocks go back
+        let earlier = Local.timestamp_opt(1_900_000_000, 0).unwrap();
+        let later = earlier + chrono::Duration::hours(1);
+        assert_eq!(
+            resolve_local(LocalResult::Ambiguous(earlier, later), datetime),
+            Ok(earlier.to_utc())
+        );
+    }
+}
@@ -0,0 +1,4 @@
+use crate::dated_log::DatedLog;
+
+/// Snapshots of deleted tasks, which can be restored for 30 days
+pub type Trash = DatedLog<30>;
@@ -1,22 +1,39 @@
 use anyhow::Result;
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Local, NaiveTime, Utc};
 use edtui::{EditorTheme, EditorView};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style, Stylize},
+    style::{Modifier, Style, Stylize},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem, Padding, Paragraph},
 };
-use ticks::tasks::{Task, TaskPriority};
+use ticks::tasks::{Task, TaskID, TaskPriority};
 
 use super::colors::*;
 use super::tui::Frame as TuiFrame;
 use super::{centered_rect, InputField, TaskEditor, TaskList, ViewTab};
-use crate::app::Mode;
+use crate::{
+    app::Mode,
+    backend::{same_task_id, ProjectInfo},
+    checklist::checklist_progress,
+    completed::day_headings,
+    config::DateFormats,
+    keymap::{HelpSection, Keymap},
+    recurrence::repeat_text,
+    reminders::{describe_trigger, reminders},
+    search::{match_ranges, Search, SearchScope},
+    tasks::{has_started, separate_start},
+};
 
 pub struct AppUI {
     pub task_list: TaskList,
     pub task_editor: TaskEditor,
+    /// How dates and times are written in the task list
+    pub dates: DateFormats,
+    /// Key bindings listed in the help overlay
+    pub help: Vec<HelpSection>,
+    /// Colors of the active theme
+    pub palette: Palette,
 }
 
 impl AppUI {
@@ -24,9 +41,13 @@ impl AppUI {
         Self {
             task_list: TaskList::new(),
             task_editor: TaskEditor::new(),
+            dates: DateFormats::default(),
+            help: Keymap::default().help(),
+            palette: Palette::DARK,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn draw(
         &mut self,
         f: &mut TuiFrame,
@@ -34,11 +55,24 @@ impl AppUI {
         mode: Mode,
         tasks: &[Task],
         error_message: &Option<String>,
+        status_message: &Option<String>,
         tasks_loaded: bool,
         task_editor_focused: bool,
+        offline: bool,
+        pending_ids: &[TaskID],
+        projects: &[ProjectInfo],
+        open_project: Option<&ProjectInfo>,
+        known_tags: &[String],
+        open_tag: Option<&str>,
+        tag_filter: &[String],
+        hide_unstarted: bool,
+        removed_at: &[DateTime<Utc>],
+        search: Option<&Search>,
     ) -> Result<()> {
+        let palette = self.palette;
         // Set consistent background for entire screen
-        let background = Block::default().style(Style::default().bg(NORMAL_BG));
+        let background =
+            Block::default().style(Style::default().bg(palette.normal_bg).fg(palette.text_fg));
         f.render_widget(background, area);
 
         // Main vertical layout: Header, Content, Footer
@@ -68,7 +102,14 @@ impl AppUI {
             })
             .split(main_chunks[1]);
 
-        self.render_header(f, main_chunks[0], mode, error_message);
+        self.render_header(
+            f,
+            main_chunks[0],
+            mode,
+            error_message,
+            status_message,
+            offline,
+        );
         self.render_task_list(
             f,
             content_chunks[0],
@@ -76,13 +117,22 @@ impl AppUI {
             tasks,
             tasks_loaded,
             task_editor_focused,
+            pending_ids,
+            projects,
+            open_project,
+            known_tags,
+            open_tag,
+            tag_filter,
+            hide_unstarted,
+            removed_at,
+            search,
         );
         self.render_task_details(f, content_chunks[1], tasks, task_editor_focused);
-        self.render_footer(f, main_chunks[2], mode);
+        self.render_footer(f, main_chunks[2], mode, search);
 
         // Render overlays
         if let Some(modal) = &mut self.task_list.current_modal {
-            modal.render(f, area);
+            modal.render(f, area, &palette);
         } else if mode == Mode::Help {
             self.render_help_overlay(f, area);
         }
@@ -96,32 +146,47 @@ impl AppUI {
         area: Rect,
         mode: Mode,
         error_message: &Option<String>,
+        status_message: &Option<String>,
+        offline: bool,
     ) {
+        let palette = self.palette;
         let (title, style) = if let Some(err) = error_message {
             (
                 Line::from(vec![
-                    Span::styled("❌ ", Style::default().fg(ACCENT_RED).bold()),
-                    Span::styled("Error: ", Style::default().fg(TEXT_WHITE).bold()),
-                    Span::styled(err, Style::default().fg(TEXT_WHITE)),
+                    Span::styled("❌ ", Style::default().fg(palette.accent_red).bold()),
+                    Span::styled("Error: ", Style::default().fg(palette.text_white).bold()),
+                    Span::styled(err, Style::default().fg(palette.text_white)),
                 ]),
-                Style::default().bg(ACCENT_RED),
+                Style::default().bg(palette.accent_red),
             )
         } else {
             let (icon, text, accent_color) = match mode {
-                Mode::Processing => ("⏳", " Processing...", ACCENT_YELLOW),
-                Mode::Insert => ("✏️", " Insert Mode", ACCENT_GREEN),
-                Mode::Visual => ("👁️", " Visual Mode", Color::Cyan),
-                Mode::Help => ("❓", " Help", Color::Cyan),
-                Mode::Normal => ("📋", " Automatick", HEADER_FG),
+                Mode::Processing => ("⏳", " Processing...", palette.accent_yellow),
+                Mode::Insert => ("✏️", " Insert Mode", palette.accent_green),
+                Mode::Visual => ("👁️", " Visual Mode", palette.accent_cyan),
+                Mode::Help => ("❓", " Help", palette.accent_cyan),
+                Mode::Search => ("🔍", " Search", palette.accent_yellow),
+                Mode::Normal => ("📋", " Automatick", palette.header_fg),
             };
 
-            (
-                Line::from(vec![
-                    Span::styled(icon, Style::default().fg(accent_color).bold()),
-                    Span::styled(text, Style::default().fg(HEADER_FG).bold()),
-                ]),
-                Style::default().bg(NORMAL_BG),
-            )
+            let mut spans = vec![
+                Span::styled(icon, Style::default().fg(accent_color).bold()),
+                Span::styled(text, Style::default().fg(palette.header_fg).bold()),
+            ];
+            if let Some(status) = status_message {
+                spans.push(Span::styled(
+                    format!("  {}", status),
+                    Style::default().fg(palette.accent_cyan),
+                ));
+            }
+            if offline {
+                spans.push(Span::styled(
+                    "  ⚠ Offline",
+                    Style::default().fg(palette.accent_yellow).bold(),
+                ));
+            }
+
+            (Line::from(spans), Style::default().bg(palette.normal_bg))
         };
 
         let header = Paragraph::new(title)
@@ -132,6 +197,7 @@ impl AppUI {
         f.render_widget(header, area);
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn render_task_list(
         &mut self,
         f: &mut TuiFrame,
@@ -140,67 +206,114 @@ impl AppUI {
         tasks: &[Task],
         tasks_loaded: bool,
         task_editor_focused: bool,
+        pending_ids: &[TaskID],
+        projects: &[ProjectInfo],
+        open_project: Option<&ProjectInfo>,
+        known_tags: &[String],
+        open_tag: Option<&str>,
+        tag_filter: &[String],
+        hide_unstarted: bool,
+        removed_at: &[DateTime<Utc>],
+        search: Option<&Search>,
     ) {
+        let palette = self.palette;
         let border_color = if task_editor_focused {
-            BORDER_NORMAL
+            palette.border_normal
         } else {
             // Task list is active - use brighter border
-            BORDER_INSERT
+            palette.border_insert
         };
 
         // Create overlapping tab effect with dynamic sizing
-        let tabs_title = match self.task_list.current_tab {
-            ViewTab::Today => Line::from(vec![
-                Span::raw(" "),
-                Span::styled(
-                    "  📅 Today  ",
-                    Style::default().fg(TEXT_WHITE).bg(SELECTED_BG).bold(),
-                ),
-                Span::styled(" Week", Style::default().fg(TEXT_FG).dim()),
-                Span::raw(" "),
-                Span::styled("Inbox", Style::default().fg(TEXT_FG).dim()),
-                Span::raw(" "),
-            ]),
-            ViewTab::Week => Line::from(vec![
-                Span::raw(" "),
-                Span::styled("Today", Style::default().fg(TEXT_FG).dim()),
-                Span::raw(" "),
-                Span::styled(
-                    "  📆 Week  ",
-                    Style::default().fg(TEXT_WHITE).bg(SELECTED_BG).bold(),
-                ),
-                Span::styled(" Inbox", Style::default().fg(TEXT_FG).dim()),
-                Span::raw(" "),
-            ]),
-            ViewTab::Inbox => Line::from(vec![
-                Span::raw(" "),
-                Span::styled("Today", Style::default().fg(TEXT_FG).dim()),
-                Span::raw(" "),
-                Span::styled("Week ", Style::default().fg(TEXT_FG).dim()),
-                Span::styled(
-                    "  📥 Inbox  ",
-                    Style::default().fg(TEXT_WHITE).bg(SELECTED_BG).bold(),
-                ),
-                Span::raw(" "),
-            ]),
-        };
+        let mut tab_spans = vec![Span::raw(" ")];
+        for tab in self.task_list.tabs() {
+            if tab == self.task_list.current_tab {
+                // An open project or tag replaces the tab title with its name
+                let title = match (tab, open_project, open_tag) {
+                    (ViewTab::Projects, Some(project), _) => project.name.clone(),
+                    (ViewTab::Tags, _, Some(tag)) => format!("#{}", tag),
+                    _ => self.task_list.tab_title(tab).to_string(),
+                };
+                tab_spans.push(Span::styled(
+                    format!("  {} {}  ", tab.icon(), title),
+                    Style::default()
+                        .fg(palette.text_white)
+                        .bg(palette.selected_bg)
+                        .bold(),
+                ));
+            } else {
+                tab_spans.push(Span::styled(
+                    format!(" {} ", self.task_list.tab_title(tab)),
+                    Style::default().fg(palette.text_fg).dim(),
+                ));
+            }
+        }
+        if !tag_filter.is_empty() {
+            let filter: Vec<String> = tag_filter.iter().map(|tag| format!("#{}", tag)).collect();
+            tab_spans.push(Span::styled(
+                format!(" Filter: {} ", filter.join(" ")),
+                Style::default().fg(palette.tag_fg).bold(),
+            ));
+        }
+        if hide_unstarted {
+            tab_spans.push(Span::styled(
+                " Not started hidden ",
+                Style::default().fg(palette.date_not_started).bold(),
+            ));
+        }
+        // Searching every project replaces the tab's tasks with the results
+        let searching_all = search.is_some_and(|search| search.scope == SearchScope::All);
+        if let Some(search) = search {
+            let scope = if searching_all {
+                " in all projects"
+            } else {
+                ""
+            };
+            tab_spans.push(Span::styled(
+                format!(" /{}{} ", search.query, scope),
+                Style::default().fg(palette.accent_yellow).bold(),
+            ));
+        }
+        let tabs_title = Line::from(tab_spans);
 
         let block = Block::default()
             .title(tabs_title)
             .borders(Borders::ALL)
             .border_style(Style::default().fg(border_color))
-            .style(Style::default().bg(NORMAL_BG));
+            .style(Style::default().bg(palette.normal_bg));
+
+        let tab = self.task_list.current_tab;
+        if tab == ViewTab::Projects && open_project.is_none() && !searching_all {
+            self.render_project_browser(f, area, block, projects, tasks_loaded);
+            return;
+        }
+        if tab == ViewTab::Tags && open_tag.is_none() && !searching_all {
+            self.render_tag_browser(f, area, block, known_tags, tasks_loaded);
+            return;
+        }
 
         if tasks.is_empty() {
-            if tasks_loaded {
+            let removed_msg = match tab {
+                _ if search.is_some() => Some("No matching tasks"),
+                ViewTab::Completed => Some("No completed tasks yet"),
+                ViewTab::Trash => Some("Trash is empty"),
+                _ => None,
+            };
+            if let Some(message) = removed_msg {
+                let empty_msg = Paragraph::new(message)
+                    .style(Style::default().fg(palette.text_fg))
+                    .block(block)
+                    .alignment(ratatui::layout::Alignment::Center);
+                f.render_widget(empty_msg, area);
+            } else if tasks_loaded {
                 let empty_msg = Paragraph::new("No tasks found")
-                    .style(Style::default().fg(TEXT_FG))
+                    .style(Style::default().fg(palette.text_fg))
                     .block(block)
                     .alignment(ratatui::layout::Alignment::Center);
                 f.render_widget(empty_msg, area);
             } else {
                 let loading_msg = Paragraph::new("Loading tasks...")
-                    .style(Style::default().fg(TEXT_FG))
+                    .style(Style::default().fg(palette.text_fg))
                     .block(block)
                     .alignment(ratatui::layout::Alignment::Center);
                 f.render_widget(loading_msg, area);
@@ -219,15 +332,27 @@ impl AppUI {
                     if is_all_day {
                         None
                     } else {
-                        Some(local.format("%I:%M %p").to_string())
+                        Some(local.format(&self.dates.time).to_string())
                     }
                 } else if is_all_day {
-                    Some(local.format("%m/%d/%Y").to_string())
+                    Some(local.format(&self.dates.date).to_string())
                 } else {
-                    Some(local.format("%m/%d/%Y %I:%M %p").to_string())
+                    Some(format!(
+                        "{} {}",
+                        local.format(&self.dates.date),
+                        local.format(&self.dates.time)
+                    ))
                 }
             }
         };
+        // Completed and deleted tasks are grouped by the day they were removed
+        let day_headings = day_headings(removed_at, Local::now().date_naive());
+        let (removed_icon, removed_color, removed_verb) =
+            if self.task_list.current_tab == ViewTab::Trash {
+                ("✗", palette.date_overdue, "Deleted")
+            } else {
+                ("✓", palette.accent_green, "Done")
+            };
         let items: Vec<ListItem> = tasks
             .iter()
             .enumerate()
@@ -241,35 +366,99 @@ impl AppUI {
                 } else {
                     Some(i) == selected
                 };
-                let bg_color = if i % 2 == 0 { NORMAL_BG } else { ALT_BG };
-                let status_icon = "○";
+                let bg_color = if i % 2 == 0 {
+                    palette.normal_bg
+                } else {
+                    palette.alt_bg
+                };
+                let removed_at = removed_at.get(i);
+                let (status_icon, status_color) = if removed_at.is_some() {
+                    (removed_icon, removed_color)
+                } else {
+                    ("○", palette.text_fg)
+                };
 
                 let priority_color = match task.priority {
-                    TaskPriority::High => PRIORITY_HIGH,
-                    TaskPriority::Medium => PRIORITY_MEDIUM,
-                    TaskPriority::Low => PRIORITY_LOW,
-                    TaskPriority::None => PRIORITY_NONE,
+                    TaskPriority::High => palette.priority_high,
+                    TaskPriority::Medium => palette.priority_medium,
+                    TaskPriority::Low => palette.priority_low,
+                    TaskPriority::None => palette.priority_none,
                 };
 
-                let text_color = TEXT_FG;
-                let row1 = Line::from("");
+                let text_color = palette.text_fg;
+                // The first task removed on each day carries the day's heading
+                let row1 = match day_headings.get(i).cloned().flatten() {
+                    Some(heading) => Line::from(Span::styled(
+                        heading,
+                        Style::default().fg(palette.accent_yellow).bold(),
+                    )),
+                    None => Line::from(""),
+                };
                 let mut row2_spans = vec![];
                 if is_selected {
-                    row2_spans.push(Span::styled("▶ ", Style::default().fg(TEXT_FG)));
+                    row2_spans.push(Span::styled("▶ ", Style::default().fg(palette.text_fg)));
                 } else {
                     row2_spans.push(Span::raw("  "));
                 }
-                row2_spans.push(Span::styled(status_icon, Style::default().fg(text_color)));
+                row2_spans.push(Span::styled(status_icon, Style::default().fg(status_color)));
                 row2_spans.push(Span::raw(" "));
                 row2_spans.push(Span::styled("●", Style::default().fg(priority_color)));
                 row2_spans.push(Span::raw(" "));
-                row2_spans.push(Span::styled(&task.title, Style::default().fg(text_color)));
+                // Highlight the parts of the title that match the search
+                let mut shown = 0;
+                let ranges =
+                    search.map_or_else(Vec::new, |search| match_ranges(&task.title, &search.query));
+                for range in ranges {
+                    row2_spans.push(Span::styled(
+                        &task.title[shown..range.start],
+                        Style::default().fg(text_color),
+                    ));
+                    row2_spans.push(Span::styled(
+                        &task.title[range.clone()],
+                        Style::default()
+                            .fg(palette.normal_bg)
+                            .bg(palette.accent_yellow),
+                    ));
+                    shown = range.end;
+                }
+                row2_spans.push(Span::styled(
+                    &task.title[shown..],
+                    Style::default().fg(text_color),
+                ));
 
                 let row2 = Line::from(row2_spans);
 
                 // Row 3 with date information
                 let mut row3_spans = vec![Span::raw("    ")];
 
+                if let Some(removed_at) = removed_at {
+                    row3_spans.push(Span::styled(
+                        format!(
+                            "{} {}  ",
+                            removed_verb,
+                            removed_at.with_timezone(&Local).format(&self.dates.time)
+                        ),
+                        Style::default().fg(removed_color),
+                    ));
+                }
+
+                // A start at midnight reads as a day, like an all-day due date
+                let start_str = separate_start(task).and_then(|start| {
+                    let is_day = start.with_timezone(&Local).time() == NaiveTime::MIN;
+                    format_date(&start, task.is_all_day || is_day)
+                });
+                if let Some(start_str) = start_str {
+                    let start_color = if has_started(task) {
+                        palette.date_normal
+                    } else {
+                        palette.date_not_started
+                    };
+                    row3_spans.push(Span::styled(
+                        format!("⏵ {} → ", start_str),
+                        Style::default().fg(start_color),
+                    ));
+                }
+
                 if let Some(due_str) = format_date(&task.due_date, task.is_all_day) {
                     // Check if task is overdue
                     let is_overdue = {
@@ -287,15 +476,72 @@ impl AppUI {
                     };
 
                     let date_color = if is_overdue {
-                        DATE_OVERDUE
+                        palette.date_overdue
                     } else {
-                        DATE_NORMAL
+                        palette.date_normal
                     };
 
                     row3_spans.push(Span::styled(due_str, Style::default().fg(date_color)));
                     row3_spans.push(Span::raw("  "));
                 }
 
+                match reminders(task).as_slice() {
+                    [] => {}
+                    [trigger] => {
+                        row3_spans.push(Span::styled(
+                            format!("🔔 {}", describe_trigger(trigger, task.is_all_day)),
+                            Style::default().fg(palette.date_normal),
+                        ));
+                        row3_spans.push(Span::raw("  "));
+                    }
+                    triggers => {
+                        row3_spans.push(Span::styled(
+                            format!("🔔 {} reminders", triggers.len()),
+                            Style::default().fg(palette.date_normal),
+                        ));
+                        row3_spans.push(Span::raw("  "));
+                    }
+                }
+
+                if let Some(repeat) = repeat_text(task) {
+                    row3_spans.push(Span::styled(
+                        format!("↻ {}", repeat),
+                        Style::default().fg(palette.date_normal),
+                    ));
+                    row3_spans.push(Span::raw("  "));
+                }
+
+                if let Some((checked, total)) = checklist_progress(task) {
+                    let progress_color = if checked == total {
+                        palette.accent_green
+                    } else {
+                        palette.date_normal
+                    };
+                    row3_spans.push(Span::styled(
+                        format!("☑ {}/{}", checked, total),
+                        Style::default().fg(progress_color),
+                    ));
+                    row3_spans.push(Span::raw("  "));
+                }
+
+                for tag in &task.tags {
+                    row3_spans.push(Span::styled(
+                        format!("#{} ", tag),
+                        Style::default().fg(palette.tag_fg),
+                    ));
+                }
+                if !task.tags.is_empty() {
+                    row3_spans.push(Span::raw(" "));
+                }
+
+                // Mark tasks with changes that haven't reached the server yet
+                if pending_ids.iter().any(|id| same_task_id(id, task.get_id())) {
+                    row3_spans.push(Span::styled(
+                        "⟳ Pending sync",
+                        Style::default().fg(palette.accent_yellow),
+                    ));
+                }
+
                 let row3 = Line::from(row3_spans);
 
                 ListItem::new(vec![row1, row2, row3]).style(Style::default().bg(bg_color))
@@ -304,7 +550,112 @@ impl AppUI {
 
         let list = List::new(items).block(block).highlight_style(
             Style::default()
-                .bg(SELECTED_BG)
+                .bg(palette.selected_bg)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        f.render_stateful_widget(list, area, self.task_list.get_list_state_mut());
+    }
+
+    /// List of projects shown in the Projects tab until one is opened
+    fn render_project_browser(
+        &mut self,
+        f: &mut TuiFrame,
+        area: Rect,
+        block: Block,
+        projects: &[ProjectInfo],
+        tasks_loaded: bool,
+    ) {
+        let palette = self.palette;
+        if projects.is_empty() {
+            let message = if tasks_loaded {
+                "No projects found"
+            } else {
+                "Loading projects..."
+            };
+            let empty_msg = Paragraph::new(message)
+                .style(Style::default().fg(palette.text_fg))
+                .block(block)
+                .alignment(ratatui::layout::Alignment::Center);
+            f.render_widget(empty_msg, area);
+            return;
+        }
+
+        let selected = self.task_list.selected_index();
+        let items: Vec<ListItem> = projects
+            .iter()
+            .enumerate()
+            .map(|(i, project)| {
+                let bg_color = if i % 2 == 0 {
+                    palette.normal_bg
+                } else {
+                    palette.alt_bg
+                };
+                let marker = if Some(i) == selected { "▶ " } else { "  " };
+                ListItem::new(Line::from(vec![
+                    Span::styled(marker, Style::default().fg(palette.text_fg)),
+                    Span::styled("📁 ", Style::default().fg(palette.text_fg)),
+                    Span::styled(&project.name, Style::default().fg(palette.text_fg)),
+                ]))
+                .style(Style::default().bg(bg_color))
+            })
+            .collect();
+
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .bg(palette.selected_bg)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        f.render_stateful_widget(list, area, self.task_list.get_list_state_mut());
+    }
+
+    /// List every tag in use, for the Tags tab before a tag is opened
+    fn render_tag_browser(
+        &mut self,
+        f: &mut TuiFrame,
+        area: Rect,
+        block: Block,
+        tags: &[String],
+        tasks_loaded: bool,
+    ) {
+        let palette = self.palette;
+        if tags.is_empty() {
+            let message = if tasks_loaded {
+                "No tags found"
+            } else {
+                "Loading tags..."
+            };
+            let empty_msg = Paragraph::new(message)
+                .style(Style::default().fg(palette.text_fg))
+                .block(block)
+                .alignment(ratatui::layout::Alignment::Center);
+            f.render_widget(empty_msg, area);
+            return;
+        }
+
+        let selected = self.task_list.selected_index();
+        let items: Vec<ListItem> = tags
+            .iter()
+            .enumerate()
+            .map(|(i, tag)| {
+                let bg_color = if i % 2 == 0 {
+                    palette.normal_bg
+                } else {
+                    palette.alt_bg
+                };
+                let marker = if Some(i) == selected { "▶ " } else { "  " };
+                ListItem::new(Line::from(vec![
+                    Span::styled(marker, Style::default().fg(palette.text_fg)),
+                    Span::styled(format!("#{}", tag), Style::default().fg(palette.tag_fg)),
+                ]))
+                .style(Style::default().bg(bg_color))
+            })
+            .collect();
+
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .bg(palette.selected_bg)
                 .add_modifier(Modifier::BOLD),
         );
 
@@ -328,23 +679,39 @@ impl AppUI {
         _tasks: &[Task],
         task_editor_focused: bool,
     ) {
+        let palette = self.palette;
         // Render background
-        let bg_block = Block::default().style(Style::default().bg(NORMAL_BG));
+        let bg_block =
+            Block::default().style(Style::default().bg(palette.normal_bg).fg(palette.text_fg));
         f.render_widget(bg_block, area);
 
+        // While the checklist has focus none of the fields above it do
+        let pane_focused = task_editor_focused;
+        let checklist_focused = pane_focused && self.task_editor.checklist_focused;
+        let task_editor_focused = pane_focused && !checklist_focused;
+        let checklist_height = self.task_editor.checklist.height();
+
         let constraints = if task_editor_focused {
             vec![
-                Constraint::Length(3), // Title field
-                Constraint::Length(4), // Date field + error message
-                Constraint::Length(4), // Time field + error message
-                Constraint::Min(3),    // Description field (remaining space, min 3 lines)
+                Constraint::Length(3),                // Title field
+                Constraint::Length(4),                // Date and time fields + error messages
+                Constraint::Length(4),                // Start and reminders fields + error messages
+                Constraint::Length(4),                // Project field + matches, repeat field
+                Constraint::Length(4),                // Priority field + error message
+                Constraint::Length(4),                // Tags field + suggestions
+                Constraint::Min(3), // Description field (remaining space, min 3 lines)
+                Constraint::Length(checklist_height), // Checklist items + key hint
             ]
         } else {
             vec![
-                Constraint::Length(3), // Title field
-                Constraint::Length(4), // Date field + error message
-                Constraint::Length(4), // Time field + error message
-                Constraint::Min(3),    // Description field (remaining space, min 3 lines)
+                Constraint::Length(3),                // Title field
+                Constraint::Length(4),                // Date and time fields + error messages
+                Constraint::Length(4),                // Start and reminders fields + error messages
+                Constraint::Length(4),                // Project field + matches, repeat field
+                Constraint::Length(4),                // Priority field + error message
+                Constraint::Length(4),                // Tags field + suggestions
+                Constraint::Min(3), // Description field (remaining space, min 3 lines)
+                Constraint::Length(checklist_height), // Checklist items + key hint
             ]
         };
 
@@ -353,24 +720,36 @@ impl AppUI {
             .margin(1)
             .constraints(constraints)
             .split(area);
+        let date_time_columns = Layout::default()
+            .direction(ratatui::layout::Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+        let start_reminders_columns = Layout::default()
+            .direction(ratatui::layout::Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[2]);
+        let project_repeat_columns = Layout::default()
+            .direction(ratatui::layout::Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[3]);
 
         // Title field
         let title_border_color = if task_editor_focused
             && self.task_editor.current_input_field == InputField::Title
             && self.task_editor.is_current_editor_in_insert_mode()
         {
-            ACCENT_YELLOW
+            palette.accent_yellow
         } else if task_editor_focused && self.task_editor.current_input_field == InputField::Title {
-            BORDER_INSERT
+            palette.border_insert
         } else {
-            BORDER_NORMAL
+            palette.border_normal
         };
 
         let title_block = Block::default()
             .title("Title")
             .borders(Borders::ALL)
             .border_style(Style::default().fg(title_border_color))
-            .style(Style::default().bg(NORMAL_BG));
+            .style(Style::default().bg(palette.normal_bg));
 
         let title_inner = title_block.inner(chunks[0]);
         f.render_widget(title_block, chunks[0]);
@@ -378,13 +757,13 @@ impl AppUI {
         let title_theme =
             if task_editor_focused && self.task_editor.current_input_field == InputField::Title {
                 EditorTheme::default()
-                    .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
-                    .cursor_style(Style::default().bg(TEXT_FG).fg(NORMAL_BG))
-                    .selection_style(Style::default().bg(SELECTED_BG).fg(TEXT_FG))
+                    .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                    .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                    .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
                     .hide_status_line()
             } else {
                 EditorTheme::default()
-                    .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
+                    .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
                     .hide_status_line()
                     .hide_cursor()
             };
@@ -399,28 +778,28 @@ impl AppUI {
                 Constraint::Length(3), // Date input
                 Constraint::Length(1), // Error message
             ])
-            .split(chunks[1]);
+            .split(date_time_columns[0]);
 
         let date_has_error =
             self.task_editor.validation_attempted && self.task_editor.date_error.is_some();
         let date_border_color = if date_has_error {
-            ACCENT_RED
+            palette.accent_red
         } else if task_editor_focused
             && self.task_editor.current_input_field == InputField::Date
             && self.task_editor.is_current_editor_in_insert_mode()
         {
-            ACCENT_YELLOW
+            palette.accent_yellow
         } else if task_editor_focused && self.task_editor.current_input_field == InputField::Date {
-            BORDER_INSERT
+            palette.border_insert
         } else {
-            BORDER_NORMAL
+            palette.border_normal
         };
 
         let date_block = Block::default()
-            .title("Date (MM/DD/YYYY)")
+            .title("Date (MM/DD/YYYY, tomorrow, fri)")
             .borders(Borders::ALL)
             .border_style(Style::default().fg(date_border_color))
-            .style(Style::default().bg(NORMAL_BG));
+            .style(Style::default().bg(palette.normal_bg));
 
         let date_inner = date_block.inner(date_field_layout[0]);
         f.render_widget(date_block, date_field_layout[0]);
@@ -428,13 +807,13 @@ impl AppUI {
         let date_theme =
             if task_editor_focused && self.task_editor.current_input_field == InputField::Date {
                 EditorTheme::default()
-                    .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
-                    .cursor_style(Style::default().bg(TEXT_FG).fg(NORMAL_BG))
-                    .selection_style(Style::default().bg(SELECTED_BG).fg(TEXT_FG))
+                    .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                    .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                    .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
                     .hide_status_line()
             } else {
                 EditorTheme::default()
-                    .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
+                    .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
                     .hide_status_line()
                     .hide_cursor()
             };
@@ -444,8 +823,11 @@ impl AppUI {
 
         // Render date error message if present
         if let Some(error) = &self.task_editor.date_error {
-            let error_paragraph =
-                Paragraph::new(error.as_str()).style(Style::default().fg(ACCENT_RED).bg(NORMAL_BG));
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
             f.render_widget(error_paragraph, date_field_layout[1]);
         }
 
@@ -456,28 +838,28 @@ impl AppUI {
                 Constraint::Length(3), // Time input
                 Constraint::Length(1), // Error message
             ])
-            .split(chunks[2]);
+            .split(date_time_columns[1]);
 
         let time_has_error =
             self.task_editor.validation_attempted && self.task_editor.time_error.is_some();
         let time_border_color = if time_has_error {
-            ACCENT_RED
+            palette.accent_red
         } else if task_editor_focused
             && self.task_editor.current_input_field == InputField::Time
             && self.task_editor.is_current_editor_in_insert_mode()
         {
-            ACCENT_YELLOW
+            palette.accent_yellow
         } else if task_editor_focused && self.task_editor.current_input_field == InputField::Time {
-            BORDER_INSERT
+            palette.border_insert
         } else {
-            BORDER_NORMAL
+            palette.border_normal
         };
 
         let time_block = Block::default()
             .title("Time (HH:MM AM/PM)")
             .borders(Borders::ALL)
             .border_style(Style::default().fg(time_border_color))
-            .style(Style::default().bg(NORMAL_BG));
+            .style(Style::default().bg(palette.normal_bg));
 
         let time_inner = time_block.inner(time_field_layout[0]);
         f.render_widget(time_block, time_field_layout[0]);
@@ -485,13 +867,13 @@ impl AppUI {
         let time_theme =
             if task_editor_focused && self.task_editor.current_input_field == InputField::Time {
                 EditorTheme::default()
-                    .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
-                    .cursor_style(Style::default().bg(TEXT_FG).fg(NORMAL_BG))
-                    .selection_style(Style::default().bg(SELECTED_BG).fg(TEXT_FG))
+                    .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                    .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                    .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
                     .hide_status_line()
             } else {
                 EditorTheme::default()
-                    .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
+                    .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
                     .hide_status_line()
                     .hide_cursor()
             };
@@ -501,45 +883,319 @@ impl AppUI {
 
         // Render time error message if present
         if let Some(error) = &self.task_editor.time_error {
-            let error_paragraph =
-                Paragraph::new(error.as_str()).style(Style::default().fg(ACCENT_RED).bg(NORMAL_BG));
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
             f.render_widget(error_paragraph, time_field_layout[1]);
         }
 
+        // Start field with error message layout
+        let start_field_layout = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Start input
+                Constraint::Length(1), // Error message
+            ])
+            .split(start_reminders_columns[0]);
+
+        let start_focused =
+            task_editor_focused && self.task_editor.current_input_field == InputField::Start;
+        let start_has_error =
+            self.task_editor.validation_attempted && self.task_editor.start_error.is_some();
+        let start_border_color = if start_has_error {
+            palette.accent_red
+        } else if start_focused && self.task_editor.is_current_editor_in_insert_mode() {
+            palette.accent_yellow
+        } else if start_focused {
+            palette.border_insert
+        } else {
+            palette.border_normal
+        };
+
+        let start_block = Block::default()
+            .title("Start (date, 9am, 2 hours before)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(start_border_color))
+            .style(Style::default().bg(palette.normal_bg));
+
+        let start_inner = start_block.inner(start_field_layout[0]);
+        f.render_widget(start_block, start_field_layout[0]);
+
+        let start_theme = if start_focused {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+                .hide_status_line()
+        } else {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .hide_status_line()
+                .hide_cursor()
+        };
+        let start_editor_view =
+            EditorView::new(&mut self.task_editor.input_start_editor).theme(start_theme);
+        f.render_widget(start_editor_view, start_inner);
+
+        // Render start error message if present
+        if let Some(error) = &self.task_editor.start_error {
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
+            f.render_widget(error_paragraph, start_field_layout[1]);
+        }
+
+        // Reminders field with error message layout
+        let reminders_field_layout = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Reminders input
+                Constraint::Length(1), // Error message
+            ])
+            .split(start_reminders_columns[1]);
+
+        let reminders_focused =
+            task_editor_focused && self.task_editor.current_input_field == InputField::Reminders;
+        let reminders_has_error =
+            self.task_editor.validation_attempted && self.task_editor.reminders_error.is_some();
+        let reminders_border_color = if reminders_has_error {
+            palette.accent_red
+        } else if reminders_focused && self.task_editor.is_current_editor_in_insert_mode() {
+            palette.accent_yellow
+        } else if reminders_focused {
+            palette.border_insert
+        } else {
+            palette.border_normal
+        };
+
+        let reminders_block = Block::default()
+            .title("Reminders (15m before, at due time)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(reminders_border_color))
+            .style(Style::default().bg(palette.normal_bg));
+
+        let reminders_inner = reminders_block.inner(reminders_field_layout[0]);
+        f.render_widget(reminders_block, reminders_field_layout[0]);
+
+        let reminders_theme = if reminders_focused {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+                .hide_status_line()
+        } else {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .hide_status_line()
+                .hide_cursor()
+        };
+        let reminders_editor_view =
+            EditorView::new(&mut self.task_editor.input_reminders_editor).theme(reminders_theme);
+        f.render_widget(reminders_editor_view, reminders_inner);
+
+        // Render reminders error message if present
+        if let Some(error) = &self.task_editor.reminders_error {
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
+            f.render_widget(error_paragraph, reminders_field_layout[1]);
+        }
+
+        // Project field
+        let project_focused =
+            task_editor_focused && self.task_editor.current_input_field == InputField::Project;
+        let project_border_color =
+            if project_focused && self.task_editor.is_current_editor_in_insert_mode() {
+                palette.accent_yellow
+            } else if project_focused {
+                palette.border_insert
+            } else {
+                palette.border_normal
+            };
+        self.task_editor.project_picker.render(
+            f,
+            project_repeat_columns[0],
+            project_border_color,
+            project_focused,
+            &palette,
+        );
+
+        // Repeat field with error message layout
+        let repeat_field_layout = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Repeat input
+                Constraint::Length(1), // Error message
+            ])
+            .split(project_repeat_columns[1]);
+
+        let repeat_focused =
+            task_editor_focused && self.task_editor.current_input_field == InputField::Repeat;
+        let repeat_has_error =
+            self.task_editor.validation_attempted && self.task_editor.repeat_error.is_some();
+        let repeat_border_color = if repeat_has_error {
+            palette.accent_red
+        } else if repeat_focused && self.task_editor.is_current_editor_in_insert_mode() {
+            palette.accent_yellow
+        } else if repeat_focused {
+            palette.border_insert
+        } else {
+            palette.border_normal
+        };
+
+        let repeat_block = Block::default()
+            .title("Repeat (every weekday, never)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(repeat_border_color))
+            .style(Style::default().bg(palette.normal_bg));
+
+        let repeat_inner = repeat_block.inner(repeat_field_layout[0]);
+        f.render_widget(repeat_block, repeat_field_layout[0]);
+
+        let repeat_theme = if repeat_focused {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+                .hide_status_line()
+        } else {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .hide_status_line()
+                .hide_cursor()
+        };
+        let repeat_editor_view =
+            EditorView::new(&mut self.task_editor.input_repeat_editor).theme(repeat_theme);
+        f.render_widget(repeat_editor_view, repeat_inner);
+
+        // Render repeat error message if present
+        if let Some(error) = &self.task_editor.repeat_error {
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
+            f.render_widget(error_paragraph, repeat_field_layout[1]);
+        }
+
+        // Priority field with error message layout
+        let priority_field_layout = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Priority input
+                Constraint::Length(1), // Error message
+            ])
+            .split(chunks[4]);
+
+        let priority_has_error =
+            self.task_editor.validation_attempted && self.task_editor.priority_error.is_some();
+        let priority_border_color = if priority_has_error {
+            palette.accent_red
+        } else if task_editor_focused
+            && self.task_editor.current_input_field == InputField::Priority
+            && self.task_editor.is_current_editor_in_insert_mode()
+        {
+            palette.accent_yellow
+        } else if task_editor_focused
+            && self.task_editor.current_input_field == InputField::Priority
+        {
+            palette.border_insert
+        } else {
+            palette.border_normal
+        };
+
+        let priority_block = Block::default()
+            .title("Priority (high, medium, low, none)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(priority_border_color))
+            .style(Style::default().bg(palette.normal_bg));
+
+        let priority_inner = priority_block.inner(priority_field_layout[0]);
+        f.render_widget(priority_block, priority_field_layout[0]);
+
+        let priority_theme = if task_editor_focused
+            && self.task_editor.current_input_field == InputField::Priority
+        {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+                .hide_status_line()
+        } else {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .hide_status_line()
+                .hide_cursor()
+        };
+        let priority_editor_view =
+            EditorView::new(&mut self.task_editor.input_priority_editor).theme(priority_theme);
+        f.render_widget(priority_editor_view, priority_inner);
+
+        // Render priority error message if present
+        if let Some(error) = &self.task_editor.priority_error {
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
+            f.render_widget(error_paragraph, priority_field_layout[1]);
+        }
+
+        // Tags field
+        let tags_focused =
+            task_editor_focused && self.task_editor.current_input_field == InputField::Tags;
+        let tags_border_color =
+            if tags_focused && self.task_editor.is_current_editor_in_insert_mode() {
+                palette.accent_yellow
+            } else if tags_focused {
+                palette.border_insert
+            } else {
+                palette.border_normal
+            };
+        self.task_editor
+            .tag_input
+            .render(f, chunks[5], tags_border_color, tags_focused, &palette);
+
         // Description field
         let description_border_color = if task_editor_focused
             && self.task_editor.current_input_field == InputField::Description
             && self.task_editor.is_current_editor_in_insert_mode()
         {
-            ACCENT_YELLOW
+            palette.accent_yellow
         } else if task_editor_focused
             && self.task_editor.current_input_field == InputField::Description
         {
-            BORDER_INSERT
+            palette.border_insert
         } else {
-            BORDER_NORMAL
+            palette.border_normal
         };
 
         let description_block = Block::default()
             .title("Description")
             .borders(Borders::ALL)
             .border_style(Style::default().fg(description_border_color))
-            .style(Style::default().bg(NORMAL_BG));
+            .style(Style::default().bg(palette.normal_bg));
 
-        let description_inner = description_block.inner(chunks[3]);
-        f.render_widget(description_block, chunks[3]);
+        let description_inner = description_block.inner(chunks[6]);
+        f.render_widget(description_block, chunks[6]);
 
         let description_theme = if task_editor_focused
             && self.task_editor.current_input_field == InputField::Description
         {
             EditorTheme::default()
-                .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
-                .cursor_style(Style::default().bg(TEXT_FG).fg(NORMAL_BG))
-                .selection_style(Style::default().bg(SELECTED_BG).fg(TEXT_FG))
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
                 .hide_status_line()
         } else {
             EditorTheme::default()
-                .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
                 .hide_status_line()
                 .hide_cursor()
         };
@@ -549,11 +1205,28 @@ impl AppUI {
                 .theme(description_theme);
         f.render_widget(description_editor_view, description_inner);
 
+        // Checklist
+        let checklist_border_color = if checklist_focused && self.task_editor.checklist.is_editing()
+        {
+            palette.accent_yellow
+        } else if checklist_focused {
+            palette.border_insert
+        } else {
+            palette.border_normal
+        };
+        self.task_editor.checklist.render(
+            f,
+            chunks[7],
+            checklist_border_color,
+            checklist_focused,
+            &palette,
+        );
+
         // Render the main border with title
-        let border_color = if task_editor_focused {
-            BORDER_INSERT
+        let border_color = if pane_focused {
+            palette.border_insert
         } else {
-            BORDER_NORMAL
+            palette.border_normal
         };
 
         let main_block = Block::default()
@@ -564,26 +1237,39 @@ impl AppUI {
         f.render_widget(main_block, area);
     }
 
-    fn render_footer(&self, f: &mut TuiFrame, area: Rect, mode: Mode) {
-        let footer_text = match mode {
-            Mode::Processing => "Processing request...",
-            Mode::Help => "Press ? or Esc to close help",
-            _ => "?: Help | q: Quit",
+    fn render_footer(&self, f: &mut TuiFrame, area: Rect, mode: Mode, search: Option<&Search>) {
+        let palette = self.palette;
+        let footer_text = match (mode, search) {
+            (Mode::Processing, _) => "Processing request...".to_string(),
+            (Mode::Help, _) => "Press ? or Esc to close help".to_string(),
+            (Mode::Search, Some(search)) => {
+                let other_scope = match search.scope {
+                    SearchScope::View => "all projects",
+                    SearchScope::All => "this tab",
+                };
+                format!(
+                    "/{}▏ | Tab: Search {} | Enter: Keep | Esc: Clear",
+                    search.query, other_scope
+                )
+            }
+            (_, Some(_)) => "n/N: Next/Previous match | Esc: Clear search | ?: Help".to_string(),
+            _ => "?: Help | q: Quit".to_string(),
         };
 
         let footer = Paragraph::new(footer_text)
-            .style(Style::default().fg(TEXT_FG).bg(NORMAL_BG))
+            .style(Style::default().fg(palette.text_fg).bg(palette.normal_bg))
             .alignment(ratatui::layout::Alignment::Center)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(BORDER_NORMAL)),
+                    .border_style(Style::default().fg(palette.border_normal)),
             );
 
         f.render_widget(footer, area);
     }
 
     fn render_help_overlay(&self, f: &mut TuiFrame, area: Rect) {
+        let palette = self.palette;
         let popup_area = centered_rect(70, 60, area);
 
         // Clear the area
@@ -592,68 +1278,71 @@ impl AppUI {
         let block = Block::default()
             .title(" Help ")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(ACCENT_YELLOW))
-            .style(Style::default().bg(NORMAL_BG))
+            .border_style(Style::default().fg(palette.accent_yellow))
+            .style(Style::default().bg(palette.normal_bg))
             .padding(Padding::uniform(1));
 
-        let help_text = vec![
-            Line::from(Span::styled(
-                "Navigation",
-                Style::default().fg(ACCENT_YELLOW).bold(),
-            )),
-            Line::from(""),
-            Line::from("  ↑ / k          Move selection up"),
-            Line::from("  ↓ / j          Move selection down"),
-            Line::from("  g / Home       Jump to first task"),
-            Line::from("  G / End        Jump to last task"),
-            Line::from("  h / l / ← / →  Switch between tabs"),
-            Line::from("  Esc            Clear selection"),
-            Line::from(""),
+        let heading = |title: &'static str| {
             Line::from(Span::styled(
-                "Task Actions",
-                Style::default().fg(Color::Yellow).bold(),
-            )),
+                title,
+                Style::default().fg(palette.accent_yellow).bold(),
+            ))
+        };
+        let mut help_text = Vec::new();
+        for section in &self.help {
+            help_text.push(heading(section.title));
+            help_text.push(Line::from(""));
+            for (keys, description) in &section.lines {
+                help_text.push(Line::from(format!("  {:<14} {}", keys, description)));
+            }
+            help_text.push(Line::from(""));
+        }
+
+        help_text.extend([
+            heading("Formats"),
             Line::from(""),
-            Line::from("  e              Complete task"),
-            Line::from("  Enter          Edit task"),
-            Line::from("  n              Create new task (with date/time)"),
-            Line::from("  d              Delete selected task"),
-            Line::from("  r              Refresh task list"),
+            Line::from(
+                "  Queries        e.g. \"due:<=+3d priority:>=medium tag:work -project:Personal\"",
+            ),
+            Line::from(
+                "  Checklist      o/O add, i rename, x check, J/K move, d delete (Task Details)",
+            ),
+            Line::from("  Start          e.g. \"12/20\", \"9am\", \"2 hours before\", none"),
+            Line::from(
+                "  Reminders      e.g. \"15m before, at due time\", \"1 day before at 9am\"",
+            ),
+            Line::from(
+                "  Repeat         e.g. \"every weekday\", \"every 2 weeks on mon,thu\", never",
+            ),
             Line::from(""),
             Line::from(Span::styled(
                 "Task Creation",
-                Style::default().fg(Color::Yellow).bold(),
+                Style::default().fg(palette.accent_yellow).bold(),
             )),
             Line::from(""),
             Line::from("  Tab            Next field"),
             Line::from("  Shift+Tab      Previous field"),
-            Line::from("  Enter          Create task"),
-            Line::from("  Esc            Cancel"),
+            Line::from("  Ctrl+N/Ctrl+P  Choose project in the Project field"),
+            Line::from("  Ctrl+N/Ctrl+P  Complete tags in the Tags field"),
             Line::from(""),
             Line::from("  Note: Date defaults to today when in Today view"),
             Line::from(""),
-            Line::from(Span::styled(
-                "General",
-                Style::default().fg(Color::Yellow).bold(),
-            )),
-            Line::from(""),
-            Line::from("  ?              Toggle this help screen"),
-            Line::from("  q              Quit application"),
-            Line::from(""),
             Line::from(Span::styled(
                 "Legend",
-                Style::default().fg(Color::Yellow).bold(),
+                Style::default().fg(palette.accent_yellow).bold(),
             )),
             Line::from(""),
             Line::from("  ○              Task"),
+            Line::from("  ✓              Completed task"),
+            Line::from("  ✗              Deleted task"),
             Line::from("  🔴             High priority"),
             Line::from("  🟡             Medium priority"),
             Line::from("  🔵             Low priority"),
-        ];
+        ]);
 
         let paragraph = Paragraph::new(help_text)
             .block(block)
-            .style(Style::default().fg(TEXT_FG));
+            .style(Style::default().fg(palette.text_fg));
 
         f.render_widget(paragraph, popup_area);
     }
@@ -0,0 +1,254 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use edtui::{EditorEventHandler, EditorMode, EditorState, EditorTheme, EditorView, Index2, Lines};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use super::colors::*;
+use super::tui::Frame as TuiFrame;
+use crate::checklist::ChecklistItem;
+
+/// Most item rows shown before the list scrolls
+const MAX_VISIBLE_ITEMS: usize = 6;
+
+/// An item title being typed, either a new item or a rename
+struct ItemEdit {
+    input: EditorState,
+    /// Whether the item was just added, so an empty title drops it
+    is_new: bool,
+}
+
+/// Checklist items of the task in the editor pane
+///
+/// Keys follow vim: j/k move, o/O add below/above, i/a rename, x or Space
+/// checks, J/K reorder and d deletes. Changes are kept here until the task is saved.
+pub struct ChecklistEditor {
+    pub items: Vec<ChecklistItem>,
+    selected: usize,
+    editing: Option<ItemEdit>,
+    event_handler: EditorEventHandler,
+}
+
+impl ChecklistEditor {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            selected: 0,
+            editing: None,
+            event_handler: EditorEventHandler::default(),
+        }
+    }
+
+    pub fn set_items(&mut self, items: Vec<ChecklistItem>) {
+        self.items = items;
+        self.selected = 0;
+        self.editing = None;
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    /// Rows needed to show every item (up to a limit), the borders and the key hint
+    pub fn height(&self) -> u16 {
+        (self.items.len().clamp(1, MAX_VISIBLE_ITEMS) + 3) as u16
+    }
+
+    fn start_edit(&mut self, text: &str, is_new: bool) {
+        let mut input = EditorState::new(Lines::from(text.to_string()));
+        input.mode = EditorMode::Insert;
+        input.cursor = Index2::new(0, text.chars().count());
+        self.editing = Some(ItemEdit { input, is_new });
+    }
+
+    fn add_item(&mut self, index: usize) {
+        self.items.insert(index, ChecklistItem::new(""));
+        self.selected = index;
+        self.start_edit("", true);
+    }
+
+    /// Keep the typed title, dropping a new item that was left empty
+    fn finish_edit(&mut self) {
+        let Some(edit) = self.editing.take() else {
+            return;
+        };
+        let title = String::from(edit.input.lines).trim().to_string();
+        if title.is_empty() {
+            if edit.is_new {
+                self.items.remove(self.selected);
+                self.selected = self.selected.min(self.items.len().saturating_sub(1));
+            }
+        } else if let Some(item) = self.items.get_mut(self.selected) {
+            item.title = title;
+        }
+    }
+
+    fn swap(&mut self, other: usize) {
+        if other < self.items.len() && self.selected < self.items.len() {
+            self.items.swap(self.selected, other);
+            self.selected = other;
+        }
+    }
+
+    /// Handle a key, returning false for keys the editor pane should handle instead
+    ///
+    /// k on the first item and Enter outside of an edit are left to the pane, so
+    /// they move back to the description and save the task.
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        if let Some(edit) = &mut self.editing {
+            match key_event.code {
+                KeyCode::Enter => self.finish_edit(),
+                KeyCode::Esc if edit.input.mode == EditorMode::Insert => self.finish_edit(),
+                _ => self.event_handler.on_key_event(key_event, &mut edit.input),
+            }
+            return true;
+        }
+
+        let last = self.items.len().saturating_sub(1);
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => self.selected = (self.selected + 1).min(last),
+            KeyCode::Char('k') | KeyCode::Up if self.selected > 0 => self.selected -= 1,
+            KeyCode::Char('g') => self.selected = 0,
+            KeyCode::Char('G') => self.selected = last,
+            KeyCode::Char('o') => self.add_item((self.selected + 1).min(self.items.len())),
+            KeyCode::Char('O') => self.add_item(self.selected),
+            KeyCode::Char('i') | KeyCode::Char('a') | KeyCode::Char('A') => {
+                if let Some(item) = self.items.get(self.selected) {
+                    let title = item.title.clone();
+                    self.start_edit(&title, false);
+                }
+            }
+            KeyCode::Char('x') | KeyCode::Char(' ') => {
+                if let Some(item) = self.items.get_mut(self.selected) {
+                    item.set_checked(!item.is_checked());
+                }
+            }
+            KeyCode::Char('J') => self.swap(self.selected + 1),
+            KeyCode::Char('K') if self.selected > 0 => self.swap(self.selected - 1),
+            KeyCode::Char('d') if !self.items.is_empty() => {
+                self.items.remove(self.selected);
+                self.selected = self.selected.min(self.items.len().saturating_sub(1));
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn render(
+        &mut self,
+        frame: &mut TuiFrame,
+        area: Rect,
+        border_color: Color,
+        focused: bool,
+        palette: &Palette,
+    ) {
+        let checked = self.items.iter().filter(|item| item.is_checked()).count();
+        let title = if self.items.is_empty() {
+            "Checklist".to_string()
+        } else {
+            format!("Checklist ({}/{})", checked, self.items.len())
+        };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color))
+            .style(Style::default().bg(palette.normal_bg));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let layout = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),    // Items
+                Constraint::Length(1), // Key hint
+            ])
+            .split(inner);
+
+        if self.items.is_empty() {
+            let empty = Paragraph::new("No items").style(
+                Style::default()
+                    .fg(palette.date_normal)
+                    .bg(palette.normal_bg),
+            );
+            frame.render_widget(empty, layout[0]);
+        }
+
+        // Scroll so the selected item stays visible
+        let visible = layout[0].height as usize;
+        let first = (self.selected + 1).saturating_sub(visible);
+        for (row, (i, item)) in self
+            .items
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(visible)
+            .enumerate()
+        {
+            let row_area = Rect {
+                y: layout[0].y + row as u16,
+                height: 1,
+                ..layout[0]
+            };
+            let is_selected = focused && i == self.selected;
+            let marker = if is_selected { "▶ " } else { "  " };
+            let checkbox = if item.is_checked() { "[x] " } else { "[ ] " };
+            let prefix = Line::from(vec![
+                Span::styled(marker, Style::default().fg(palette.text_fg)),
+                Span::styled(checkbox, Style::default().fg(palette.accent_green)),
+            ]);
+
+            if let (true, Some(edit)) = (is_selected, &mut self.editing) {
+                let columns = Layout::default()
+                    .direction(ratatui::layout::Direction::Horizontal)
+                    .constraints([Constraint::Length(6), Constraint::Min(1)])
+                    .split(row_area);
+                frame.render_widget(Paragraph::new(prefix), columns[0]);
+                let theme = EditorTheme::default()
+                    .base(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+                    .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                    .hide_status_line();
+                frame.render_widget(EditorView::new(&mut edit.input).theme(theme), columns[1]);
+                continue;
+            }
+
+            let title_style = if item.is_checked() {
+                Style::default()
+                    .fg(palette.date_normal)
+                    .add_modifier(Modifier::CROSSED_OUT)
+            } else {
+                Style::default().fg(palette.text_fg)
+            };
+            let mut spans = prefix.spans;
+            spans.push(Span::styled(item.title.as_str(), title_style));
+            let bg = if is_selected {
+                palette.selected_bg
+            } else {
+                palette.normal_bg
+            };
+            frame.render_widget(
+                Paragraph::new(Line::from(spans)).style(Style::default().bg(bg)),
+                row_area,
+            );
+        }
+
+        let hint = if !focused {
+            ""
+        } else if self.editing.is_some() {
+            "Enter done"
+        } else {
+            "o add  i rename  x check  J/K move  d delete"
+        };
+        frame.render_widget(
+            Paragraph::new(hint).style(
+                Style::default()
+                    .fg(palette.date_normal)
+                    .bg(palette.normal_bg)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+            layout[1],
+        );
+    }
+}
@@ -1,87 +1,222 @@
 use ratatui::style::Color;
 
-// ============================================================================
-// Background Colors
-// ============================================================================
-
-/// Main background color for most UI elements
-pub const NORMAL_BG: Color = Color::Rgb(19, 19, 19);
-
-/// Alternate background color for visual variety
-pub const ALT_BG: Color = Color::Rgb(25, 25, 25);
-
-/// Background color for selected items and header
-pub const SELECTED_BG: Color = Color::Rgb(36, 36, 36);
-
-// ============================================================================
-// Text/Foreground Colors
-// ============================================================================
-
-/// Standard text color
-pub const TEXT_FG: Color = Color::Rgb(200, 200, 200);
-
-/// Header text color
-pub const HEADER_FG: Color = Color::Rgb(200, 200, 200);
-
-/// White text for high contrast
-pub const TEXT_WHITE: Color = Color::White;
-
-// ============================================================================
-// Border Colors
-// ============================================================================
-
-/// Normal border color
-pub const BORDER_NORMAL: Color = Color::Rgb(116, 116, 116);
-
-/// Border color during processing/normal mode
-pub const BORDER_PROCESSING: Color = Color::Rgb(116, 116, 116);
-
-/// Border color for insert mode
-pub const BORDER_INSERT: Color = Color::Rgb(165, 165, 165);
-
-/// Border color for new task modal (green accent)
-pub const BORDER_NEW: Color = Color::Green;
-
-/// Border color for edit mode (blue accent)
-pub const BORDER_EDIT: Color = Color::Blue;
-
-/// Border color for confirmation/danger actions
-pub const BORDER_DANGER: Color = Color::Red;
-
-// ============================================================================
-// Semantic Colors
-// ============================================================================
-
-/// High priority color
-pub const PRIORITY_HIGH: Color = Color::Red;
-
-/// Medium priority color
-pub const PRIORITY_MEDIUM: Color = Color::Yellow;
-
-/// Low priority color
-pub const PRIORITY_LOW: Color = Color::Blue;
-
-/// No/default priority color
-pub const PRIORITY_NONE: Color = Color::Gray;
-
-/// Color for overdue dates
-pub const DATE_OVERDUE: Color = Color::Rgb(150, 80, 80);
-
-/// Color for normal dates
-pub const DATE_NORMAL: Color = Color::Rgb(100, 100, 100);
-
-// ============================================================================
-// Accent Colors
-// ============================================================================
-
-/// Yellow accent for labels and warnings
-pub const ACCENT_YELLOW: Color = Color::Yellow;
-
-// /// Cyan accent for examples and hints
-// pub const ACCENT_CYAN: Color = Color::Cyan;
-
-/// Green accent for success/confirmation
-pub const ACCENT_GREEN: Color = Color::Green;
-
-/// Red accent for errors/cancellation
-pub const ACCENT_RED: Color = Color::Red;
+/// Colors the UI is drawn with, set by the active theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    // ========================================================================
+    // Background Colors
+    // ========================================================================
+    /// Main background color for most UI elements
+    pub normal_bg: Color,
+    /// Alternate background color for visual variety
+    pub alt_bg: Color,
+    /// Background color for selected items and header
+    pub selected_bg: Color,
+
+    // ========================================================================
+    // Text/Foreground Colors
+    // ========================================================================
+    /// Standard text color
+    pub text_fg: Color,
+    /// Header text color
+    pub header_fg: Color,
+    /// White text for high contrast
+    pub text_white: Color,
+
+    // ========================================================================
+    // Border Colors
+    // ========================================================================
+    /// Normal border color
+    pub border_normal: Color,
+    /// Border color during processing/normal mode
+    pub border_processing: Color,
+    /// Border color for insert mode
+    pub border_insert: Color,
+    /// Border color for new task modal (green accent)
+    pub border_new: Color,
+    /// Border color for edit mode (blue accent)
+    pub border_edit: Color,
+    /// Border color for confirmation/danger actions
+    pub border_danger: Color,
+
+    // ========================================================================
+    // Semantic Colors
+    // ========================================================================
+    /// High priority color
+    pub priority_high: Color,
+    /// Medium priority color
+    pub priority_medium: Color,
+    /// Low priority color
+    pub priority_low: Color,
+    /// No/default priority color
+    pub priority_none: Color,
+    /// Color for overdue dates
+    pub date_overdue: Color,
+    /// Color for normal dates
+    pub date_normal: Color,
+    /// Color for tags shown under a task
+    pub tag_fg: Color,
+    /// Color for start dates that haven't come yet
+    pub date_not_started: Color,
+
+    // ========================================================================
+    // Accent Colors
+    // ========================================================================
+    /// Yellow accent for labels and warnings
+    pub accent_yellow: Color,
+    /// Green accent for success/confirmation
+    pub accent_green: Color,
+    /// Red accent for errors/cancellation
+    pub accent_red: Color,
+    /// Cyan accent for visual mode, help and status messages
+    pub accent_cyan: Color,
+}
+
+impl Palette {
+    /// Colors for dark terminals, the default
+    pub const DARK: Palette = Palette {
+        normal_bg: Color::Rgb(19, 19, 19),
+        alt_bg: Color::Rgb(25, 25, 25),
+        selected_bg: Color::Rgb(36, 36, 36),
+        text_fg: Color::Rgb(200, 200, 200),
+        header_fg: Color::Rgb(200, 200, 200),
+        text_white: Color::White,
+        border_normal: Color::Rgb(116, 116, 116),
+        border_processing: Color::Rgb(116, 116, 116),
+        border_insert: Color::Rgb(165, 165, 165),
+        border_new: Color::Green,
+        border_edit: Color::Blue,
+        border_danger: Color::Red,
+        priority_high: Color::Red,
+        priority_medium: Color::Yellow,
+        priority_low: Color::Blue,
+        priority_none: Color::Gray,
+        date_overdue: Color::Rgb(150, 80, 80),
+        date_normal: Color::Rgb(100, 100, 100),
+        tag_fg: Color::Rgb(90, 140, 160),
+        date_not_started: Color::Rgb(110, 120, 170),
+        accent_yellow: Color::Yellow,
+        accent_green: Color::Green,
+        accent_red: Color::Red,
+        accent_cyan: Color::Cyan,
+    };
+
+    /// Colors for light terminals
+    pub const LIGHT: Palette = Palette {
+        normal_bg: Color::Rgb(250, 250, 250),
+        alt_bg: Color::Rgb(240, 240, 240),
+        selected_bg: Color::Rgb(220, 226, 236),
+        text_fg: Color::Rgb(40, 40, 40),
+        header_fg: Color::Rgb(40, 40, 40),
+        text_white: Color::Black,
+        border_normal: Color::Rgb(170, 170, 170),
+        border_processing: Color::Rgb(170, 170, 170),
+        border_insert: Color::Rgb(90, 90, 90),
+        border_new: Color::Rgb(30, 140, 60),
+        border_edit: Color::Rgb(40, 90, 200),
+        border_danger: Color::Rgb(200, 40, 40),
+        priority_high: Color::Rgb(200, 40, 40),
+        priority_medium: Color::Rgb(190, 130, 0),
+        priority_low: Color::Rgb(40, 90, 200),
+        priority_none: Color::Rgb(120, 120, 120),
+        date_overdue: Color::Rgb(190, 50, 50),
+        date_normal: Color::Rgb(110, 110, 110),
+        tag_fg: Color::Rgb(30, 110, 140),
+        date_not_started: Color::Rgb(90, 100, 170),
+        accent_yellow: Color::Rgb(170, 110, 0),
+        accent_green: Color::Rgb(30, 140, 60),
+        accent_red: Color::Rgb(200, 40, 40),
+        accent_cyan: Color::Rgb(0, 130, 150),
+    };
+
+    /// Black and bright colors only
+    pub const HIGH_CONTRAST: Palette = Palette {
+        normal_bg: Color::Black,
+        alt_bg: Color::Black,
+        selected_bg: Color::Rgb(0, 60, 160),
+        text_fg: Color::White,
+        header_fg: Color::White,
+        text_white: Color::White,
+        border_normal: Color::White,
+        border_processing: Color::White,
+        border_insert: Color::LightYellow,
+        border_new: Color::LightGreen,
+        border_edit: Color::LightCyan,
+        border_danger: Color::LightRed,
+        priority_high: Color::LightRed,
+        priority_medium: Color::LightYellow,
+        priority_low: Color::LightCyan,
+        priority_none: Color::White,
+        date_overdue: Color::LightRed,
+        date_normal: Color::Gray,
+        tag_fg: Color::LightCyan,
+        date_not_started: Color::LightMagenta,
+        accent_yellow: Color::LightYellow,
+        accent_green: Color::LightGreen,
+        accent_red: Color::LightRed,
+        accent_cyan: Color::LightCyan,
+    };
+
+    /// A color by its name in config files, like `normal_bg`
+    pub fn color_mut(&mut self, name: &str) -> Option<&mut Color> {
+        let color = match name {
+            "normal_bg" => &mut self.normal_bg,
+            "alt_bg" => &mut self.alt_bg,
+            "selected_bg" => &mut self.selected_bg,
+            "text_fg" => &mut self.text_fg,
+            "header_fg" => &mut self.header_fg,
+            "text_white" => &mut self.text_white,
+            "border_normal" => &mut self.border_normal,
+            "border_processing" => &mut self.border_processing,
+            "border_insert" => &mut self.border_insert,
+            "border_new" => &mut self.border_new,
+            "border_edit" => &mut self.border_edit,
+            "border_danger" => &mut self.border_danger,
+            "priority_high" => &mut self.priority_high,
+            "priority_medium" => &mut self.priority_medium,
+            "priority_low" => &mut self.priority_low,
+            "priority_none" => &mut self.priority_none,
+            "date_overdue" => &mut self.date_overdue,
+            "date_normal" => &mut self.date_normal,
+            "tag_fg" => &mut self.tag_fg,
+            "date_not_started" => &mut self.date_not_started,
+            "accent_yellow" => &mut self.accent_yellow,
+            "accent_green" => &mut self.accent_green,
+            "accent_red" => &mut self.accent_red,
+            "accent_cyan" => &mut self.accent_cyan,
+            _ => return None,
+        };
+        Some(color)
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::DARK
+    }
+}
+
+/// A named palette
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub name: String,
+    pub colors: Palette,
+}
+
+impl Theme {
+    pub fn new(name: &str, colors: Palette) -> Self {
+        Self {
+            name: name.to_string(),
+            colors,
+        }
+    }
+
+    /// The themes that come with the app, dark first
+    pub fn built_in() -> Vec<Theme> {
+        vec![
+            Theme::new("dark", Palette::DARK),
+            Theme::new("light", Palette::LIGHT),
+            Theme::new("high-contrast", Palette::HIGH_CONTRAST),
+        ]
+    }
+}
@@ -1,14 +1,23 @@
 pub mod app_ui;
+pub mod checklist_editor;
 pub mod colors;
 pub mod modal;
+pub mod project_picker;
+pub mod tag_input;
 pub mod task_editor;
 pub mod task_list;
 pub mod tui;
 
 pub use app_ui::AppUI;
-pub use modal::{ConfirmationModal, ConfirmationType, PostponeModal, TaskModal};
-pub use task_editor::{InputField, TaskEditor};
-pub use task_list::{TaskList, ViewTab};
+pub use checklist_editor::ChecklistEditor;
+pub use modal::{
+    ConfirmationModal, ConfirmationType, MoveModal, PostponeModal, QueryModal, QuickAddModal,
+    TagModal, TagModalKind, TaskModal,
+};
+pub use project_picker::ProjectPicker;
+pub use tag_input::TagInput;
+pub use task_editor::{FieldValues, InputField, TaskEditor};
+pub use task_list::{TabSlot, TaskList, ViewTab};
 pub use tui::{Event, Tui};
 
 use ratatui::layout::{Constraint, Layout, Rect};
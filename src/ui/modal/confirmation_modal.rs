@@ -62,12 +62,12 @@ impl Modal for ConfirmationModal {
 
     // Uses default implementations for validate() and has_validation_errors()
 
-    fn render(&mut self, frame: &mut TuiFrame, area: Rect) {
+    fn render(&mut self, frame: &mut TuiFrame, area: Rect, palette: &Palette) {
         // Message content
         let message_lines: Vec<Line> = self
             .message
             .split('\n')
-            .map(|line| Line::from(Span::styled(line, Style::default().fg(TEXT_WHITE))))
+            .map(|line| Line::from(Span::styled(line, Style::default().fg(palette.text_white))))
             .collect();
 
         let instructions = vec![
@@ -76,13 +76,15 @@ impl Modal for ConfirmationModal {
                 Span::styled(
                     "y",
                     Style::default()
-                        .fg(ACCENT_GREEN)
+                        .fg(palette.accent_green)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(" to confirm, "),
                 Span::styled(
                     "n/Esc",
-                    Style::default().fg(ACCENT_RED).add_modifier(Modifier::BOLD),
+                    Style::default()
+                        .fg(palette.accent_red)
+                        .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(" to cancel"),
             ]),
@@ -117,13 +119,17 @@ impl Modal for ConfirmationModal {
         frame.render_widget(Clear, popup_area);
 
         let message_paragraph = Paragraph::new(all_lines)
-            .style(Style::default().fg(TEXT_WHITE).bg(NORMAL_BG))
+            .style(
+                Style::default()
+                    .fg(palette.text_white)
+                    .bg(palette.normal_bg),
+            )
             .alignment(Alignment::Center);
 
         // Choose border color based on confirmation type
         let border_color = match self.confirmation_type {
-            ConfirmationType::Delete => BORDER_DANGER,
-            ConfirmationType::Complete => ACCENT_GREEN,
+            ConfirmationType::Delete => palette.border_danger,
+            ConfirmationType::Complete => palette.accent_green,
         };
 
         // Render the modal border
@@ -131,7 +137,7 @@ impl Modal for ConfirmationModal {
             .title(self.title.as_str())
             .borders(Borders::ALL)
             .border_style(Style::default().fg(border_color))
-            .style(Style::default().bg(NORMAL_BG));
+            .style(Style::default().bg(palette.normal_bg));
 
         let inner_area = modal_block.inner(popup_area);
         frame.render_widget(modal_block, popup_area);
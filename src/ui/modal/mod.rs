@@ -2,6 +2,7 @@ use anyhow::Result;
 use crossterm::event::KeyEvent;
 use ratatui::layout::Rect;
 
+use super::colors::Palette;
 use super::tui::Frame as TuiFrame;
 
 /// Trait for modal dialogs that can be displayed as overlays
@@ -17,7 +18,7 @@ pub trait Modal {
     fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool>;
 
     /// Render the modal content
-    fn render(&mut self, frame: &mut TuiFrame, area: Rect);
+    fn render(&mut self, frame: &mut TuiFrame, area: Rect, palette: &Palette);
 
     /// Get the modal's input values (if any)
     fn get_values(&self) -> Vec<String>;
@@ -40,9 +41,17 @@ pub trait Modal {
 }
 
 pub mod confirmation_modal;
+pub mod move_modal;
 pub mod postpone_modal;
+pub mod query_modal;
+pub mod quick_add_modal;
+pub mod tag_modal;
 pub mod task_modal;
 
 pub use confirmation_modal::{ConfirmationModal, ConfirmationType};
+pub use move_modal::MoveModal;
 pub use postpone_modal::PostponeModal;
+pub use query_modal::QueryModal;
+pub use quick_add_modal::QuickAddModal;
+pub use tag_modal::{TagModal, TagModalKind};
 pub use task_modal::TaskModal;
@@ -0,0 +1,148 @@
+use anyhow::Result;
+use crossterm::event::KeyEvent;
+use edtui::EditorMode;
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::super::colors::*;
+use super::super::tui::Frame as TuiFrame;
+use super::super::{centered_rect, ProjectPicker};
+use super::Modal;
+use crate::backend::ProjectInfo;
+
+/// Modal for moving the selected tasks to another project
+pub struct MoveModal {
+    title: String,
+    project_picker: ProjectPicker,
+}
+
+impl MoveModal {
+    pub fn new(title: &str, projects: Vec<ProjectInfo>) -> Self {
+        let mut modal = Self {
+            title: title.to_string(),
+            project_picker: ProjectPicker::new(projects),
+        };
+        modal.project_picker.input.mode = EditorMode::Insert;
+        modal
+    }
+
+    fn is_editor_in_insert_mode(&self) -> bool {
+        self.project_picker.input.mode == EditorMode::Insert
+    }
+}
+
+impl Modal for MoveModal {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Esc => {
+                // If editor is in insert mode, switch to normal mode
+                if self.is_editor_in_insert_mode() {
+                    self.project_picker.input.mode = EditorMode::Normal;
+                    Ok(true)
+                } else {
+                    // Already in normal mode, let app handle it (close modal)
+                    Ok(false)
+                }
+            }
+            // Single-line input, so Enter always submits
+            KeyCode::Enter => Ok(false),
+            _ => {
+                self.project_picker.handle_key_event(key_event);
+                Ok(true)
+            }
+        }
+    }
+
+    fn render(&mut self, frame: &mut TuiFrame, area: Rect, palette: &Palette) {
+        let popup_area = centered_rect(50, 25, area);
+        frame.render_widget(Clear, popup_area);
+
+        // Render background
+        let bg_block =
+            Block::default().style(Style::default().bg(palette.normal_bg).fg(palette.text_fg));
+        frame.render_widget(bg_block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(4), // Project field + matches
+                Constraint::Min(0),
+                Constraint::Length(1), // Help text
+            ])
+            .split(popup_area);
+
+        let border_color = if self.is_editor_in_insert_mode() {
+            palette.border_new
+        } else {
+            palette.border_processing
+        };
+        self.project_picker
+            .render(frame, chunks[0], border_color, true, palette);
+
+        // Help text at bottom of modal (matching TaskModal style)
+        let help_text = vec![Line::from(vec![
+            Span::styled("Enter", Style::default().fg(palette.accent_green)),
+            Span::raw(" move  •  "),
+            Span::styled("C-n/C-p", Style::default().fg(palette.accent_yellow)),
+            Span::raw(" choose  •  "),
+            Span::styled("Esc", Style::default().fg(palette.accent_red)),
+            Span::raw(" cancel"),
+        ])];
+        let help_paragraph = Paragraph::new(help_text)
+            .style(Style::default().bg(palette.normal_bg))
+            .alignment(Alignment::Center);
+        frame.render_widget(help_paragraph, chunks[2]);
+
+        let modal_block = Block::default()
+            .title(self.title.as_str())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(palette.border_edit));
+        frame.render_widget(modal_block, popup_area);
+    }
+
+    /// The chosen project's id
+    fn get_values(&self) -> Vec<String> {
+        vec![self
+            .project_picker
+            .selected()
+            .map(|project| project.id.0.clone())
+            .unwrap_or_default()]
+    }
+
+    fn clear_inputs(&mut self) {
+        self.project_picker.set_query("");
+    }
+
+    fn set_values(&mut self, values: Vec<String>) {
+        if let Some(value) = values.into_iter().next() {
+            self.project_picker.set_query(&value);
+        }
+    }
+
+    fn validate(&mut self) -> bool {
+        if self.project_picker.query().trim().is_empty() {
+            self.project_picker.error = Some("Choose a project".to_string());
+            return false;
+        }
+        self.project_picker.validate()
+    }
+
+    fn has_validation_errors(&self) -> bool {
+        self.project_picker.error.is_some()
+    }
+}
@@ -283,12 +283,13 @@ impl Modal for PostponeModal {
         }
     }
 
-    fn render(&mut self, frame: &mut TuiFrame, area: Rect) {
+    fn render(&mut self, frame: &mut TuiFrame, area: Rect, palette: &Palette) {
         let popup_area = centered_rect(45, 15, area);
         frame.render_widget(Clear, popup_area);
 
         // Render background
-        let bg_block = Block::default().style(Style::default().bg(NORMAL_BG));
+        let bg_block =
+            Block::default().style(Style::default().bg(palette.normal_bg).fg(palette.text_fg));
         frame.render_widget(bg_block, popup_area);
 
         let chunks = Layout::default()
@@ -311,30 +312,30 @@ impl Modal for PostponeModal {
 
         let duration_has_error = self.validation_attempted && self.duration_error.is_some();
         let duration_border_color = if duration_has_error {
-            ACCENT_RED
+            palette.accent_red
         } else if self.is_editor_in_insert_mode() {
             if self.is_edit_mode {
-                BORDER_EDIT
+                palette.border_edit
             } else {
-                BORDER_NEW
+                palette.border_new
             }
         } else {
-            BORDER_PROCESSING
+            palette.border_processing
         };
 
         let duration_block = Block::default()
             .title("Duration (e.g., \"5min\", \"2 hours\", \"1day\", \"now\", \"now + 30min\")")
             .borders(Borders::ALL)
             .border_style(Style::default().fg(duration_border_color))
-            .style(Style::default().bg(NORMAL_BG));
+            .style(Style::default().bg(palette.normal_bg));
 
         let duration_inner = duration_block.inner(duration_field_layout[0]);
         frame.render_widget(duration_block, duration_field_layout[0]);
 
         let duration_theme = EditorTheme::default()
-            .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
-            .cursor_style(Style::default().bg(TEXT_FG).fg(NORMAL_BG))
-            .selection_style(Style::default().bg(SELECTED_BG).fg(TEXT_FG))
+            .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+            .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+            .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
             .hide_status_line();
         let duration_editor_view =
             EditorView::new(&mut self.input_duration_editor).theme(duration_theme);
@@ -342,29 +343,32 @@ impl Modal for PostponeModal {
 
         // Render duration error message if present
         if let Some(error) = &self.duration_error {
-            let error_paragraph =
-                Paragraph::new(error.as_str()).style(Style::default().fg(ACCENT_RED).bg(NORMAL_BG));
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
             frame.render_widget(error_paragraph, duration_field_layout[1]);
         }
 
         // Help text at bottom of modal (matching TaskModal style)
         let help_text = vec![Line::from(vec![
-            Span::styled("Enter", Style::default().fg(ACCENT_GREEN)),
+            Span::styled("Enter", Style::default().fg(palette.accent_green)),
             Span::raw(" confirm  •  "),
-            Span::styled("Esc", Style::default().fg(ACCENT_RED)),
+            Span::styled("Esc", Style::default().fg(palette.accent_red)),
             Span::raw(" cancel"),
         ])];
         let help_paragraph = Paragraph::new(help_text)
-            .style(Style::default().bg(NORMAL_BG))
+            .style(Style::default().bg(palette.normal_bg))
             .alignment(Alignment::Center);
 
         frame.render_widget(help_paragraph, chunks[1]);
 
         // Render the modal border
         let modal_border_color = if self.is_edit_mode {
-            BORDER_EDIT
+            palette.border_edit
         } else {
-            BORDER_NEW
+            palette.border_new
         };
         let modal_block = Block::default()
             .title(self.title.as_str())
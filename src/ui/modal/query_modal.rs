@@ -0,0 +1,272 @@
+use anyhow::Result;
+use crossterm::event::KeyEvent;
+use edtui::{EditorEventHandler, EditorMode, EditorState, EditorTheme, EditorView, Index2, Lines};
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::super::centered_rect;
+use super::super::colors::*;
+use super::super::tui::Frame as TuiFrame;
+use super::Modal;
+use crate::query::Query;
+
+/// Name and filter of a saved query tab, e.g. `due:<=+3d tag:work`
+pub struct QueryModal {
+    title: String,
+    name_editor: EditorState,
+    query_editor: EditorState,
+    /// Whether the query field has focus rather than the name
+    query_focused: bool,
+    event_handler: EditorEventHandler,
+    name_error: Option<String>,
+    query_error: Option<String>,
+}
+
+impl QueryModal {
+    pub fn new(title: &str, name: &str, query: &str) -> Self {
+        let mut modal = Self {
+            title: title.to_string(),
+            name_editor: EditorState::default(),
+            query_editor: EditorState::default(),
+            // New queries start at the name, edits at the query
+            query_focused: !name.is_empty(),
+            event_handler: EditorEventHandler::default(),
+            name_error: None,
+            query_error: None,
+        };
+        modal.set_values(vec![name.to_string(), query.to_string()]);
+        modal
+    }
+
+    fn editor_with(text: &str) -> EditorState {
+        let mut editor = EditorState::new(Lines::from(text.to_string()));
+        editor.mode = EditorMode::Insert;
+        editor.cursor = Index2::new(0, text.chars().count());
+        editor
+    }
+
+    fn focused_editor(&mut self) -> &mut EditorState {
+        if self.query_focused {
+            &mut self.query_editor
+        } else {
+            &mut self.name_editor
+        }
+    }
+
+    fn render_field(
+        frame: &mut TuiFrame,
+        area: Rect,
+        title: &str,
+        editor: &mut EditorState,
+        focused: bool,
+        has_error: bool,
+        palette: &Palette,
+    ) {
+        let border_color = if has_error {
+            palette.accent_red
+        } else if focused && editor.mode == EditorMode::Insert {
+            palette.border_new
+        } else if focused {
+            palette.border_processing
+        } else {
+            palette.border_normal
+        };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color))
+            .style(Style::default().bg(palette.normal_bg));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let mut theme = EditorTheme::default()
+            .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+            .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+            .hide_status_line();
+        theme = if focused {
+            theme.cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+        } else {
+            theme.hide_cursor()
+        };
+        frame.render_widget(EditorView::new(editor).theme(theme), inner);
+    }
+}
+
+impl Modal for QueryModal {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Esc => {
+                let editor = self.focused_editor();
+                if editor.mode == EditorMode::Insert {
+                    editor.mode = EditorMode::Normal;
+                    Ok(true)
+                } else {
+                    // Already in normal mode, let app handle it (close modal)
+                    Ok(false)
+                }
+            }
+            // Single-line fields, so Enter always submits
+            KeyCode::Enter => Ok(false),
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.query_focused = !self.query_focused;
+                self.focused_editor().mode = EditorMode::Insert;
+                Ok(true)
+            }
+            _ => {
+                if self.query_focused {
+                    self.event_handler
+                        .on_key_event(key_event, &mut self.query_editor);
+                    self.query_error = None;
+                } else {
+                    self.event_handler
+                        .on_key_event(key_event, &mut self.name_editor);
+                    self.name_error = None;
+                }
+                Ok(true)
+            }
+        }
+    }
+
+    fn render(&mut self, frame: &mut TuiFrame, area: Rect, palette: &Palette) {
+        let popup_area = centered_rect(60, 40, area);
+        frame.render_widget(Clear, popup_area);
+
+        // Render background
+        let bg_block =
+            Block::default().style(Style::default().bg(palette.normal_bg).fg(palette.text_fg));
+        frame.render_widget(bg_block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3), // Name
+                Constraint::Length(3), // Query
+                Constraint::Length(1), // Error message
+                Constraint::Min(4),    // Syntax
+                Constraint::Length(1), // Help text
+            ])
+            .split(popup_area);
+
+        Self::render_field(
+            frame,
+            chunks[0],
+            "Name",
+            &mut self.name_editor,
+            !self.query_focused,
+            self.name_error.is_some(),
+            palette,
+        );
+        Self::render_field(
+            frame,
+            chunks[1],
+            "Query (e.g. \"due:<=+3d priority:>=medium tag:work -project:Personal\")",
+            &mut self.query_editor,
+            self.query_focused,
+            self.query_error.is_some(),
+            palette,
+        );
+
+        if let Some(error) = self.name_error.as_ref().or(self.query_error.as_ref()) {
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
+            frame.render_widget(error_paragraph, chunks[2]);
+        }
+
+        let hint = |text: &'static str| {
+            Line::from(Span::styled(
+                text,
+                Style::default()
+                    .fg(palette.date_normal)
+                    .add_modifier(Modifier::ITALIC),
+            ))
+        };
+        let syntax = Paragraph::new(vec![
+            hint("due:today  due:<=+3d  due:>-1w  due:12/25  due:none"),
+            hint("priority:high  priority:>=medium  tag:work  project:\"Side projects\""),
+            hint("Plain words search titles and notes, -term leaves matches out"),
+        ])
+        .style(Style::default().bg(palette.normal_bg));
+        frame.render_widget(syntax, chunks[3]);
+
+        // Help text at bottom of modal (matching TaskModal style)
+        let help_text = vec![Line::from(vec![
+            Span::styled("Enter", Style::default().fg(palette.accent_green)),
+            Span::raw(" save  •  "),
+            Span::styled("Tab", Style::default().fg(palette.accent_yellow)),
+            Span::raw(" next field  •  "),
+            Span::styled("Esc", Style::default().fg(palette.accent_red)),
+            Span::raw(" cancel"),
+        ])];
+        let help_paragraph = Paragraph::new(help_text)
+            .style(Style::default().bg(palette.normal_bg))
+            .alignment(Alignment::Center);
+        frame.render_widget(help_paragraph, chunks[4]);
+
+        let modal_block = Block::default()
+            .title(self.title.as_str())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(palette.border_new));
+        frame.render_widget(modal_block, popup_area);
+    }
+
+    /// The name and the query text
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            String::from(self.name_editor.lines.clone())
+                .trim()
+                .to_string(),
+            String::from(self.query_editor.lines.clone())
+                .trim()
+                .to_string(),
+        ]
+    }
+
+    fn clear_inputs(&mut self) {
+        self.set_values(vec![String::new(), String::new()]);
+    }
+
+    fn set_values(&mut self, values: Vec<String>) {
+        let mut values = values.into_iter();
+        self.name_editor = Self::editor_with(&values.next().unwrap_or_default());
+        self.query_editor = Self::editor_with(&values.next().unwrap_or_default());
+        self.name_error = None;
+        self.query_error = None;
+    }
+
+    fn validate(&mut self) -> bool {
+        let values = self.get_values();
+        self.name_error = if values[0].is_empty() {
+            Some("Name cannot be empty".to_string())
+        } else {
+            None
+        };
+        self.query_error = if values[1].is_empty() {
+            Some("Query cannot be empty".to_string())
+        } else {
+            Query::parse(&values[1]).err()
+        };
+        self.name_error.is_none() && self.query_error.is_none()
+    }
+
+    fn has_validation_errors(&self) -> bool {
+        self.name_error.is_some() || self.query_error.is_some()
+    }
+}
@@ -0,0 +1,299 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use crossterm::event::KeyEvent;
+use edtui::{EditorEventHandler, EditorMode, EditorState, EditorTheme, EditorView};
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use ticks::tasks::TaskPriority;
+
+use super::super::centered_rect;
+use super::super::colors::*;
+use super::super::tui::Frame as TuiFrame;
+use super::Modal;
+use crate::{
+    backend::ProjectInfo,
+    quick_add::{find_project, parse_quick_add},
+};
+
+/// Single-line task entry, e.g. `Call vendor tomorrow 3pm !high #billing ^Work`
+pub struct QuickAddModal {
+    title: String,
+    input_editor: EditorState,
+    event_handler: EditorEventHandler,
+    /// Projects that `^name` can refer to
+    projects: Vec<ProjectInfo>,
+    /// Date used when the line doesn't contain one
+    default_date: Option<NaiveDate>,
+    validation_attempted: bool,
+    input_error: Option<String>,
+}
+
+impl QuickAddModal {
+    pub fn new(title: &str, projects: Vec<ProjectInfo>, default_date: Option<NaiveDate>) -> Self {
+        let mut modal = Self {
+            title: title.to_string(),
+            input_editor: EditorState::default(),
+            event_handler: EditorEventHandler::default(),
+            projects,
+            default_date,
+            validation_attempted: false,
+            input_error: None,
+        };
+        modal.input_editor.mode = EditorMode::Insert;
+        modal
+    }
+
+    pub fn get_input(&self) -> String {
+        String::from(self.input_editor.lines.clone())
+    }
+
+    fn is_editor_in_insert_mode(&self) -> bool {
+        self.input_editor.mode == EditorMode::Insert
+    }
+
+    /// One line per parsed field, shown while typing
+    fn preview_lines(&self, palette: &Palette) -> Vec<Line<'static>> {
+        let parsed = parse_quick_add(&self.get_input());
+        let label = |text: &str| {
+            Span::styled(
+                format!("{:<10}", text),
+                Style::default().fg(palette.accent_yellow),
+            )
+        };
+        let value = |text: String| Span::styled(text, Style::default().fg(palette.text_fg));
+        let unset = |text: &str| {
+            Span::styled(
+                text.to_string(),
+                Style::default()
+                    .fg(palette.date_normal)
+                    .add_modifier(Modifier::ITALIC),
+            )
+        };
+
+        let title = if parsed.title.is_empty() {
+            unset("(empty)")
+        } else {
+            value(parsed.title.clone())
+        };
+
+        let due = match (parsed.due_date(self.default_date), parsed.time) {
+            (Some(date), Some(time)) => value(format!(
+                "{} {}",
+                date.format("%a %m/%d/%Y"),
+                time.format("%I:%M %p")
+            )),
+            (Some(date), None) => value(date.format("%a %m/%d/%Y (all day)").to_string()),
+            _ => unset("none"),
+        };
+
+        let priority = match parsed.priority {
+            Some(TaskPriority::High) => {
+                Span::styled("High", Style::default().fg(palette.priority_high))
+            }
+            Some(TaskPriority::Medium) => {
+                Span::styled("Medium", Style::default().fg(palette.priority_medium))
+            }
+            Some(TaskPriority::Low) => {
+                Span::styled("Low", Style::default().fg(palette.priority_low))
+            }
+            Some(TaskPriority::None) | None => unset("none"),
+        };
+
+        let tags = if parsed.tags.is_empty() {
+            unset("none")
+        } else {
+            value(
+                parsed
+                    .tags
+                    .iter()
+                    .map(|tag| format!("#{}", tag))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+        };
+
+        let project = match &parsed.project {
+            Some(name) => match find_project(&self.projects, name) {
+                Some(project) => value(project.name.clone()),
+                None => Span::styled(
+                    format!("{} (not found)", name),
+                    Style::default().fg(palette.accent_red),
+                ),
+            },
+            None => unset("Inbox"),
+        };
+
+        vec![
+            Line::from(vec![label("Title"), title]),
+            Line::from(vec![label("Due"), due]),
+            Line::from(vec![label("Priority"), priority]),
+            Line::from(vec![label("Tags"), tags]),
+            Line::from(vec![label("Project"), project]),
+        ]
+    }
+}
+
+impl Modal for QuickAddModal {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Esc => {
+                // If editor is in insert mode, switch to normal mode
+                if self.is_editor_in_insert_mode() {
+                    self.input_editor.mode = EditorMode::Normal;
+                    Ok(true)
+                } else {
+                    // Already in normal mode, let app handle it (close modal)
+                    Ok(false)
+                }
+            }
+            // Single-line input, so Enter always submits
+            KeyCode::Enter => Ok(false),
+            _ => {
+                self.event_handler
+                    .on_key_event(key_event, &mut self.input_editor);
+                // Clear validation errors when user edits the field
+                if self.validation_attempted {
+                    self.input_error = None;
+                }
+                Ok(true)
+            }
+        }
+    }
+
+    fn render(&mut self, frame: &mut TuiFrame, area: Rect, palette: &Palette) {
+        let popup_area = centered_rect(60, 40, area);
+        frame.render_widget(Clear, popup_area);
+
+        // Render background
+        let bg_block =
+            Block::default().style(Style::default().bg(palette.normal_bg).fg(palette.text_fg));
+        frame.render_widget(bg_block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3), // Input
+                Constraint::Length(1), // Error message
+                Constraint::Min(7),    // Preview
+                Constraint::Length(1), // Help text
+            ])
+            .split(popup_area);
+
+        let input_border_color = if self.validation_attempted && self.input_error.is_some() {
+            palette.accent_red
+        } else if self.is_editor_in_insert_mode() {
+            palette.border_new
+        } else {
+            palette.border_processing
+        };
+        let input_block = Block::default()
+            .title("Task (e.g. \"Call vendor tomorrow 3pm !high #billing ^Work\")")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(input_border_color))
+            .style(Style::default().bg(palette.normal_bg));
+        let input_inner = input_block.inner(chunks[0]);
+        frame.render_widget(input_block, chunks[0]);
+
+        let input_theme = EditorTheme::default()
+            .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+            .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+            .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+            .hide_status_line();
+        frame.render_widget(
+            EditorView::new(&mut self.input_editor).theme(input_theme),
+            input_inner,
+        );
+
+        if let Some(error) = &self.input_error {
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
+            frame.render_widget(error_paragraph, chunks[1]);
+        }
+
+        let preview = Paragraph::new(self.preview_lines(palette)).block(
+            Block::default()
+                .title("Preview")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(palette.border_normal))
+                .style(Style::default().bg(palette.normal_bg)),
+        );
+        frame.render_widget(preview, chunks[2]);
+
+        // Help text at bottom of modal (matching TaskModal style)
+        let help_text = vec![Line::from(vec![
+            Span::styled("Enter", Style::default().fg(palette.accent_green)),
+            Span::raw(" create  •  "),
+            Span::styled("Esc", Style::default().fg(palette.accent_red)),
+            Span::raw(" cancel  •  "),
+            Span::styled(
+                "!high #tag ^Project",
+                Style::default().fg(palette.accent_yellow),
+            ),
+        ])];
+        let help_paragraph = Paragraph::new(help_text)
+            .style(Style::default().bg(palette.normal_bg))
+            .alignment(Alignment::Center);
+        frame.render_widget(help_paragraph, chunks[3]);
+
+        let modal_block = Block::default()
+            .title(self.title.as_str())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(palette.border_new));
+        frame.render_widget(modal_block, popup_area);
+    }
+
+    fn get_values(&self) -> Vec<String> {
+        vec![self.get_input()]
+    }
+
+    fn clear_inputs(&mut self) {
+        self.input_editor = EditorState::default();
+        self.validation_attempted = false;
+        self.input_error = None;
+    }
+
+    fn set_values(&mut self, values: Vec<String>) {
+        if let Some(value) = values.into_iter().next() {
+            self.input_editor = EditorState::new(edtui::Lines::from(value));
+        }
+    }
+
+    fn validate(&mut self) -> bool {
+        self.validation_attempted = true;
+        let parsed = parse_quick_add(&self.get_input());
+
+        self.input_error = if parsed.title.is_empty() {
+            Some("Title cannot be empty".to_string())
+        } else if let Some(name) = parsed
+            .project
+            .filter(|name| find_project(&self.projects, name).is_none())
+        {
+            Some(format!("No project named \"{}\"", name))
+        } else {
+            None
+        };
+        self.input_error.is_none()
+    }
+
+    fn has_validation_errors(&self) -> bool {
+        self.input_error.is_some()
+    }
+}
@@ -0,0 +1,150 @@
+use anyhow::Result;
+use crossterm::event::KeyEvent;
+use edtui::EditorMode;
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::super::colors::*;
+use super::super::tui::Frame as TuiFrame;
+use super::super::{centered_rect, TagInput};
+use super::Modal;
+use crate::quick_add::format_tags;
+
+/// What the tags entered in a [`TagModal`] are used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagModalKind {
+    /// Set the tags of the selected tasks
+    SetTags,
+    /// Only show tasks with these tags
+    Filter,
+}
+
+/// Modal for entering tags, with completion from the tags already in use
+pub struct TagModal {
+    title: String,
+    kind: TagModalKind,
+    tag_input: TagInput,
+}
+
+impl TagModal {
+    pub fn new(title: &str, kind: TagModalKind, known_tags: Vec<String>, tags: &[String]) -> Self {
+        let mut tag_input = TagInput::new(known_tags);
+        tag_input.set_text(&format_tags(tags));
+        tag_input.input.mode = EditorMode::Insert;
+        tag_input.position_cursor_at_end();
+        Self {
+            title: title.to_string(),
+            kind,
+            tag_input,
+        }
+    }
+
+    pub fn kind(&self) -> TagModalKind {
+        self.kind
+    }
+
+    fn is_editor_in_insert_mode(&self) -> bool {
+        self.tag_input.input.mode == EditorMode::Insert
+    }
+}
+
+impl Modal for TagModal {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Esc => {
+                // If editor is in insert mode, switch to normal mode
+                if self.is_editor_in_insert_mode() {
+                    self.tag_input.input.mode = EditorMode::Normal;
+                    Ok(true)
+                } else {
+                    // Already in normal mode, let app handle it (close modal)
+                    Ok(false)
+                }
+            }
+            // Single-line input, so Enter always submits
+            KeyCode::Enter => Ok(false),
+            _ => {
+                self.tag_input.handle_key_event(key_event);
+                Ok(true)
+            }
+        }
+    }
+
+    fn render(&mut self, frame: &mut TuiFrame, area: Rect, palette: &Palette) {
+        let popup_area = centered_rect(50, 25, area);
+        frame.render_widget(Clear, popup_area);
+
+        // Render background
+        let bg_block =
+            Block::default().style(Style::default().bg(palette.normal_bg).fg(palette.text_fg));
+        frame.render_widget(bg_block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(4), // Tags field + suggestions
+                Constraint::Min(0),
+                Constraint::Length(1), // Help text
+            ])
+            .split(popup_area);
+
+        let border_color = if self.is_editor_in_insert_mode() {
+            palette.border_new
+        } else {
+            palette.border_processing
+        };
+        self.tag_input
+            .render(frame, chunks[0], border_color, true, palette);
+
+        let confirm = match self.kind {
+            TagModalKind::SetTags => " save  •  ",
+            TagModalKind::Filter => " filter (empty clears)  •  ",
+        };
+        let help_text = vec![Line::from(vec![
+            Span::styled("Enter", Style::default().fg(palette.accent_green)),
+            Span::raw(confirm),
+            Span::styled("C-n/C-p", Style::default().fg(palette.accent_yellow)),
+            Span::raw(" complete  •  "),
+            Span::styled("Esc", Style::default().fg(palette.accent_red)),
+            Span::raw(" cancel"),
+        ])];
+        let help_paragraph = Paragraph::new(help_text)
+            .style(Style::default().bg(palette.normal_bg))
+            .alignment(Alignment::Center);
+        frame.render_widget(help_paragraph, chunks[2]);
+
+        let modal_block = Block::default()
+            .title(self.title.as_str())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(palette.border_edit));
+        frame.render_widget(modal_block, popup_area);
+    }
+
+    /// The entered tags, one per value
+    fn get_values(&self) -> Vec<String> {
+        self.tag_input.tags()
+    }
+
+    fn clear_inputs(&mut self) {
+        self.tag_input.set_text("");
+    }
+
+    fn set_values(&mut self, values: Vec<String>) {
+        self.tag_input.set_text(&format_tags(&values));
+    }
+}
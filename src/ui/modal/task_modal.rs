@@ -1,5 +1,5 @@
 use anyhow::Result;
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyEvent, KeyModifiers};
 use edtui::{EditorEventHandler, EditorMode, EditorState, EditorTheme, EditorView, Index2, Lines};
 use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
@@ -10,8 +10,13 @@ use ratatui::{
 
 use super::super::colors::*;
 use super::super::tui::Frame as TuiFrame;
-use super::super::{centered_rect, InputField};
+use super::super::{centered_rect, InputField, ProjectPicker, TagInput};
 use super::Modal;
+use crate::backend::ProjectInfo;
+use crate::quick_add::{format_tags, parse_priority_field};
+use crate::recurrence::parse_recurrence;
+use crate::reminders::validate_reminders;
+use crate::tasks::validate_start;
 
 /// Modal for creating and editing tasks
 pub struct TaskModal {
@@ -20,6 +25,12 @@ pub struct TaskModal {
     input_description_editor: EditorState,
     input_date_editor: EditorState,
     input_time_editor: EditorState,
+    input_start_editor: EditorState,
+    input_reminders_editor: EditorState,
+    input_priority_editor: EditorState,
+    input_repeat_editor: EditorState,
+    project_picker: ProjectPicker,
+    tag_input: TagInput,
     current_input_field: InputField,
     event_handler: EditorEventHandler,
     is_edit_mode: bool,
@@ -28,6 +39,10 @@ pub struct TaskModal {
     validation_attempted: bool,
     date_error: Option<String>,
     time_error: Option<String>,
+    start_error: Option<String>,
+    reminders_error: Option<String>,
+    priority_error: Option<String>,
+    repeat_error: Option<String>,
 }
 
 impl TaskModal {
@@ -38,6 +53,12 @@ impl TaskModal {
             input_description_editor: EditorState::default(),
             input_date_editor: EditorState::default(),
             input_time_editor: EditorState::default(),
+            input_start_editor: EditorState::default(),
+            input_reminders_editor: EditorState::default(),
+            input_priority_editor: EditorState::default(),
+            input_repeat_editor: EditorState::default(),
+            project_picker: ProjectPicker::new(Vec::new()),
+            tag_input: TagInput::new(Vec::new()),
             current_input_field: InputField::Title,
             event_handler: EditorEventHandler::default(),
             is_edit_mode: false,
@@ -45,6 +66,10 @@ impl TaskModal {
             validation_attempted: false,
             date_error: None,
             time_error: None,
+            start_error: None,
+            reminders_error: None,
+            priority_error: None,
+            repeat_error: None,
         }
     }
 
@@ -90,12 +115,59 @@ impl TaskModal {
         modal
     }
 
+    /// Projects the task can be moved to, starting with the named one (if any)
+    pub fn with_projects(mut self, projects: Vec<ProjectInfo>, current: Option<&str>) -> Self {
+        self.project_picker.set_projects(projects);
+        if let Some(name) = current {
+            self.project_picker.set_query(name);
+            self.project_picker.position_cursor_at_end();
+        }
+        self
+    }
+
+    /// Start the priority field at the given priority name
+    pub fn with_priority(mut self, priority: &str) -> Self {
+        self.input_priority_editor = EditorState::new(Lines::from(priority.to_string()));
+        self
+    }
+
+    /// Start the repeat field at the given friendly recurrence text
+    pub fn with_repeat(mut self, repeat: &str) -> Self {
+        self.input_repeat_editor = EditorState::new(Lines::from(repeat.to_string()));
+        self
+    }
+
+    /// Start the start field at the given start date text
+    pub fn with_start(mut self, start: &str) -> Self {
+        self.input_start_editor = EditorState::new(Lines::from(start.to_string()));
+        self
+    }
+
+    /// Start the reminders field at the given friendly reminder text
+    pub fn with_reminders(mut self, reminders: &str) -> Self {
+        self.input_reminders_editor = EditorState::new(Lines::from(reminders.to_string()));
+        self
+    }
+
+    /// Tags to complete from, and the task's current tags
+    pub fn with_tags(mut self, known_tags: Vec<String>, tags: &[String]) -> Self {
+        self.tag_input.set_known_tags(known_tags);
+        self.tag_input.set_text(&format_tags(tags));
+        self
+    }
+
     fn get_current_editor_mut(&mut self) -> &mut EditorState {
         match self.current_input_field {
             InputField::Title => &mut self.input_title_editor,
             InputField::Description => &mut self.input_description_editor,
             InputField::Date => &mut self.input_date_editor,
             InputField::Time => &mut self.input_time_editor,
+            InputField::Start => &mut self.input_start_editor,
+            InputField::Reminders => &mut self.input_reminders_editor,
+            InputField::Project => &mut self.project_picker.input,
+            InputField::Repeat => &mut self.input_repeat_editor,
+            InputField::Priority => &mut self.input_priority_editor,
+            InputField::Tags => &mut self.tag_input.input,
         }
     }
 
@@ -105,16 +177,17 @@ impl TaskModal {
             InputField::Description => &self.input_description_editor,
             InputField::Date => &self.input_date_editor,
             InputField::Time => &self.input_time_editor,
+            InputField::Start => &self.input_start_editor,
+            InputField::Reminders => &self.input_reminders_editor,
+            InputField::Project => &self.project_picker.input,
+            InputField::Repeat => &self.input_repeat_editor,
+            InputField::Priority => &self.input_priority_editor,
+            InputField::Tags => &self.tag_input.input,
         }
     }
 
     pub fn next_input_field(&mut self) {
-        self.current_input_field = match self.current_input_field {
-            InputField::Title => InputField::Date,
-            InputField::Date => InputField::Time,
-            InputField::Time => InputField::Description,
-            InputField::Description => InputField::Title,
-        };
+        self.current_input_field = self.current_input_field.next();
         // Ensure mode is preserved when switching fields
         if self.is_edit_mode {
             self.set_current_editor_to_normal_mode();
@@ -122,12 +195,7 @@ impl TaskModal {
     }
 
     pub fn previous_input_field(&mut self) {
-        self.current_input_field = match self.current_input_field {
-            InputField::Title => InputField::Description,
-            InputField::Date => InputField::Title,
-            InputField::Time => InputField::Date,
-            InputField::Description => InputField::Time,
-        };
+        self.current_input_field = self.current_input_field.previous();
         // Ensure mode is preserved when switching fields
         if self.is_edit_mode {
             self.set_current_editor_to_normal_mode();
@@ -149,6 +217,12 @@ impl TaskModal {
         self.input_description_editor.mode = EditorMode::Normal;
         self.input_date_editor.mode = EditorMode::Normal;
         self.input_time_editor.mode = EditorMode::Normal;
+        self.input_start_editor.mode = EditorMode::Normal;
+        self.input_reminders_editor.mode = EditorMode::Normal;
+        self.project_picker.input.mode = EditorMode::Normal;
+        self.input_priority_editor.mode = EditorMode::Normal;
+        self.input_repeat_editor.mode = EditorMode::Normal;
+        self.tag_input.input.mode = EditorMode::Normal;
     }
 
     pub fn position_cursor_at_end(&mut self) {
@@ -292,36 +366,14 @@ impl TaskModal {
         }
 
         // Move to next field (no wraparound)
-        match self.current_input_field {
-            InputField::Title => {
-                self.current_input_field = InputField::Date;
-                self.position_cursor_at_desired_column(0);
-                if self.is_edit_mode {
-                    self.set_current_editor_to_normal_mode();
-                }
-                true
-            }
-            InputField::Date => {
-                self.current_input_field = InputField::Time;
-                self.position_cursor_at_desired_column(0);
-                if self.is_edit_mode {
-                    self.set_current_editor_to_normal_mode();
-                }
-                true
-            }
-            InputField::Time => {
-                self.current_input_field = InputField::Description;
-                self.position_cursor_at_desired_column(0);
-                if self.is_edit_mode {
-                    self.set_current_editor_to_normal_mode();
-                }
-                true
-            }
-            InputField::Description => {
-                // Don't wrap around, stay at description field
-                true
+        if self.current_input_field != InputField::Description {
+            self.current_input_field = self.current_input_field.next();
+            self.position_cursor_at_desired_column(0);
+            if self.is_edit_mode {
+                self.set_current_editor_to_normal_mode();
             }
         }
+        true
     }
 
     fn handle_k_navigation(&mut self) -> bool {
@@ -377,36 +429,14 @@ impl TaskModal {
         }
 
         // Move to previous field (no wraparound)
-        match self.current_input_field {
-            InputField::Title => {
-                // Don't wrap around, stay at title field
-                true
-            }
-            InputField::Date => {
-                self.current_input_field = InputField::Title;
-                self.position_cursor_at_desired_column(0);
-                if self.is_edit_mode {
-                    self.set_current_editor_to_normal_mode();
-                }
-                true
-            }
-            InputField::Time => {
-                self.current_input_field = InputField::Date;
-                self.position_cursor_at_desired_column(0);
-                if self.is_edit_mode {
-                    self.set_current_editor_to_normal_mode();
-                }
-                true
-            }
-            InputField::Description => {
-                self.current_input_field = InputField::Time;
-                self.position_cursor_at_desired_column(0);
-                if self.is_edit_mode {
-                    self.set_current_editor_to_normal_mode();
-                }
-                true
+        if self.current_input_field != InputField::Title {
+            self.current_input_field = self.current_input_field.previous();
+            self.position_cursor_at_desired_column(0);
+            if self.is_edit_mode {
+                self.set_current_editor_to_normal_mode();
             }
         }
+        true
     }
 
     pub fn is_current_editor_in_insert_mode(&self) -> bool {
@@ -430,6 +460,30 @@ impl TaskModal {
         String::from(self.input_time_editor.lines.clone())
     }
 
+    /// Id of the chosen project, or an empty string if none was chosen
+    pub fn get_input_project(&self) -> String {
+        self.project_picker
+            .selected()
+            .map(|project| project.id.0.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn get_input_start(&self) -> String {
+        String::from(self.input_start_editor.lines.clone())
+    }
+
+    pub fn get_input_reminders(&self) -> String {
+        String::from(self.input_reminders_editor.lines.clone())
+    }
+
+    pub fn get_input_priority(&self) -> String {
+        String::from(self.input_priority_editor.lines.clone())
+    }
+
+    pub fn get_input_repeat(&self) -> String {
+        String::from(self.input_repeat_editor.lines.clone())
+    }
+
     pub fn handle_input_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
         match self.current_input_field {
             InputField::Title => {
@@ -448,6 +502,28 @@ impl TaskModal {
                 self.event_handler
                     .on_key_event(key_event, &mut self.input_time_editor);
             }
+            InputField::Start => {
+                self.event_handler
+                    .on_key_event(key_event, &mut self.input_start_editor);
+            }
+            InputField::Reminders => {
+                self.event_handler
+                    .on_key_event(key_event, &mut self.input_reminders_editor);
+            }
+            InputField::Project => {
+                self.project_picker.handle_key_event(key_event);
+            }
+            InputField::Repeat => {
+                self.event_handler
+                    .on_key_event(key_event, &mut self.input_repeat_editor);
+            }
+            InputField::Priority => {
+                self.event_handler
+                    .on_key_event(key_event, &mut self.input_priority_editor);
+            }
+            InputField::Tags => {
+                self.tag_input.handle_key_event(key_event);
+            }
         }
         Ok(())
     }
@@ -462,6 +538,10 @@ impl TaskModal {
             match self.current_input_field {
                 InputField::Date => self.date_error = None,
                 InputField::Time => self.time_error = None,
+                InputField::Start => self.start_error = None,
+                InputField::Reminders => self.reminders_error = None,
+                InputField::Repeat => self.repeat_error = None,
+                InputField::Priority => self.priority_error = None,
                 _ => {}
             }
         }
@@ -497,16 +577,26 @@ impl Modal for TaskModal {
             KeyCode::Enter => {
                 match self.current_input_field {
                     InputField::Description => {
-                        if self.is_current_editor_in_insert_mode() {
+                        let confirm = key_event
+                            .modifiers
+                            .intersects(KeyModifiers::CONTROL | KeyModifiers::SUPER);
+                        if self.is_current_editor_in_insert_mode() && !confirm {
                             // Allow newline insertion for description field in insert mode
                             self.handle_input_key_event(key_event)?;
                             Ok(true)
                         } else {
-                            // In normal mode, confirm input
+                            // In normal mode or with Ctrl/Cmd, confirm input
                             Ok(false)
                         }
                     }
-                    InputField::Title | InputField::Date | InputField::Time => {
+                    InputField::Title
+                    | InputField::Date
+                    | InputField::Time
+                    | InputField::Reminders
+                    | InputField::Project
+                    | InputField::Repeat
+                    | InputField::Priority
+                    | InputField::Tags => {
                         // For single-line fields, always confirm input (both normal and insert mode)
                         Ok(false)
                     }
@@ -649,12 +739,13 @@ impl Modal for TaskModal {
         }
     }
 
-    fn render(&mut self, frame: &mut TuiFrame, area: Rect) {
-        let popup_area = centered_rect(60, 50, area);
+    fn render(&mut self, frame: &mut TuiFrame, area: Rect, palette: &Palette) {
+        let popup_area = centered_rect(60, 70, area);
         frame.render_widget(Clear, popup_area);
 
         // Render background
-        let bg_block = Block::default().style(Style::default().bg(NORMAL_BG));
+        let bg_block =
+            Block::default().style(Style::default().bg(palette.normal_bg).fg(palette.text_fg));
         frame.render_widget(bg_block, popup_area);
 
         let chunks = Layout::default()
@@ -662,43 +753,61 @@ impl Modal for TaskModal {
             .margin(1)
             .constraints([
                 Constraint::Length(3), // Title field
-                Constraint::Length(4), // Date field + error message
-                Constraint::Length(4), // Time field + error message
+                Constraint::Length(4), // Date and time fields + error messages
+                Constraint::Length(4), // Start and reminders fields + error messages
+                Constraint::Length(4), // Project field + matches, repeat field
+                Constraint::Length(4), // Priority and tags fields
                 Constraint::Min(5),    // Description field (remaining space, min 5 lines)
                 Constraint::Length(1), // Help text
             ])
             .split(popup_area);
+        let priority_tags_columns = Layout::default()
+            .direction(ratatui::layout::Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(chunks[4]);
+        let date_time_columns = Layout::default()
+            .direction(ratatui::layout::Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+        let start_reminders_columns = Layout::default()
+            .direction(ratatui::layout::Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[2]);
+        let project_repeat_columns = Layout::default()
+            .direction(ratatui::layout::Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[3]);
         // Title field
         let title_border_color = if self.current_input_field == InputField::Title
             && self.is_current_editor_in_insert_mode()
         {
             if self.is_edit_mode {
-                BORDER_EDIT
+                palette.border_edit
             } else {
-                BORDER_NEW
+                palette.border_new
             }
         } else {
-            BORDER_PROCESSING
+            palette.border_processing
         };
 
         let title_block = Block::default()
             .title("Title")
             .borders(Borders::ALL)
             .border_style(Style::default().fg(title_border_color))
-            .style(Style::default().bg(NORMAL_BG));
+            .style(Style::default().bg(palette.normal_bg));
 
         let title_inner = title_block.inner(chunks[0]);
         frame.render_widget(title_block, chunks[0]);
 
         let title_theme = if self.current_input_field == InputField::Title {
             EditorTheme::default()
-                .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
-                .cursor_style(Style::default().bg(TEXT_FG).fg(NORMAL_BG))
-                .selection_style(Style::default().bg(SELECTED_BG).fg(TEXT_FG))
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
                 .hide_status_line()
         } else {
             EditorTheme::default()
-                .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
                 .hide_status_line()
                 .hide_cursor()
         };
@@ -712,41 +821,41 @@ impl Modal for TaskModal {
                 Constraint::Length(3), // Date input
                 Constraint::Length(1), // Error message
             ])
-            .split(chunks[1]);
+            .split(date_time_columns[0]);
 
         let date_has_error = self.validation_attempted && self.date_error.is_some();
         let date_border_color = if date_has_error {
-            ACCENT_RED
+            palette.accent_red
         } else if self.current_input_field == InputField::Date
             && self.is_current_editor_in_insert_mode()
         {
             if self.is_edit_mode {
-                BORDER_EDIT
+                palette.border_edit
             } else {
-                BORDER_NEW
+                palette.border_new
             }
         } else {
-            BORDER_PROCESSING
+            palette.border_processing
         };
 
         let date_block = Block::default()
-            .title("Date (MM/DD/YYYY)")
+            .title("Date (MM/DD/YYYY, tomorrow, fri)")
             .borders(Borders::ALL)
             .border_style(Style::default().fg(date_border_color))
-            .style(Style::default().bg(NORMAL_BG));
+            .style(Style::default().bg(palette.normal_bg));
 
         let date_inner = date_block.inner(date_field_layout[0]);
         frame.render_widget(date_block, date_field_layout[0]);
 
         let date_theme = if self.current_input_field == InputField::Date {
             EditorTheme::default()
-                .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
-                .cursor_style(Style::default().bg(TEXT_FG).fg(NORMAL_BG))
-                .selection_style(Style::default().bg(SELECTED_BG).fg(TEXT_FG))
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
                 .hide_status_line()
         } else {
             EditorTheme::default()
-                .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
                 .hide_status_line()
                 .hide_cursor()
         };
@@ -755,8 +864,11 @@ impl Modal for TaskModal {
 
         // Render date error message if present
         if let Some(error) = &self.date_error {
-            let error_paragraph =
-                Paragraph::new(error.as_str()).style(Style::default().fg(ACCENT_RED).bg(NORMAL_BG));
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
             frame.render_widget(error_paragraph, date_field_layout[1]);
         }
 
@@ -767,41 +879,41 @@ impl Modal for TaskModal {
                 Constraint::Length(3), // Time input
                 Constraint::Length(1), // Error message
             ])
-            .split(chunks[2]);
+            .split(date_time_columns[1]);
 
         let time_has_error = self.validation_attempted && self.time_error.is_some();
         let time_border_color = if time_has_error {
-            ACCENT_RED
+            palette.accent_red
         } else if self.current_input_field == InputField::Time
             && self.is_current_editor_in_insert_mode()
         {
             if self.is_edit_mode {
-                BORDER_EDIT
+                palette.border_edit
             } else {
-                BORDER_NEW
+                palette.border_new
             }
         } else {
-            BORDER_PROCESSING
+            palette.border_processing
         };
 
         let time_block = Block::default()
             .title("Time (HH:MM AM/PM)")
             .borders(Borders::ALL)
             .border_style(Style::default().fg(time_border_color))
-            .style(Style::default().bg(NORMAL_BG));
+            .style(Style::default().bg(palette.normal_bg));
 
         let time_inner = time_block.inner(time_field_layout[0]);
         frame.render_widget(time_block, time_field_layout[0]);
 
         let time_theme = if self.current_input_field == InputField::Time {
             EditorTheme::default()
-                .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
-                .cursor_style(Style::default().bg(TEXT_FG).fg(NORMAL_BG))
-                .selection_style(Style::default().bg(SELECTED_BG).fg(TEXT_FG))
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
                 .hide_status_line()
         } else {
             EditorTheme::default()
-                .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
                 .hide_status_line()
                 .hide_cursor()
         };
@@ -810,42 +922,319 @@ impl Modal for TaskModal {
 
         // Render time error message if present
         if let Some(error) = &self.time_error {
-            let error_paragraph =
-                Paragraph::new(error.as_str()).style(Style::default().fg(ACCENT_RED).bg(NORMAL_BG));
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
             frame.render_widget(error_paragraph, time_field_layout[1]);
         }
 
+        // Start field with error message layout
+        let start_field_layout = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Start input
+                Constraint::Length(1), // Error message
+            ])
+            .split(start_reminders_columns[0]);
+
+        let start_has_error = self.validation_attempted && self.start_error.is_some();
+        let start_border_color = if start_has_error {
+            palette.accent_red
+        } else if self.current_input_field == InputField::Start
+            && self.is_current_editor_in_insert_mode()
+        {
+            if self.is_edit_mode {
+                palette.border_edit
+            } else {
+                palette.border_new
+            }
+        } else {
+            palette.border_processing
+        };
+
+        let start_block = Block::default()
+            .title("Start (date, 9am, 2 hours before due)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(start_border_color))
+            .style(Style::default().bg(palette.normal_bg));
+
+        let start_inner = start_block.inner(start_field_layout[0]);
+        frame.render_widget(start_block, start_field_layout[0]);
+
+        let start_theme = if self.current_input_field == InputField::Start {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+                .hide_status_line()
+        } else {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .hide_status_line()
+                .hide_cursor()
+        };
+        let start_editor_view = EditorView::new(&mut self.input_start_editor).theme(start_theme);
+        frame.render_widget(start_editor_view, start_inner);
+
+        // Render start error message if present
+        if let Some(error) = &self.start_error {
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
+            frame.render_widget(error_paragraph, start_field_layout[1]);
+        }
+
+        // Reminders field with error message layout
+        let reminders_field_layout = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Reminders input
+                Constraint::Length(1), // Error message
+            ])
+            .split(start_reminders_columns[1]);
+
+        let reminders_has_error = self.validation_attempted && self.reminders_error.is_some();
+        let reminders_border_color = if reminders_has_error {
+            palette.accent_red
+        } else if self.current_input_field == InputField::Reminders
+            && self.is_current_editor_in_insert_mode()
+        {
+            if self.is_edit_mode {
+                palette.border_edit
+            } else {
+                palette.border_new
+            }
+        } else {
+            palette.border_processing
+        };
+
+        let reminders_block = Block::default()
+            .title("Reminders (15m before, 1 day before at 9am)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(reminders_border_color))
+            .style(Style::default().bg(palette.normal_bg));
+
+        let reminders_inner = reminders_block.inner(reminders_field_layout[0]);
+        frame.render_widget(reminders_block, reminders_field_layout[0]);
+
+        let reminders_theme = if self.current_input_field == InputField::Reminders {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+                .hide_status_line()
+        } else {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .hide_status_line()
+                .hide_cursor()
+        };
+        let reminders_editor_view =
+            EditorView::new(&mut self.input_reminders_editor).theme(reminders_theme);
+        frame.render_widget(reminders_editor_view, reminders_inner);
+
+        // Render reminders error message if present
+        if let Some(error) = &self.reminders_error {
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
+            frame.render_widget(error_paragraph, reminders_field_layout[1]);
+        }
+
+        // Project field
+        let project_border_color = if self.current_input_field == InputField::Project
+            && self.is_current_editor_in_insert_mode()
+        {
+            if self.is_edit_mode {
+                palette.border_edit
+            } else {
+                palette.border_new
+            }
+        } else {
+            palette.border_processing
+        };
+        self.project_picker.render(
+            frame,
+            project_repeat_columns[0],
+            project_border_color,
+            self.current_input_field == InputField::Project,
+            palette,
+        );
+
+        // Repeat field with error message layout
+        let repeat_field_layout = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Repeat input
+                Constraint::Length(1), // Error message
+            ])
+            .split(project_repeat_columns[1]);
+
+        let repeat_has_error = self.validation_attempted && self.repeat_error.is_some();
+        let repeat_border_color = if repeat_has_error {
+            palette.accent_red
+        } else if self.current_input_field == InputField::Repeat
+            && self.is_current_editor_in_insert_mode()
+        {
+            if self.is_edit_mode {
+                palette.border_edit
+            } else {
+                palette.border_new
+            }
+        } else {
+            palette.border_processing
+        };
+
+        let repeat_block = Block::default()
+            .title("Repeat (every weekday, every 2 weeks on mon)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(repeat_border_color))
+            .style(Style::default().bg(palette.normal_bg));
+
+        let repeat_inner = repeat_block.inner(repeat_field_layout[0]);
+        frame.render_widget(repeat_block, repeat_field_layout[0]);
+
+        let repeat_theme = if self.current_input_field == InputField::Repeat {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+                .hide_status_line()
+        } else {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .hide_status_line()
+                .hide_cursor()
+        };
+        let repeat_editor_view = EditorView::new(&mut self.input_repeat_editor).theme(repeat_theme);
+        frame.render_widget(repeat_editor_view, repeat_inner);
+
+        // Render repeat error message if present
+        if let Some(error) = &self.repeat_error {
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
+            frame.render_widget(error_paragraph, repeat_field_layout[1]);
+        }
+
+        // Priority field with error message layout
+        let priority_field_layout = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Priority input
+                Constraint::Length(1), // Error message
+            ])
+            .split(priority_tags_columns[0]);
+
+        let priority_has_error = self.validation_attempted && self.priority_error.is_some();
+        let priority_border_color = if priority_has_error {
+            palette.accent_red
+        } else if self.current_input_field == InputField::Priority
+            && self.is_current_editor_in_insert_mode()
+        {
+            if self.is_edit_mode {
+                palette.border_edit
+            } else {
+                palette.border_new
+            }
+        } else {
+            palette.border_processing
+        };
+
+        let priority_block = Block::default()
+            .title("Priority (high/med/low)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(priority_border_color))
+            .style(Style::default().bg(palette.normal_bg));
+
+        let priority_inner = priority_block.inner(priority_field_layout[0]);
+        frame.render_widget(priority_block, priority_field_layout[0]);
+
+        let priority_theme = if self.current_input_field == InputField::Priority {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+                .hide_status_line()
+        } else {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .hide_status_line()
+                .hide_cursor()
+        };
+        let priority_editor_view =
+            EditorView::new(&mut self.input_priority_editor).theme(priority_theme);
+        frame.render_widget(priority_editor_view, priority_inner);
+
+        // Render priority error message if present
+        if let Some(error) = &self.priority_error {
+            let error_paragraph = Paragraph::new(error.as_str()).style(
+                Style::default()
+                    .fg(palette.accent_red)
+                    .bg(palette.normal_bg),
+            );
+            frame.render_widget(error_paragraph, priority_field_layout[1]);
+        }
+
+        // Tags field
+        let tags_border_color = if self.current_input_field == InputField::Tags
+            && self.is_current_editor_in_insert_mode()
+        {
+            if self.is_edit_mode {
+                palette.border_edit
+            } else {
+                palette.border_new
+            }
+        } else {
+            palette.border_processing
+        };
+        self.tag_input.render(
+            frame,
+            priority_tags_columns[1],
+            tags_border_color,
+            self.current_input_field == InputField::Tags,
+            palette,
+        );
+
         // Description field
         let description_border_color = if self.current_input_field == InputField::Description
             && self.is_current_editor_in_insert_mode()
         {
             if self.is_edit_mode {
-                BORDER_EDIT
+                palette.border_edit
             } else {
-                BORDER_NEW
+                palette.border_new
             }
         } else {
-            BORDER_PROCESSING
+            palette.border_processing
         };
 
         let description_block = Block::default()
             .title("Description")
             .borders(Borders::ALL)
             .border_style(Style::default().fg(description_border_color))
-            .style(Style::default().bg(NORMAL_BG));
+            .style(Style::default().bg(palette.normal_bg));
 
-        let description_inner = description_block.inner(chunks[3]);
-        frame.render_widget(description_block, chunks[3]);
+        let description_inner = description_block.inner(chunks[5]);
+        frame.render_widget(description_block, chunks[5]);
 
         let description_theme = if self.current_input_field == InputField::Description {
             EditorTheme::default()
-                .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
-                .cursor_style(Style::default().bg(TEXT_FG).fg(NORMAL_BG))
-                .selection_style(Style::default().bg(SELECTED_BG).fg(TEXT_FG))
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
                 .hide_status_line()
         } else {
             EditorTheme::default()
-                .base(Style::default().bg(NORMAL_BG).fg(TEXT_FG))
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
                 .hide_status_line()
                 .hide_cursor()
         };
@@ -855,24 +1244,24 @@ impl Modal for TaskModal {
 
         // Help text at bottom of modal
         let help_text = vec![Line::from(vec![
-            Span::styled("Tab", Style::default().fg(ACCENT_YELLOW)),
+            Span::styled("Tab", Style::default().fg(palette.accent_yellow)),
             Span::raw(" switch fields  •  "),
-            Span::styled("Enter", Style::default().fg(ACCENT_GREEN)),
+            Span::styled("Enter", Style::default().fg(palette.accent_green)),
             Span::raw(" confirm  •  "),
-            Span::styled("Esc", Style::default().fg(ACCENT_RED)),
+            Span::styled("Esc", Style::default().fg(palette.accent_red)),
             Span::raw(" cancel"),
         ])];
         let help_paragraph = Paragraph::new(help_text)
-            .style(Style::default().bg(NORMAL_BG))
+            .style(Style::default().bg(palette.normal_bg))
             .alignment(Alignment::Center);
 
-        frame.render_widget(help_paragraph, chunks[4]);
+        frame.render_widget(help_paragraph, chunks[6]);
 
         // Render the modal border
         let modal_border_color = if self.is_edit_mode {
-            BORDER_EDIT
+            palette.border_edit
         } else {
-            BORDER_NEW
+            palette.border_new
         };
         let modal_block = Block::default()
             .title(self.title.as_str())
@@ -888,6 +1277,12 @@ impl Modal for TaskModal {
             self.get_input_description(),
             self.get_input_date(),
             self.get_input_time(),
+            self.get_input_project(),
+            self.get_input_priority(),
+            self.tag_input.text(),
+            self.get_input_repeat(),
+            self.get_input_reminders(),
+            self.get_input_start(),
         ]
     }
 
@@ -896,10 +1291,20 @@ impl Modal for TaskModal {
         self.input_description_editor = EditorState::default();
         self.input_date_editor = EditorState::default();
         self.input_time_editor = EditorState::default();
+        self.input_start_editor = EditorState::default();
+        self.input_reminders_editor = EditorState::default();
+        self.input_priority_editor = EditorState::default();
+        self.input_repeat_editor = EditorState::default();
+        self.project_picker.set_query("");
+        self.tag_input.set_text("");
         self.current_input_field = InputField::Title;
         self.validation_attempted = false;
         self.date_error = None;
         self.time_error = None;
+        self.start_error = None;
+        self.reminders_error = None;
+        self.priority_error = None;
+        self.repeat_error = None;
     }
 
     fn set_values(&mut self, values: Vec<String>) {
@@ -915,6 +1320,21 @@ impl Modal for TaskModal {
         if values.len() >= 4 && !values[3].is_empty() {
             self.input_time_editor = EditorState::new(Lines::from(values[3].clone()));
         }
+        if values.len() >= 6 && !values[5].is_empty() {
+            self.input_priority_editor = EditorState::new(Lines::from(values[5].clone()));
+        }
+        if values.len() >= 7 && !values[6].is_empty() {
+            self.tag_input.set_text(&values[6]);
+        }
+        if values.len() >= 8 && !values[7].is_empty() {
+            self.input_repeat_editor = EditorState::new(Lines::from(values[7].clone()));
+        }
+        if values.len() >= 9 && !values[8].is_empty() {
+            self.input_reminders_editor = EditorState::new(Lines::from(values[8].clone()));
+        }
+        if values.len() >= 10 && !values[9].is_empty() {
+            self.input_start_editor = EditorState::new(Lines::from(values[9].clone()));
+        }
     }
 
     fn validate(&mut self) -> bool {
@@ -949,10 +1369,40 @@ impl Modal for TaskModal {
             self.time_error = None;
         }
 
+        self.start_error = validate_start(&self.get_input_start(), &date_str).err();
+        if self.start_error.is_some() {
+            is_valid = false;
+        }
+
+        self.reminders_error = validate_reminders(&self.get_input_reminders(), &date_str).err();
+        if self.reminders_error.is_some() {
+            is_valid = false;
+        }
+
+        if !self.project_picker.validate() {
+            is_valid = false;
+        }
+
+        self.priority_error = parse_priority_field(&self.get_input_priority()).err();
+        if self.priority_error.is_some() {
+            is_valid = false;
+        }
+
+        self.repeat_error = parse_recurrence(&self.get_input_repeat()).err();
+        if self.repeat_error.is_some() {
+            is_valid = false;
+        }
+
         is_valid
     }
 
     fn has_validation_errors(&self) -> bool {
-        self.date_error.is_some() || self.time_error.is_some()
+        self.date_error.is_some()
+            || self.time_error.is_some()
+            || self.start_error.is_some()
+            || self.reminders_error.is_some()
+            || self.project_picker.error.is_some()
+            || self.priority_error.is_some()
+            || self.repeat_error.is_some()
     }
 }
@@ -0,0 +1,213 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use edtui::{EditorEventHandler, EditorState, EditorTheme, EditorView, Index2, Lines};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use super::colors::*;
+use super::tui::Frame as TuiFrame;
+use crate::{backend::ProjectInfo, utils::fuzzy_score};
+
+/// Number of matches listed under the input
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Single-line input that picks a project by fuzzy-matching its name
+///
+/// Ctrl-n / Ctrl-p move the highlight through the matches.
+pub struct ProjectPicker {
+    pub input: EditorState,
+    event_handler: EditorEventHandler,
+    projects: Vec<ProjectInfo>,
+    /// Index into `matches()` of the highlighted project
+    highlighted: usize,
+    pub error: Option<String>,
+}
+
+impl ProjectPicker {
+    pub fn new(projects: Vec<ProjectInfo>) -> Self {
+        Self {
+            input: EditorState::default(),
+            event_handler: EditorEventHandler::default(),
+            projects,
+            highlighted: 0,
+            error: None,
+        }
+    }
+
+    pub fn set_projects(&mut self, projects: Vec<ProjectInfo>) {
+        self.projects = projects;
+        self.highlighted = 0;
+    }
+
+    /// Replace the query, e.g. with the name of the task's current project
+    pub fn set_query(&mut self, query: &str) {
+        self.input = EditorState::new(Lines::from(query.to_string()));
+        self.highlighted = 0;
+        self.error = None;
+    }
+
+    pub fn query(&self) -> String {
+        String::from(self.input.lines.clone())
+    }
+
+    /// Projects matching the query, best match first
+    pub fn matches(&self) -> Vec<&ProjectInfo> {
+        let query = self.query();
+        let mut scored: Vec<(i64, &ProjectInfo)> = self
+            .projects
+            .iter()
+            .filter_map(|project| fuzzy_score(&query, &project.name).map(|score| (score, project)))
+            .collect();
+        // Stable sort keeps the project order for equal scores
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        scored.into_iter().map(|(_, project)| project).collect()
+    }
+
+    /// The highlighted project, or `None` while the query is empty
+    pub fn selected(&self) -> Option<&ProjectInfo> {
+        if self.query().trim().is_empty() {
+            return None;
+        }
+        self.matches().get(self.highlighted).copied()
+    }
+
+    /// Check that a non-empty query matches a project
+    pub fn validate(&mut self) -> bool {
+        let query = self.query();
+        self.error = if !query.trim().is_empty() && self.selected().is_none() {
+            Some(format!("No project matches \"{}\"", query.trim()))
+        } else {
+            None
+        };
+        self.error.is_none()
+    }
+
+    pub fn position_cursor_at_end(&mut self) {
+        let col = self.input.lines.len_col(0).unwrap_or(0);
+        self.input.cursor = Index2::new(0, col);
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) {
+        let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+        let count = self.matches().len();
+        match key_event.code {
+            KeyCode::Char('n') if ctrl => {
+                if count > 0 {
+                    self.highlighted = (self.highlighted + 1) % count;
+                }
+            }
+            KeyCode::Char('p') if ctrl => {
+                if count > 0 {
+                    self.highlighted = (self.highlighted + count - 1) % count;
+                }
+            }
+            _ => {
+                let before = self.query();
+                self.event_handler.on_key_event(key_event, &mut self.input);
+                if self.query() != before {
+                    self.highlighted = 0;
+                    self.error = None;
+                }
+            }
+        }
+    }
+
+    /// Render the input with a line of matches (or the error) underneath
+    ///
+    /// Needs 4 rows: 3 for the bordered input and 1 for the matches.
+    pub fn render(
+        &mut self,
+        frame: &mut TuiFrame,
+        area: Rect,
+        border_color: Color,
+        focused: bool,
+        palette: &Palette,
+    ) {
+        let layout = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Project input
+                Constraint::Length(1), // Matches or error message
+            ])
+            .split(area);
+
+        let border_color = if self.error.is_some() {
+            palette.accent_red
+        } else {
+            border_color
+        };
+        let block = Block::default()
+            .title("Project")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color))
+            .style(Style::default().bg(palette.normal_bg));
+        let inner = block.inner(layout[0]);
+        frame.render_widget(block, layout[0]);
+
+        let theme = if focused {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+                .hide_status_line()
+        } else {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .hide_status_line()
+                .hide_cursor()
+        };
+        frame.render_widget(EditorView::new(&mut self.input).theme(theme), inner);
+
+        let line = if let Some(error) = &self.error {
+            Line::from(Span::styled(
+                error.clone(),
+                Style::default().fg(palette.accent_red),
+            ))
+        } else if !focused {
+            Line::default()
+        } else if self.query().trim().is_empty() {
+            Line::from(Span::styled(
+                "Type to search, Ctrl-n / Ctrl-p to choose",
+                Style::default()
+                    .fg(palette.date_normal)
+                    .add_modifier(Modifier::ITALIC),
+            ))
+        } else {
+            let matches = self.matches();
+            if matches.is_empty() {
+                Line::from(Span::styled(
+                    "No matching project",
+                    Style::default().fg(palette.date_normal),
+                ))
+            } else {
+                // Keep the highlighted match visible when it's past the first few
+                let start = self.highlighted.saturating_sub(MAX_SUGGESTIONS - 1);
+                let mut spans = Vec::new();
+                for (i, project) in matches.iter().enumerate().skip(start).take(MAX_SUGGESTIONS) {
+                    if !spans.is_empty() {
+                        spans.push(Span::styled(
+                            " · ",
+                            Style::default().fg(palette.date_normal),
+                        ));
+                    }
+                    let style = if i == self.highlighted {
+                        Style::default()
+                            .fg(palette.accent_yellow)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(palette.text_fg)
+                    };
+                    spans.push(Span::styled(project.name.clone(), style));
+                }
+                Line::from(spans)
+            }
+        };
+        frame.render_widget(
+            Paragraph::new(line).style(Style::default().bg(palette.normal_bg)),
+            layout[1],
+        );
+    }
+}
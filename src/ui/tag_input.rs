@@ -0,0 +1,250 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use edtui::{EditorEventHandler, EditorState, EditorTheme, EditorView, Index2, Lines};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use super::colors::*;
+use super::tui::Frame as TuiFrame;
+use crate::{quick_add::parse_tags, utils::fuzzy_score};
+
+/// Number of suggestions listed under the input
+const MAX_SUGGESTIONS: usize = 5;
+
+/// A completion in progress, started by the first Ctrl-n / Ctrl-p
+struct Completion {
+    /// Text before the word being completed
+    before: String,
+    /// Text after the cursor when completion started
+    after: String,
+    candidates: Vec<String>,
+    index: usize,
+}
+
+/// Single-line input for a comma separated list of tags
+///
+/// Ctrl-n / Ctrl-p complete the word before the cursor from the known tags,
+/// cycling through the matches like vim's insert-mode completion.
+pub struct TagInput {
+    pub input: EditorState,
+    event_handler: EditorEventHandler,
+    known_tags: Vec<String>,
+    completion: Option<Completion>,
+}
+
+impl TagInput {
+    pub fn new(known_tags: Vec<String>) -> Self {
+        Self {
+            input: EditorState::default(),
+            event_handler: EditorEventHandler::default(),
+            known_tags,
+            completion: None,
+        }
+    }
+
+    pub fn set_known_tags(&mut self, known_tags: Vec<String>) {
+        self.known_tags = known_tags;
+        self.completion = None;
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.input = EditorState::new(Lines::from(text.to_string()));
+        self.completion = None;
+    }
+
+    pub fn text(&self) -> String {
+        String::from(self.input.lines.clone())
+    }
+
+    /// The tags typed so far
+    pub fn tags(&self) -> Vec<String> {
+        parse_tags(&self.text())
+    }
+
+    pub fn position_cursor_at_end(&mut self) {
+        let col = self.input.lines.len_col(0).unwrap_or(0);
+        self.input.cursor = Index2::new(0, col);
+    }
+
+    /// Split the text around the word before the cursor
+    fn split_at_word(&self) -> (String, String, String) {
+        let chars: Vec<char> = self.text().chars().collect();
+        let cursor = self.input.cursor.col.min(chars.len());
+        let start = chars[..cursor]
+            .iter()
+            .rposition(|c| *c == ',' || c.is_whitespace())
+            .map_or(0, |i| i + 1);
+        (
+            chars[..start].iter().collect(),
+            chars[start..cursor].iter().collect(),
+            chars[cursor..].iter().collect(),
+        )
+    }
+
+    /// Known tags matching `word`, best match first, leaving out tags already entered
+    fn suggestions_for(&self, word: &str, entered: &[String]) -> Vec<String> {
+        let word = word.trim_start_matches('#');
+        let mut scored: Vec<(i64, &String)> = self
+            .known_tags
+            .iter()
+            .filter(|tag| !entered.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+            .filter_map(|tag| fuzzy_score(word, tag).map(|score| (score, tag)))
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        scored.into_iter().map(|(_, tag)| tag.clone()).collect()
+    }
+
+    /// Suggestions for the word before the cursor
+    pub fn suggestions(&self) -> Vec<String> {
+        if let Some(completion) = &self.completion {
+            return completion.candidates.clone();
+        }
+        let (before, word, after) = self.split_at_word();
+        let entered = parse_tags(&format!("{} {}", before, after));
+        self.suggestions_for(&word, &entered)
+    }
+
+    /// Replace the word being completed with the next (or previous) candidate
+    fn complete(&mut self, forward: bool) {
+        let completion = match self.completion.take() {
+            Some(mut completion) => {
+                let count = completion.candidates.len();
+                completion.index = if forward {
+                    (completion.index + 1) % count
+                } else {
+                    (completion.index + count - 1) % count
+                };
+                completion
+            }
+            None => {
+                let (before, word, after) = self.split_at_word();
+                let entered = parse_tags(&format!("{} {}", before, after));
+                let candidates = self.suggestions_for(&word, &entered);
+                if candidates.is_empty() {
+                    return;
+                }
+                let index = if forward { 0 } else { candidates.len() - 1 };
+                Completion {
+                    before,
+                    after,
+                    candidates,
+                    index,
+                }
+            }
+        };
+
+        let completed = format!(
+            "{}{}",
+            completion.before, completion.candidates[completion.index]
+        );
+        let mode = self.input.mode;
+        self.input = EditorState::new(Lines::from(format!("{}{}", completed, completion.after)));
+        self.input.mode = mode;
+        self.input.cursor = Index2::new(0, completed.chars().count());
+        self.completion = Some(completion);
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) {
+        let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+        match key_event.code {
+            KeyCode::Char('n') if ctrl => self.complete(true),
+            KeyCode::Char('p') if ctrl => self.complete(false),
+            _ => {
+                self.completion = None;
+                self.event_handler.on_key_event(key_event, &mut self.input);
+            }
+        }
+    }
+
+    /// Render the input with a line of suggestions underneath
+    ///
+    /// Needs 4 rows: 3 for the bordered input and 1 for the suggestions.
+    pub fn render(
+        &mut self,
+        frame: &mut TuiFrame,
+        area: Rect,
+        border_color: Color,
+        focused: bool,
+        palette: &Palette,
+    ) {
+        let layout = Layout::default()
+            .direction(ratatui::layout::Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Tags input
+                Constraint::Length(1), // Suggestions
+            ])
+            .split(area);
+
+        let block = Block::default()
+            .title("Tags (comma separated)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color))
+            .style(Style::default().bg(palette.normal_bg));
+        let inner = block.inner(layout[0]);
+        frame.render_widget(block, layout[0]);
+
+        let theme = if focused {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .cursor_style(Style::default().bg(palette.text_fg).fg(palette.normal_bg))
+                .selection_style(Style::default().bg(palette.selected_bg).fg(palette.text_fg))
+                .hide_status_line()
+        } else {
+            EditorTheme::default()
+                .base(Style::default().bg(palette.normal_bg).fg(palette.text_fg))
+                .hide_status_line()
+                .hide_cursor()
+        };
+        frame.render_widget(EditorView::new(&mut self.input).theme(theme), inner);
+
+        let suggestions = if focused {
+            self.suggestions()
+        } else {
+            Vec::new()
+        };
+        let line = if suggestions.is_empty() {
+            Line::default()
+        } else if self.completion.is_none() && self.split_at_word().1.is_empty() {
+            Line::from(Span::styled(
+                "Ctrl-n / Ctrl-p to complete from existing tags",
+                Style::default()
+                    .fg(palette.date_normal)
+                    .add_modifier(Modifier::ITALIC),
+            ))
+        } else {
+            let highlighted = self.completion.as_ref().map(|c| c.index);
+            // Keep the highlighted suggestion visible when it's past the first few
+            let start = highlighted.unwrap_or(0).saturating_sub(MAX_SUGGESTIONS - 1);
+            let mut spans = Vec::new();
+            for (i, tag) in suggestions
+                .iter()
+                .enumerate()
+                .skip(start)
+                .take(MAX_SUGGESTIONS)
+            {
+                if !spans.is_empty() {
+                    spans.push(Span::styled(
+                        " · ",
+                        Style::default().fg(palette.date_normal),
+                    ));
+                }
+                let style = if Some(i) == highlighted {
+                    Style::default()
+                        .fg(palette.accent_yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(palette.tag_fg)
+                };
+                spans.push(Span::styled(format!("#{}", tag), style));
+            }
+            Line::from(spans)
+        };
+        frame.render_widget(
+            Paragraph::new(line).style(Style::default().bg(palette.normal_bg)),
+            layout[1],
+        );
+    }
+}
@@ -1,20 +1,88 @@
 use anyhow::Result;
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use edtui::{EditorEventHandler, EditorMode, EditorState, Index2, Lines};
 
+use super::{ChecklistEditor, ProjectPicker, TagInput};
+use crate::{
+    checklist::ChecklistItem, quick_add::parse_priority_field, recurrence::parse_recurrence,
+    reminders::validate_reminders, tasks::validate_start,
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InputField {
     Title,
     Date,
     Time,
+    Start,
+    Reminders,
+    Project,
+    Repeat,
+    Priority,
+    Tags,
     Description,
 }
 
+impl InputField {
+    /// Fields in the order Tab and j/k move through them
+    const ORDER: [InputField; 10] = [
+        InputField::Title,
+        InputField::Date,
+        InputField::Time,
+        InputField::Start,
+        InputField::Reminders,
+        InputField::Project,
+        InputField::Repeat,
+        InputField::Priority,
+        InputField::Tags,
+        InputField::Description,
+    ];
+
+    /// The next field, wrapping around to the first
+    pub fn next(self) -> Self {
+        let index = Self::ORDER.iter().position(|&f| f == self).unwrap_or(0);
+        Self::ORDER[(index + 1) % Self::ORDER.len()]
+    }
+
+    /// The previous field, wrapping around to the last
+    pub fn previous(self) -> Self {
+        let index = Self::ORDER.iter().position(|&f| f == self).unwrap_or(0);
+        Self::ORDER[(index + Self::ORDER.len() - 1) % Self::ORDER.len()]
+    }
+}
+
+/// Contents of every field, used to fill the editor and to detect changes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldValues {
+    pub title: String,
+    pub description: String,
+    pub date: String,
+    pub time: String,
+    /// Start date and time, empty when the task starts when it's due
+    pub start: String,
+    /// Friendly reminder text, e.g. `15 minutes before, at due time`
+    pub reminders: String,
+    pub project: String,
+    /// Friendly recurrence text, e.g. `every weekday`
+    pub repeat: String,
+    pub priority: String,
+    pub tags: String,
+    pub checklist: Vec<ChecklistItem>,
+}
+
 pub struct TaskEditor {
     pub input_title_editor: EditorState,
     pub input_description_editor: EditorState,
     pub input_date_editor: EditorState,
     pub input_time_editor: EditorState,
+    pub input_start_editor: EditorState,
+    pub input_reminders_editor: EditorState,
+    pub input_priority_editor: EditorState,
+    pub input_repeat_editor: EditorState,
+    pub project_picker: ProjectPicker,
+    pub tag_input: TagInput,
+    pub checklist: ChecklistEditor,
+    /// Whether the checklist below the fields has focus instead of `current_input_field`
+    pub checklist_focused: bool,
     pub current_input_field: InputField,
     pub event_handler: EditorEventHandler,
     pub is_edit_mode: bool,
@@ -23,11 +91,12 @@ pub struct TaskEditor {
     pub validation_attempted: bool,
     pub date_error: Option<String>,
     pub time_error: Option<String>,
+    pub start_error: Option<String>,
+    pub reminders_error: Option<String>,
+    pub priority_error: Option<String>,
+    pub repeat_error: Option<String>,
     // Original values for change detection
-    pub original_title: String,
-    pub original_description: String,
-    pub original_date: String,
-    pub original_time: String,
+    pub original: FieldValues,
 }
 
 impl TaskEditor {
@@ -37,6 +106,14 @@ impl TaskEditor {
             input_description_editor: EditorState::default(),
             input_date_editor: EditorState::default(),
             input_time_editor: EditorState::default(),
+            input_start_editor: EditorState::default(),
+            input_reminders_editor: EditorState::default(),
+            input_priority_editor: EditorState::default(),
+            input_repeat_editor: EditorState::default(),
+            project_picker: ProjectPicker::new(Vec::new()),
+            tag_input: TagInput::new(Vec::new()),
+            checklist: ChecklistEditor::new(),
+            checklist_focused: false,
             current_input_field: InputField::Title,
             event_handler: EditorEventHandler::default(),
             is_edit_mode: false,
@@ -44,10 +121,11 @@ impl TaskEditor {
             validation_attempted: false,
             date_error: None,
             time_error: None,
-            original_title: String::new(),
-            original_description: String::new(),
-            original_date: String::new(),
-            original_time: String::new(),
+            start_error: None,
+            reminders_error: None,
+            priority_error: None,
+            repeat_error: None,
+            original: FieldValues::default(),
         }
     }
 
@@ -57,27 +135,72 @@ impl TaskEditor {
         self.input_description_editor = EditorState::default();
         self.input_date_editor = EditorState::default();
         self.input_time_editor = EditorState::default();
+        self.input_start_editor = EditorState::default();
+        self.input_reminders_editor = EditorState::default();
+        self.input_priority_editor = EditorState::default();
+        self.input_repeat_editor = EditorState::default();
+        self.project_picker.set_query("");
+        self.tag_input.set_text("");
+        self.checklist.set_items(Vec::new());
+        self.checklist_focused = false;
         self.current_input_field = InputField::Title;
         self.validation_attempted = false;
         self.date_error = None;
         self.time_error = None;
-        self.original_title = String::new();
-        self.original_description = String::new();
-        self.original_date = String::new();
-        self.original_time = String::new();
+        self.start_error = None;
+        self.reminders_error = None;
+        self.priority_error = None;
+        self.repeat_error = None;
+        self.original = FieldValues::default();
     }
 
-    pub fn set_values(&mut self, title: &str, description: &str, date: &str, time: &str) {
-        self.input_title_editor = EditorState::new(Lines::from(title.to_string()));
-        self.input_description_editor = EditorState::new(Lines::from(description.to_string()));
-        self.input_date_editor = EditorState::new(Lines::from(date.to_string()));
-        self.input_time_editor = EditorState::new(Lines::from(time.to_string()));
+    pub fn set_values(&mut self, values: FieldValues) {
+        self.input_title_editor = EditorState::new(Lines::from(values.title.clone()));
+        self.input_description_editor = EditorState::new(Lines::from(values.description.clone()));
+        self.input_date_editor = EditorState::new(Lines::from(values.date.clone()));
+        self.input_time_editor = EditorState::new(Lines::from(values.time.clone()));
+        self.input_start_editor = EditorState::new(Lines::from(values.start.clone()));
+        self.input_reminders_editor = EditorState::new(Lines::from(values.reminders.clone()));
+        self.project_picker.set_query(&values.project);
+        self.input_repeat_editor = EditorState::new(Lines::from(values.repeat.clone()));
+        self.input_priority_editor = EditorState::new(Lines::from(values.priority.clone()));
+        self.tag_input.set_text(&values.tags);
+        self.checklist.set_items(values.checklist.clone());
 
         // Store original values for change detection
-        self.original_title = title.to_string();
-        self.original_description = description.to_string();
-        self.original_date = date.to_string();
-        self.original_time = time.to_string();
+        self.original = values;
+    }
+
+    /// Current text of every field
+    pub fn current_values(&self) -> FieldValues {
+        FieldValues {
+            title: self.get_input_title(),
+            description: self.get_input_description(),
+            date: self.get_input_date(),
+            time: self.get_input_time(),
+            start: self.get_input_start(),
+            reminders: self.get_input_reminders(),
+            project: self.project_picker.query(),
+            repeat: self.get_input_repeat(),
+            priority: self.get_input_priority(),
+            tags: self.tag_input.text(),
+            checklist: self.checklist.items.clone(),
+        }
+    }
+
+    fn current_editor(&self) -> &EditorState {
+        match self.current_input_field {
+            InputField::Title => &self.input_title_editor,
+            InputField::Description => &self.input_description_editor,
+            InputField::Date => &self.input_date_editor,
+            InputField::Time => &self.input_time_editor,
+            InputField::Start => &self.input_start_editor,
+            InputField::Reminders => &self.input_reminders_editor,
+            InputField::Project => &self.project_picker.input,
+            InputField::Repeat => &self.input_repeat_editor,
+            InputField::Priority => &self.input_priority_editor,
+            InputField::Tags => &self.tag_input.input,
+        }
     }
 
     pub fn get_current_editor_mut(&mut self) -> &mut EditorState {
@@ -86,35 +209,37 @@ impl TaskEditor {
             InputField::Description => &mut self.input_description_editor,
             InputField::Date => &mut self.input_date_editor,
             InputField::Time => &mut self.input_time_editor,
+            InputField::Start => &mut self.input_start_editor,
+            InputField::Reminders => &mut self.input_reminders_editor,
+            InputField::Project => &mut self.project_picker.input,
+            InputField::Repeat => &mut self.input_repeat_editor,
+            InputField::Priority => &mut self.input_priority_editor,
+            InputField::Tags => &mut self.tag_input.input,
         }
     }
 
     pub fn next_input_field(&mut self) {
-        self.current_input_field = match self.current_input_field {
-            InputField::Title => InputField::Date,
-            InputField::Date => InputField::Time,
-            InputField::Time => InputField::Description,
-            InputField::Description => InputField::Title,
-        };
+        if self.current_input_field == InputField::Description {
+            self.checklist_focused = true;
+        } else {
+            self.current_input_field = self.current_input_field.next();
+        }
+    }
+
+    /// Move focus from the checklist back to the description, or on to the title
+    pub fn leave_checklist(&mut self, forward: bool) {
+        self.checklist_focused = false;
+        if forward {
+            self.current_input_field = InputField::Title;
+        }
     }
 
     pub fn previous_input_field(&mut self) {
-        self.current_input_field = match self.current_input_field {
-            InputField::Title => InputField::Description,
-            InputField::Date => InputField::Title,
-            InputField::Time => InputField::Date,
-            InputField::Description => InputField::Time,
-        };
+        self.current_input_field = self.current_input_field.previous();
     }
 
     pub fn is_current_editor_in_insert_mode(&self) -> bool {
-        let editor = match self.current_input_field {
-            InputField::Title => &self.input_title_editor,
-            InputField::Description => &self.input_description_editor,
-            InputField::Date => &self.input_date_editor,
-            InputField::Time => &self.input_time_editor,
-        };
-        editor.mode == EditorMode::Insert
+        self.current_editor().mode == EditorMode::Insert
     }
 
     #[allow(dead_code)]
@@ -160,6 +285,28 @@ impl TaskEditor {
                 self.event_handler
                     .on_key_event(key_event, &mut self.input_time_editor);
             }
+            InputField::Start => {
+                self.event_handler
+                    .on_key_event(key_event, &mut self.input_start_editor);
+            }
+            InputField::Reminders => {
+                self.event_handler
+                    .on_key_event(key_event, &mut self.input_reminders_editor);
+            }
+            InputField::Project => {
+                self.project_picker.handle_key_event(key_event);
+            }
+            InputField::Repeat => {
+                self.event_handler
+                    .on_key_event(key_event, &mut self.input_repeat_editor);
+            }
+            InputField::Priority => {
+                self.event_handler
+                    .on_key_event(key_event, &mut self.input_priority_editor);
+            }
+            InputField::Tags => {
+                self.tag_input.handle_key_event(key_event);
+            }
         }
         Ok(())
     }
@@ -173,6 +320,10 @@ impl TaskEditor {
             match self.current_input_field {
                 InputField::Date => self.date_error = None,
                 InputField::Time => self.time_error = None,
+                InputField::Start => self.start_error = None,
+                InputField::Reminders => self.reminders_error = None,
+                InputField::Repeat => self.repeat_error = None,
+                InputField::Priority => self.priority_error = None,
                 _ => {}
             }
         }
@@ -180,12 +331,89 @@ impl TaskEditor {
         Ok(())
     }
 
+    /// Whether a key edits the current field before the editor key bindings see it: text
+    /// typed in insert mode, Esc back to normal mode and new lines in the notes
+    pub fn takes_key(&self, key_event: KeyEvent) -> bool {
+        let plain = !key_event
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER);
+        let mode = self.current_editor().mode;
+        match key_event.code {
+            KeyCode::Char(_) => plain && mode == EditorMode::Insert,
+            KeyCode::Esc => mode != EditorMode::Normal,
+            KeyCode::Enter => plain && self.current_input_field == InputField::Description,
+            _ => false,
+        }
+    }
+
+    /// Pass a key to the current field, keeping the column vertical moves return to
+    pub fn edit_field(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            // Keys that should preserve desired column (vertical movement)
+            KeyCode::Up | KeyCode::Down => {
+                let _ = self.handle_input_key_event(key_event);
+            }
+            // Keys that should update desired column (horizontal movement, word movement, etc.)
+            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End => {
+                let _ = self.handle_input_key_event(key_event);
+                self.update_desired_column();
+            }
+            // Vertical movement commands that should preserve desired column
+            KeyCode::Char('j') | KeyCode::Char('k') if !self.is_current_editor_in_insert_mode() => {
+                let _ = self.handle_input_key_event(key_event);
+            }
+            // Character keys like 'w', 'b', 'e', '0', '$', 'i', 'x' and typed text
+            KeyCode::Char(_) => {
+                let _ = self.handle_input_key_event(key_event);
+                self.update_desired_column();
+            }
+            // For other keys, use the safe method that updates desired column
+            _ => {
+                let _ = self.handle_input_key_event_and_update_column(key_event);
+            }
+        }
+    }
+
+    /// Move the cursor left, remembering the column
+    pub fn move_left(&mut self) {
+        let _ = self.handle_input_key_event(KeyEvent::from(KeyCode::Left));
+        self.update_desired_column();
+    }
+
+    /// Move down within the notes or on to the next field, just the cursor in insert mode
+    pub fn move_down(&mut self) {
+        let down = KeyEvent::from(KeyCode::Down);
+        if self.is_current_editor_in_insert_mode() {
+            let _ = self.handle_input_key_event(down);
+        } else if !self.handle_j_navigation() {
+            let _ = self.handle_input_key_event(down);
+            self.update_desired_column();
+        }
+    }
+
+    /// Move up within the notes or back to the previous field, just the cursor in insert mode
+    pub fn move_up(&mut self) {
+        let up = KeyEvent::from(KeyCode::Up);
+        if self.is_current_editor_in_insert_mode() {
+            let _ = self.handle_input_key_event(up);
+        } else if !self.handle_k_navigation() {
+            let _ = self.handle_input_key_event(up);
+            self.update_desired_column();
+        }
+    }
+
     pub fn update_desired_column(&mut self) {
         let editor = match self.current_input_field {
             InputField::Title => &self.input_title_editor,
             InputField::Description => &self.input_description_editor,
             InputField::Date => &self.input_date_editor,
             InputField::Time => &self.input_time_editor,
+            InputField::Start => &self.input_start_editor,
+            InputField::Reminders => &self.input_reminders_editor,
+            InputField::Project => &self.project_picker.input,
+            InputField::Repeat => &self.input_repeat_editor,
+            InputField::Priority => &self.input_priority_editor,
+            InputField::Tags => &self.tag_input.input,
         };
         self.desired_column = editor.cursor.col;
     }
@@ -252,37 +480,17 @@ impl TaskEditor {
             return false;
         }
 
-        // Move to next field
-        match self.current_input_field {
-            InputField::Title => {
-                self.current_input_field = InputField::Date;
-                // Set cursor to first row of new field, then position at desired column
-                let editor = self.get_current_editor_mut();
-                editor.cursor.row = 0;
-                self.position_cursor_at_desired_column();
-                true
-            }
-            InputField::Date => {
-                self.current_input_field = InputField::Time;
-                // Set cursor to first row of new field, then position at desired column
-                let editor = self.get_current_editor_mut();
-                editor.cursor.row = 0;
-                self.position_cursor_at_desired_column();
-                true
-            }
-            InputField::Time => {
-                self.current_input_field = InputField::Description;
-                // Set cursor to first row of new field, then position at desired column
-                let editor = self.get_current_editor_mut();
-                editor.cursor.row = 0;
-                self.position_cursor_at_desired_column();
-                true
-            }
-            InputField::Description => {
-                // Don't wrap around, stay at description field
-                true
-            }
+        // Move to next field (no wraparound), ending at the checklist
+        if self.current_input_field == InputField::Description {
+            self.checklist_focused = true;
+        } else {
+            self.current_input_field = self.current_input_field.next();
+            // Set cursor to first row of new field, then position at desired column
+            let editor = self.get_current_editor_mut();
+            editor.cursor.row = 0;
+            self.position_cursor_at_desired_column();
         }
+        true
     }
 
     pub fn handle_k_navigation(&mut self) -> bool {
@@ -293,37 +501,15 @@ impl TaskEditor {
             return false;
         }
 
-        // Move to previous field
-        match self.current_input_field {
-            InputField::Title => {
-                // Don't wrap around, stay at title field
-                true
-            }
-            InputField::Date => {
-                self.current_input_field = InputField::Title;
-                // For single-line fields, position at row 0
-                let editor = self.get_current_editor_mut();
-                editor.cursor.row = 0;
-                self.position_cursor_at_desired_column();
-                true
-            }
-            InputField::Time => {
-                self.current_input_field = InputField::Date;
-                // For single-line fields, position at row 0
-                let editor = self.get_current_editor_mut();
-                editor.cursor.row = 0;
-                self.position_cursor_at_desired_column();
-                true
-            }
-            InputField::Description => {
-                self.current_input_field = InputField::Time;
-                // For single-line fields, position at row 0
-                let editor = self.get_current_editor_mut();
-                editor.cursor.row = 0;
-                self.position_cursor_at_desired_column();
-                true
-            }
+        // Move to previous field (no wraparound)
+        if self.current_input_field != InputField::Title {
+            self.current_input_field = self.current_input_field.previous();
+            // For single-line fields, position at row 0
+            let editor = self.get_current_editor_mut();
+            editor.cursor.row = 0;
+            self.position_cursor_at_desired_column();
         }
+        true
     }
 
     pub fn get_input_title(&self) -> String {
@@ -342,6 +528,22 @@ impl TaskEditor {
         String::from(self.input_time_editor.lines.clone())
     }
 
+    pub fn get_input_start(&self) -> String {
+        String::from(self.input_start_editor.lines.clone())
+    }
+
+    pub fn get_input_reminders(&self) -> String {
+        String::from(self.input_reminders_editor.lines.clone())
+    }
+
+    pub fn get_input_priority(&self) -> String {
+        String::from(self.input_priority_editor.lines.clone())
+    }
+
+    pub fn get_input_repeat(&self) -> String {
+        String::from(self.input_repeat_editor.lines.clone())
+    }
+
     pub fn validate(&mut self) -> bool {
         use crate::utils::{parse_date_us_format, parse_time_us_format};
 
@@ -374,14 +576,35 @@ impl TaskEditor {
             self.time_error = None;
         }
 
+        self.start_error = validate_start(&self.get_input_start(), &date_str).err();
+        if self.start_error.is_some() {
+            is_valid = false;
+        }
+
+        self.reminders_error = validate_reminders(&self.get_input_reminders(), &date_str).err();
+        if self.reminders_error.is_some() {
+            is_valid = false;
+        }
+
+        if !self.project_picker.validate() {
+            is_valid = false;
+        }
+
+        self.priority_error = parse_priority_field(&self.get_input_priority()).err();
+        if self.priority_error.is_some() {
+            is_valid = false;
+        }
+
+        self.repeat_error = parse_recurrence(&self.get_input_repeat()).err();
+        if self.repeat_error.is_some() {
+            is_valid = false;
+        }
+
         is_valid
     }
 
     pub fn has_changes(&self) -> bool {
-        self.get_input_title() != self.original_title
-            || self.get_input_description() != self.original_description
-            || self.get_input_date() != self.original_date
-            || self.get_input_time() != self.original_time
+        self.current_values() != self.original
     }
 
     pub fn is_at_line_start(&self) -> bool {
@@ -390,6 +613,12 @@ impl TaskEditor {
             InputField::Description => &self.input_description_editor,
             InputField::Date => &self.input_date_editor,
             InputField::Time => &self.input_time_editor,
+            InputField::Start => &self.input_start_editor,
+            InputField::Reminders => &self.input_reminders_editor,
+            InputField::Project => &self.project_picker.input,
+            InputField::Repeat => &self.input_repeat_editor,
+            InputField::Priority => &self.input_priority_editor,
+            InputField::Tags => &self.tag_input.input,
         };
         editor.cursor.col == 0
     }
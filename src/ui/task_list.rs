@@ -1,5 +1,8 @@
 use super::modal::Modal;
-use super::{ConfirmationModal, ConfirmationType, TaskModal};
+use super::{
+    ConfirmationModal, ConfirmationType, MoveModal, QueryModal, QuickAddModal, TagModal,
+    TagModalKind, TaskModal,
+};
 use anyhow::Result;
 use crossterm::event::KeyEvent;
 use ratatui::widgets::ListState;
@@ -10,6 +13,77 @@ pub enum ViewTab {
     Today,
     Week,
     Inbox,
+    /// A saved query, by its position in the saved list
+    Saved(usize),
+    /// A project or query tab from the config, by its position among them
+    Custom(usize),
+    Projects,
+    Tags,
+    Completed,
+    Trash,
+}
+
+impl ViewTab {
+    /// Built-in tabs in display order
+    pub const ALL: [ViewTab; 7] = [
+        ViewTab::Today,
+        ViewTab::Week,
+        ViewTab::Inbox,
+        ViewTab::Projects,
+        ViewTab::Tags,
+        ViewTab::Completed,
+        ViewTab::Trash,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            ViewTab::Today => "Today",
+            ViewTab::Week => "Week",
+            ViewTab::Inbox => "Inbox",
+            ViewTab::Saved(_) => "Saved",
+            ViewTab::Custom(_) => "Custom",
+            ViewTab::Projects => "Projects",
+            ViewTab::Tags => "Tags",
+            ViewTab::Completed => "Completed",
+            ViewTab::Trash => "Trash",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ViewTab::Today => "📅",
+            ViewTab::Week => "📆",
+            ViewTab::Inbox => "📥",
+            ViewTab::Saved(_) => "⭐",
+            ViewTab::Custom(_) => "📌",
+            ViewTab::Projects => "📁",
+            ViewTab::Tags => "🔖",
+            ViewTab::Completed => "✅",
+            ViewTab::Trash => "🗑",
+        }
+    }
+}
+
+/// A place in the tab bar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TabSlot {
+    /// A single tab, with a label replacing its title
+    Tab(ViewTab, Option<String>),
+    /// Where the saved query tabs go
+    SavedQueries,
+}
+
+impl TabSlot {
+    /// The built-in tabs, with saved queries after the inbox
+    pub fn default_layout() -> Vec<TabSlot> {
+        let (fixed, browsers) = ViewTab::ALL.split_at(3);
+        fixed
+            .iter()
+            .map(|&tab| TabSlot::Tab(tab, None))
+            .chain(std::iter::once(TabSlot::SavedQueries))
+            .chain(browsers.iter().map(|&tab| TabSlot::Tab(tab, None)))
+            .collect()
+    }
 }
 
 pub struct TaskList {
@@ -17,6 +91,10 @@ pub struct TaskList {
     pub current_tab: ViewTab,
     pub visual_range: Option<(usize, usize)>,
     pub current_modal: Option<Box<dyn Modal>>,
+    /// Names of the saved query tabs
+    pub saved_tabs: Vec<String>,
+    /// Order and labels of the tabs
+    pub layout: Vec<TabSlot>,
 }
 
 #[allow(dead_code)]
@@ -27,6 +105,8 @@ impl TaskList {
             current_tab: ViewTab::Today,
             visual_range: None,
             current_modal: None,
+            saved_tabs: Vec::new(),
+            layout: TabSlot::default_layout(),
         }
     }
 
@@ -246,24 +326,98 @@ impl TaskList {
         }
     }
 
-    pub fn next_tab(&mut self) {
-        self.current_tab = match self.current_tab {
-            ViewTab::Today => ViewTab::Week,
-            ViewTab::Week => ViewTab::Inbox,
-            ViewTab::Inbox => ViewTab::Today,
+    pub fn is_quick_add_modal(&self) -> bool {
+        self.current_modal
+            .as_ref()
+            .is_some_and(|modal| modal.as_any().downcast_ref::<QuickAddModal>().is_some())
+    }
+
+    pub fn is_move_modal(&self) -> bool {
+        self.current_modal
+            .as_ref()
+            .is_some_and(|modal| modal.as_any().downcast_ref::<MoveModal>().is_some())
+    }
+
+    /// What the open tag modal is for, if a tag modal is open
+    pub fn tag_modal_kind(&self) -> Option<TagModalKind> {
+        self.current_modal
+            .as_ref()
+            .and_then(|modal| modal.as_any().downcast_ref::<TagModal>())
+            .map(|modal| modal.kind())
+    }
+
+    pub fn is_query_modal(&self) -> bool {
+        self.current_modal
+            .as_ref()
+            .is_some_and(|modal| modal.as_any().downcast_ref::<QueryModal>().is_some())
+    }
+
+    /// Tabs in display order, with the saved queries where the layout puts them
+    ///
+    /// Saved queries go last when the layout has no place for them.
+    pub fn tabs(&self) -> Vec<ViewTab> {
+        let saved = (0..self.saved_tabs.len()).map(ViewTab::Saved);
+        let mut tabs = Vec::new();
+        for slot in &self.layout {
+            match slot {
+                TabSlot::Tab(tab, _) => tabs.push(*tab),
+                TabSlot::SavedQueries => tabs.extend(saved.clone()),
+            }
+        }
+        if !self.layout.contains(&TabSlot::SavedQueries) {
+            tabs.extend(saved);
+        }
+        tabs
+    }
+
+    /// Label of a tab, from the layout or the name of a saved query
+    pub fn tab_title(&self, tab: ViewTab) -> &str {
+        if let ViewTab::Saved(index) = tab {
+            return self
+                .saved_tabs
+                .get(index)
+                .map_or(tab.title(), String::as_str);
+        }
+        self.layout
+            .iter()
+            .find_map(|slot| match slot {
+                TabSlot::Tab(t, Some(label)) if *t == tab => Some(label.as_str()),
+                _ => None,
+            })
+            .unwrap_or(tab.title())
+    }
+
+    /// Switch to the tab at `position` in the tab bar, returning whether it exists
+    pub fn select_tab(&mut self, position: usize) -> bool {
+        let Some(&tab) = self.tabs().get(position) else {
+            return false;
         };
+        if tab != self.current_tab {
+            self.current_tab = tab;
+            self.select_none();
+        }
+        true
+    }
+
+    /// Move `step` tabs along, wrapping around at either end
+    fn step_tab(&mut self, step: isize) {
+        let tabs = self.tabs();
+        let position = tabs
+            .iter()
+            .position(|&tab| tab == self.current_tab)
+            .unwrap_or(0);
+        let next = (position as isize + step).rem_euclid(tabs.len() as isize);
+        self.current_tab = tabs[next as usize];
         // Clear selection when switching tabs
         self.select_none();
     }
 
+    pub fn next_tab(&mut self) {
+        self.step_tab(1);
+    }
+
     pub fn previous_tab(&mut self) {
-        self.current_tab = match self.current_tab {
-            ViewTab::Today => ViewTab::Inbox,
-            ViewTab::Week => ViewTab::Today,
-            ViewTab::Inbox => ViewTab::Week,
-        };
-        // Clear selection when switching tabs
-        self.select_none();
+        self.step_tab(-1);
     }
 
     pub fn get_current_tab(&self) -> ViewTab {
@@ -19,9 +19,6 @@ use tokio::{
 };
 use tokio_util::sync::CancellationToken;
 
-const TICK_RATE: f64 = 4.0;
-const FRAME_RATE: f64 = 60.0;
-
 pub type Frame<'a> = ratatui::Frame<'a>;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -46,6 +43,8 @@ pub struct Tui {
     pub cancellation_token: CancellationToken,
     pub event_rx: UnboundedReceiver<Event>,
     pub event_tx: UnboundedSender<Event>,
+    pub tick_rate: f64,
+    pub frame_rate: f64,
 }
 
 impl Tui {
@@ -60,12 +59,24 @@ impl Tui {
             cancellation_token,
             event_rx,
             event_tx,
+            tick_rate: 4.0,
+            frame_rate: 60.0,
         })
     }
 
+    pub fn tick_rate(mut self, tick_rate: f64) -> Self {
+        self.tick_rate = tick_rate;
+        self
+    }
+
+    pub fn frame_rate(mut self, frame_rate: f64) -> Self {
+        self.frame_rate = frame_rate;
+        self
+    }
+
     pub fn start(&mut self) {
-        let tick_delay = std::time::Duration::from_secs_f64(1.0 / TICK_RATE);
-        let render_delay = std::time::Duration::from_secs_f64(1.0 / FRAME_RATE);
+        let tick_delay = std::time::Duration::from_secs_f64(1.0 / self.tick_rate);
+        let render_delay = std::time::Duration::from_secs_f64(1.0 / self.frame_rate);
         self.cancel();
         self.cancellation_token = CancellationToken::new();
         let _cancellation_token = self.cancellation_token.clone();
@@ -1,8 +1,9 @@
-use ticks::tasks::Task;
+use ticks::{projects::ProjectID, tasks::TaskID};
 
-use crate::tasks;
+use crate::{backend::TaskBackend, tasks};
 
-/// Parse date in US format (MM/DD or MM/DD/YYYY) or ISO format (YYYY-MM-DD)
+/// Parse date in US format (MM/DD or MM/DD/YYYY), ISO format (YYYY-MM-DD) or
+/// natural language ("tomorrow", "next fri", "in 3 days", ...)
 /// If year is not provided, uses current year or next year for valid future dates
 pub fn parse_date_us_format(date_str: &str) -> Result<chrono::NaiveDate, String> {
     use chrono::{Datelike, Local, NaiveDate};
@@ -14,6 +15,10 @@ pub fn parse_date_us_format(date_str: &str) -> Result<chrono::NaiveDate, String>
         return Ok(date);
     }
 
+    if let Some(date) = parse_natural_date(date_str, Local::now().date_naive()) {
+        return Ok(date);
+    }
+
     // Determine separator (/ or -)
     let separator = if date_str.contains('/') {
         '/'
@@ -21,7 +26,7 @@ pub fn parse_date_us_format(date_str: &str) -> Result<chrono::NaiveDate, String>
         '-'
     } else {
         return Err(format!(
-            "Invalid date format. Use MM/DD, MM/DD/YYYY, MM-DD, MM-DD-YY, or YYYY-MM-DD"
+            "Invalid date format. Use MM/DD, MM/DD/YYYY, YYYY-MM-DD, or words like 'tomorrow', 'fri', 'in 3 days'"
         ));
     };
 
@@ -100,6 +105,72 @@ pub fn parse_date_us_format(date_str: &str) -> Result<chrono::NaiveDate, String>
         .ok_or_else(|| format!("Invalid date: {}/{}/{}", month, day, year))
 }
 
+/// Parse natural language dates relative to `today`
+/// Supports:
+/// - "today", "tomorrow" (or "tmr")
+/// - "mon", "friday" - the next such day, today included
+/// - "next fri" - that day in next week (weeks start on Monday)
+/// - "next week" - Monday of next week
+/// - "in 3 days", "in 2 weeks", "in 1 month"
+/// - "end of month" (or "eom")
+fn parse_natural_date(date_str: &str, today: chrono::NaiveDate) -> Option<chrono::NaiveDate> {
+    use chrono::{Datelike, Days, Duration, Months, NaiveDate};
+
+    let input = date_str.trim().to_lowercase();
+    let words: Vec<&str> = input.split_whitespace().collect();
+
+    // Days from today until the given weekday, 0 if today is that day
+    let days_until = |weekday: chrono::Weekday| {
+        (weekday.num_days_from_monday() as i64 - today.weekday().num_days_from_monday() as i64)
+            .rem_euclid(7)
+    };
+    let next_monday = today + Duration::days(7 - today.weekday().num_days_from_monday() as i64);
+
+    match words.as_slice() {
+        ["today"] => Some(today),
+        ["tomorrow"] | ["tmr"] => Some(today + Duration::days(1)),
+        ["next", "week"] => Some(next_monday),
+        ["end", "of", "month"] | ["eom"] => {
+            let first_of_month = NaiveDate::from_ymd_opt(today.year(), today.month(), 1)?;
+            Some(first_of_month.checked_add_months(Months::new(1))? - Duration::days(1))
+        }
+        ["next", day] => {
+            let weekday = parse_weekday(day)?;
+            Some(next_monday + Duration::days(weekday.num_days_from_monday() as i64))
+        }
+        [day] => {
+            let weekday = parse_weekday(day)?;
+            Some(today + Duration::days(days_until(weekday)))
+        }
+        ["in", count, unit] => {
+            let count = count.parse::<u32>().ok()?;
+            match *unit {
+                "day" | "days" | "d" => today.checked_add_days(Days::new(count as u64)),
+                "week" | "weeks" | "w" => today.checked_add_days(Days::new(count as u64 * 7)),
+                "month" | "months" => today.checked_add_months(Months::new(count)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Parse a lowercase weekday name or abbreviation (`mon`, `tues`, `thursday`)
+pub fn parse_weekday(day: &str) -> Option<chrono::Weekday> {
+    use chrono::Weekday;
+
+    match day {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
 /// Parse time in US format (12-hour with AM/PM)
 pub fn parse_time_us_format(time_str: &str) -> Result<chrono::NaiveTime, String> {
     use chrono::NaiveTime;
@@ -169,6 +240,33 @@ pub fn parse_time_us_format(time_str: &str) -> Result<chrono::NaiveTime, String>
         .ok_or_else(|| format!("Invalid time: {}:{:02}", hour_24, minute))
 }
 
+/// Parse a date, a time or a date followed by a time, e.g. `12/25 5:30 pm`
+///
+/// The date is `None` when only a time was given.
+pub fn parse_date_and_time(
+    text: &str,
+) -> Result<(Option<chrono::NaiveDate>, Option<chrono::NaiveTime>), String> {
+    let text = text.trim();
+    if let Ok(time) = parse_time_us_format(text) {
+        return Ok((None, Some(time)));
+    }
+    if let Ok(date) = parse_date_us_format(text) {
+        return Ok((Some(date), None));
+    }
+
+    // Try every split between a date part and a time part
+    let words: Vec<&str> = text.split_whitespace().collect();
+    for split in 1..words.len() {
+        let date = parse_date_us_format(&words[..split].join(" "));
+        let time = parse_time_us_format(&words[split..].join(" "));
+        if let (Ok(date), Ok(time)) = (date, time) {
+            return Ok((Some(date), Some(time)));
+        }
+    }
+
+    Err("Use a date, a time or both, e.g. 12/25, 5pm or \"12/25 5pm\"".to_string())
+}
+
 /// Represents the target for a postpone operation
 #[derive(Debug)]
 pub enum PostponeTarget {
@@ -178,6 +276,35 @@ pub enum PostponeTarget {
     AbsoluteTime(chrono::DateTime<chrono::Local>),
 }
 
+impl PostponeTarget {
+    /// Calculate new due datetimes for a group of tasks
+    ///
+    /// For absolute targets the earliest task moves to the target time and the
+    /// others keep their offsets from it.
+    pub fn new_due_dates(
+        &self,
+        due_dates: &[chrono::DateTime<chrono::Utc>],
+    ) -> Vec<chrono::DateTime<chrono::Utc>> {
+        match self {
+            PostponeTarget::RelativeToDueDate(duration) => {
+                // Add duration to each task's original due_date
+                due_dates.iter().map(|due| *due + *duration).collect()
+            }
+            PostponeTarget::AbsoluteTime(datetime) => {
+                let target_time = datetime.with_timezone(&chrono::Utc);
+                match due_dates.iter().min() {
+                    // Apply each task's offset from the earliest task to the target time
+                    Some(earliest) => due_dates
+                        .iter()
+                        .map(|due| target_time + (*due - *earliest))
+                        .collect(),
+                    None => Vec::new(),
+                }
+            }
+        }
+    }
+}
+
 /// Parse duration expression and return the postpone target
 /// Supports formats like:
 /// - "now" - current time (absolute)
@@ -251,14 +378,149 @@ pub fn parse_duration(duration_str: &str) -> Result<PostponeTarget, String> {
     }
 }
 
-pub async fn delete_task(task: Task) -> Result<(), String> {
-    tasks::delete_task(task).await
+/// Score how well `query` fuzzy-matches `candidate`, or `None` if it doesn't
+///
+/// Every query character must appear in order (ignoring case). Exact matches,
+/// prefixes, word starts and consecutive characters score higher.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query.trim().to_lowercase().chars().collect();
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+    if query == candidate {
+        return Some(1000);
+    }
+
+    let mut score = 0;
+    let mut query_index = 0;
+    let mut previous_match: Option<usize> = None;
+    for (i, &c) in candidate.iter().enumerate() {
+        if query_index == query.len() {
+            break;
+        }
+        if c != query[query_index] {
+            continue;
+        }
+        score += 1;
+        if previous_match.is_some_and(|previous| previous + 1 == i) {
+            score += 5;
+        }
+        if i == 0 || !candidate[i - 1].is_alphanumeric() {
+            score += 10;
+        }
+        previous_match = Some(i);
+        query_index += 1;
+    }
+
+    if query_index < query.len() {
+        return None;
+    }
+    if candidate.starts_with(&query) {
+        score += 100;
+    }
+    // Prefer shorter candidates when the match is otherwise equal
+    Some(score - candidate.len() as i64)
+}
+
+pub async fn delete_task(
+    client: &dyn TaskBackend,
+    project_id: &ProjectID,
+    task_id: &TaskID,
+) -> Result<(), String> {
+    tasks::delete_task(client, project_id, task_id).await
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_natural_date() {
+        use chrono::NaiveDate;
+
+        // Wednesday
+        let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+
+        assert_eq!(parse_natural_date("today", today), date(2025, 1, 15));
+        assert_eq!(parse_natural_date("Tomorrow", today), date(2025, 1, 16));
+        assert_eq!(parse_natural_date("wed", today), date(2025, 1, 15));
+        assert_eq!(parse_natural_date("fri", today), date(2025, 1, 17));
+        assert_eq!(parse_natural_date("monday", today), date(2025, 1, 20));
+        assert_eq!(parse_natural_date("next friday", today), date(2025, 1, 24));
+        assert_eq!(parse_natural_date("next mon", today), date(2025, 1, 20));
+        assert_eq!(parse_natural_date("next week", today), date(2025, 1, 20));
+        assert_eq!(parse_natural_date("in 3 days", today), date(2025, 1, 18));
+        assert_eq!(parse_natural_date("in 2 weeks", today), date(2025, 1, 29));
+        assert_eq!(parse_natural_date("in 1 month", today), date(2025, 2, 15));
+        assert_eq!(parse_natural_date("end of month", today), date(2025, 1, 31));
+
+        // End of a short month and a leap year
+        let feb = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+        assert_eq!(parse_natural_date("eom", feb), date(2024, 2, 29));
+
+        assert_eq!(parse_natural_date("someday", today), None);
+        assert_eq!(parse_natural_date("in x days", today), None);
+        assert_eq!(parse_natural_date("next month", today), None);
+
+        // Past the end of the calendar
+        assert_eq!(parse_natural_date("in 4000000000 days", today), None);
+        assert_eq!(parse_natural_date("in 4000000000 weeks", today), None);
+        assert_eq!(parse_natural_date("in 4000000000 months", today), None);
+        assert!(parse_date_us_format("in 4000000000 days").is_err());
+    }
+
+    #[test]
+    fn test_parse_date_us_format() {
+        use chrono::{Duration, Local, NaiveDate};
+
+        let today = Local::now().date_naive();
+        assert_eq!(parse_date_us_format(" today ").unwrap(), today);
+        assert_eq!(
+            parse_date_us_format("tomorrow").unwrap(),
+            today + Duration::days(1)
+        );
+        assert_eq!(
+            parse_date_us_format("12/25/2030").unwrap(),
+            NaiveDate::from_ymd_opt(2030, 12, 25).unwrap()
+        );
+        assert!(parse_date_us_format("someday").is_err());
+        assert!(parse_date_us_format("13/01").is_err());
+    }
+
+    #[test]
+    fn test_parse_date_and_time() {
+        use chrono::{NaiveDate, NaiveTime};
+
+        let christmas = NaiveDate::from_ymd_opt(2030, 12, 25);
+        let five_thirty = NaiveTime::from_hms_opt(17, 30, 0);
+        assert_eq!(
+            parse_date_and_time("12/25/2030 5:30 pm"),
+            Ok((christmas, five_thirty))
+        );
+        assert_eq!(parse_date_and_time("12/25/2030"), Ok((christmas, None)));
+        assert_eq!(parse_date_and_time(" 5:30pm "), Ok((None, five_thirty)));
+        assert!(parse_date_and_time("someday soon").is_err());
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("wrk", "Work").is_some());
+        assert!(fuzzy_score("sp", "Side Projects").is_some());
+        assert!(fuzzy_score("kw", "Work").is_none());
+        assert_eq!(fuzzy_score("", "Work"), Some(0));
+
+        // Exact beats prefix beats word starts beats scattered letters
+        let exact = fuzzy_score("work", "Work").unwrap();
+        let prefix = fuzzy_score("work", "Workshop").unwrap();
+        let word = fuzzy_score("shop", "Work Shopping").unwrap();
+        let scattered = fuzzy_score("wp", "Workshop").unwrap();
+        assert!(exact > prefix);
+        assert!(prefix > word);
+        assert!(word > scattered);
+    }
+
     #[test]
     fn test_parse_time_us_format() {
         // Test PM times